@@ -0,0 +1,77 @@
+//! Capture build metadata for `gh-log version --verbose`.
+//!
+//! Sets `GH_LOG_GIT_SHA`, `GH_LOG_BUILD_DATE` and `GH_LOG_RUSTC_VERSION` for `env!`. Every value
+//! falls back to "unknown" so builds from a source tarball without git still succeed.
+
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    let git_sha = command_output("git", &["rev-parse", "--short=12", "HEAD"]);
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version = command_output(&rustc, &["--version"]);
+
+    println!("cargo:rustc-env=GH_LOG_GIT_SHA={}", git_sha);
+    println!("cargo:rustc-env=GH_LOG_BUILD_DATE={}", build_date());
+    println!("cargo:rustc-env=GH_LOG_RUSTC_VERSION={}", rustc_version);
+    for path in git_watch_paths() {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+}
+
+/// Files whose change means a new commit: `HEAD` itself, the branch ref it points to, and
+/// `packed-refs` in case the ref has been packed. Paths that do not exist are skipped, since cargo
+/// would otherwise rerun the script on every build.
+fn git_watch_paths() -> Vec<String> {
+    let mut names = vec!["HEAD".to_string(), "packed-refs".to_string()];
+    let head = command_output("git", &["symbolic-ref", "-q", "HEAD"]);
+    if head != "unknown" {
+        names.push(head);
+    }
+    names
+        .iter()
+        .map(|name| command_output("git", &["rev-parse", "--git-path", name]))
+        .filter(|path| path != "unknown" && std::path::Path::new(path).exists())
+        .collect()
+}
+
+fn command_output(program: &str, args: &[&str]) -> String {
+    Command::new(program)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|stdout| stdout.trim().to_string())
+        .filter(|stdout| !stdout.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// UTC build date as `YYYY-MM-DD`, honoring `SOURCE_DATE_EPOCH` for reproducible builds.
+fn build_date() -> String {
+    let secs = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse::<i64>().ok())
+        .or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .ok()
+                .map(|elapsed| elapsed.as_secs() as i64)
+        });
+    let Some(secs) = secs else {
+        return "unknown".to_string();
+    };
+
+    // Days since 1970-01-01 to a civil date (Howard Hinnant's algorithm).
+    let z = secs.div_euclid(86_400) + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
@@ -1,6 +1,7 @@
 //! gh-log cache layer.
 //!
 //! Caches monthly PR snapshots in the OS cache directory so repeat runs avoid extra GitHub calls.
+//! Snapshots are gzip-compressed JSON; plain JSON files from older versions are still read.
 //! The current month refreshes after six hours, the previous month after twenty-four, and older
 //! snapshots stick around while respecting `MAX_CACHE_SIZE`.
 //!
@@ -12,21 +13,41 @@
 //! }
 //! ```
 
-use anyhow::{Context, Result, bail};
 use chrono::{DateTime, Duration, Utc};
 use directories::ProjectDirs;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
-use crate::github::PullRequest;
+use crate::error::{Error, Result};
+use crate::github::{DateField, PullRequest};
 
-// Cache each month's PR snapshot as a standalone JSON file in the OS cache dir.
+// Cache each month's PR snapshot as a standalone gzip-compressed JSON file in the OS cache dir.
 // Size and TTL caps keep recent data handy without letting old entries pile up.
 const MAX_CACHE_SIZE: usize = 10_000;
+const SNAPSHOT_SUFFIX: &str = ".json.gz";
+// Uncompressed snapshots written before gzip; read, listed and replaced on the next save.
+const LEGACY_SNAPSHOT_SUFFIX: &str = ".json";
 const CURRENT_MONTH_CACHE_TTL_HOURS: i64 = 6;
 const PREVIOUS_MONTH_CACHE_TTL_HOURS: i64 = 24;
 const LAST_MONTH_LOOKBACK_DAYS: i64 = 30;
+// Temp files older than this were left by a run that died mid-save; younger ones may belong to
+// a run that is still writing.
+const STALE_TEMP_FILE_SECS: u64 = 60 * 60;
+
+/// Schema version written into every snapshot. Bump it whenever [`CachedData`] or the cached
+/// [`PullRequest`] fields change so older files are refetched instead of read with gaps.
+pub const CACHE_VERSION: u32 = 4;
+
+// Serializes writes from threads within one process (`prefetch --jobs`), which share a temp
+// file name per key.
+static SAVE_LOCK: Mutex<()> = Mutex::new(());
 
 #[derive(Debug)]
 /// File-backed cache for monthly PR snapshots stored in the user's cache directory.
@@ -45,17 +66,74 @@ pub struct Cache {
     max_prs_in_cache: usize,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// Snapshot of PR analytics cached for a specific month, including review aggregates.
 pub struct CachedData {
+    /// Schema version the snapshot was written with; files from before versioning read as 0.
+    #[serde(default)]
+    pub version: u32,
     /// Month tag (YYYY-MM) that identifies the cache entry.
     pub month: String,
     /// Timestamp when the data was persisted, used to determine freshness.
     pub timestamp: DateTime<Utc>,
     /// Full list of pull requests captured for the month.
     pub prs: Vec<PullRequest>,
-    /// Total number of PRs you reviewed during the month.
-    pub reviewed_count: usize,
+    /// Total number of PRs you reviewed during the month; `None` when the snapshot was saved by a
+    /// `--no-reviewed` run that skipped the review query.
+    pub reviewed_count: Option<usize>,
+    /// The reviewed PRs per `owner/repo`; `None` alongside a missing `reviewed_count` or in
+    /// snapshots written before per-repo counts were fetched.
+    #[serde(default)]
+    pub reviewed_by_repo: Option<BTreeMap<String, usize>>,
+    /// Seconds from creation to your first review for each reviewed PR; `None` alongside a
+    /// missing `reviewed_count` or in snapshots written before turnaround was fetched.
+    #[serde(default)]
+    pub review_turnaround_secs: Option<Vec<i64>>,
+}
+
+/// Where a month's PR list came from, so the UI can say how current it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataSource {
+    /// Served from a snapshot written at this time.
+    Cache(DateTime<Utc>),
+    /// Fetched from GitHub during this run.
+    Fresh,
+}
+
+impl DataSource {
+    /// Short label such as "Data as of 2h ago" or "fresh", relative to `now`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use gh_log::cache::DataSource;
+    /// # use chrono::{Duration, Utc};
+    /// let now = Utc::now();
+    /// assert_eq!(DataSource::Fresh.describe(now), "fresh");
+    /// assert_eq!(
+    ///     DataSource::Cache(now - Duration::hours(2)).describe(now),
+    ///     "Data as of 2h ago"
+    /// );
+    /// ```
+    pub fn describe(&self, now: DateTime<Utc>) -> String {
+        match self {
+            DataSource::Fresh => "fresh".to_string(),
+            DataSource::Cache(timestamp) => {
+                format!("Data as of {}", format_age(now - *timestamp))
+            }
+        }
+    }
+}
+
+fn format_age(age: Duration) -> String {
+    if age < Duration::minutes(1) {
+        "just now".to_string()
+    } else if age < Duration::hours(1) {
+        format!("{}m ago", age.num_minutes())
+    } else if age < Duration::days(1) {
+        format!("{}h ago", age.num_hours())
+    } else {
+        format!("{}d ago", age.num_days())
+    }
 }
 
 impl Cache {
@@ -66,12 +144,17 @@ impl Cache {
     /// # use gh_log::cache::Cache;
     /// let cache = Cache::default().expect("cache directory to exist");
     /// ```
-    pub fn default() -> anyhow::Result<Self> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn default() -> Result<Self> {
         let project_dirs =
-            ProjectDirs::from("", "", "gh-log").context("Failed to determine cache directory")?;
+            ProjectDirs::from("", "", "gh-log").ok_or(Error::NoProjectDirs("cache"))?;
         let cache_dir = project_dirs.cache_dir().to_path_buf();
-        fs::create_dir_all(&cache_dir)
-            .with_context(|| format!("Failed to create cache directory: {:?}", cache_dir))?;
+        fs::create_dir_all(&cache_dir).map_err(|e| {
+            Error::io(
+                format!("Failed to create cache directory: {:?}", cache_dir),
+                e,
+            )
+        })?;
 
         Self::new(cache_dir, MAX_CACHE_SIZE)
     }
@@ -85,9 +168,14 @@ impl Cache {
     /// let cache_dir = PathBuf::from("/tmp/gh-log-cache");
     /// let cache = Cache::new(cache_dir, 10_000).expect("custom cache directory");
     /// ```
-    pub fn new(cache_dir: PathBuf, max_prs_in_cache: usize) -> anyhow::Result<Self> {
-        fs::create_dir_all(&cache_dir)
-            .with_context(|| format!("Failed to create cache directory: {:?}", cache_dir))?;
+    pub fn new(cache_dir: PathBuf, max_prs_in_cache: usize) -> Result<Self> {
+        fs::create_dir_all(&cache_dir).map_err(|e| {
+            Error::io(
+                format!("Failed to create cache directory: {:?}", cache_dir),
+                e,
+            )
+        })?;
+        remove_stale_temp_files(&cache_dir);
 
         Ok(Cache {
             cache_dir,
@@ -95,7 +183,14 @@ impl Cache {
         })
     }
 
-    /// Load cached data for a month when the on-disk snapshot exists and is still considered fresh.
+    /// Load the snapshot stored under `key` when it exists and is still considered fresh.
+    ///
+    /// Freshness follows the month recorded inside the snapshot, so qualified keys (see
+    /// [`cache_key`]) age the same way as the plain month they cover. A file that no longer
+    /// parses (say, from a write cut short by an older version), or that was written with a
+    /// different [`CACHE_VERSION`], is removed and reported as a miss so the caller refetches.
+    /// One dated in the future fails with [`Error::CacheFromFuture`], which callers can warn
+    /// about and treat as a miss.
     ///
     /// # Examples
     /// ```rust,no_run
@@ -105,79 +200,420 @@ impl Cache {
     ///     println!("Found {} cached PRs", snapshot.prs.len());
     /// }
     /// ```
-    pub fn load(&self, month: &str) -> Result<Option<CachedData>> {
-        let cache_file = self
-            .get_cache_file_path(month)
-            .with_context(|| format!("Failed to get cache file path for {}", month))?;
-        if !cache_file.exists() {
+    pub fn load(&self, key: &str) -> Result<Option<CachedData>> {
+        let cached = match self.load_any(key) {
+            Ok(Some(cached)) => cached,
+            Ok(None) => return Ok(None),
+            Err(Error::CacheCorrupt(path)) => {
+                fs::remove_file(&path)
+                    .map_err(|e| Error::io(format!("Failed to remove file for {}", key), e))?;
+                return Ok(None);
+            }
+            Err(err) => return Err(err),
+        };
+
+        if cached.version != CACHE_VERSION {
+            self.remove_snapshot(key)?;
             return Ok(None);
         }
 
-        let contents = fs::read_to_string(&cache_file)
-            .with_context(|| format!("Failed to read cache file for {}", month))?;
-        let cached: CachedData = serde_json::from_str(&contents)
-            .with_context(|| format!("Failed to parse cache file for {}", month))?;
-
-        if is_cache_fresh(month, cached.timestamp) {
+        let now = Utc::now();
+        if cached.timestamp > now {
+            return Err(Error::CacheFromFuture {
+                key: key.to_string(),
+                timestamp: cached.timestamp,
+            });
+        }
+        if is_cache_fresh(&cached.month, cached.timestamp, now) {
             return Ok(Some(cached));
         }
 
         // Drop the stale cache so the next request forces a fresh write with the new schema/data.
-        fs::remove_file(&cache_file)
-            .with_context(|| format!("Failed to remove file for {}", month))?;
+        self.remove_snapshot(key)?;
 
         Ok(None)
     }
 
-    /// Persist a month's snapshot to disk after ensuring it fits within cache bounds.
+    /// Load the snapshot stored under `key` however old it is, for `--offline` runs where stale
+    /// data beats no data. Unlike [`Cache::load`], a stale or older-version snapshot is left in
+    /// place and an unparsable one is reported as [`Error::CacheCorrupt`].
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::cache::Cache;
+    /// let cache = Cache::default().expect("cache directory");
+    /// if let Some(snapshot) = cache.load_any("2025-01").expect("cache read") {
+    ///     println!("Cached at {}", snapshot.timestamp);
+    /// }
+    /// ```
+    pub fn load_any(&self, key: &str) -> Result<Option<CachedData>> {
+        let compressed_file = self.get_cache_file_path(key);
+        let legacy_file = self.get_legacy_cache_file_path(key);
+        let (cache_file, contents) = if compressed_file.exists() {
+            let compressed = fs::read(&compressed_file)
+                .map_err(|e| Error::io(format!("Failed to read cache file for {}", key), e))?;
+            let mut contents = String::new();
+            GzDecoder::new(compressed.as_slice())
+                .read_to_string(&mut contents)
+                .map_err(|_| Error::CacheCorrupt(compressed_file.clone()))?;
+            (compressed_file, contents)
+        } else if legacy_file.exists() {
+            let contents = fs::read_to_string(&legacy_file)
+                .map_err(|e| Error::io(format!("Failed to read cache file for {}", key), e))?;
+            (legacy_file, contents)
+        } else {
+            return Ok(None);
+        };
+
+        let cached: CachedData =
+            serde_json::from_str(&contents).map_err(|_| Error::CacheCorrupt(cache_file.clone()))?;
+        Ok(Some(cached))
+    }
+
+    /// Persist a month's snapshot under `key` after ensuring it fits within cache bounds.
+    ///
+    /// The gzip-compressed JSON is written to a temporary file in the cache directory and renamed
+    /// into place, so an interrupted run leaves either the previous snapshot or the new one, never
+    /// a partial file. A plain JSON snapshot left by an older version is removed once replaced.
     ///
     /// # Examples
     /// ```rust,no_run
-    /// # use gh_log::cache::{Cache, CachedData};
+    /// # use gh_log::cache::{Cache, CachedData, CACHE_VERSION};
     /// # use chrono::Utc;
     /// let cache = Cache::default().expect("cache directory");
     /// let data = CachedData {
+    ///     version: CACHE_VERSION,
     ///     month: "2025-01".into(),
     ///     timestamp: Utc::now(),
     ///     prs: Vec::new(),
-    ///     reviewed_count: 0,
+    ///     reviewed_count: Some(0),
+    ///     reviewed_by_repo: None,
+    ///     review_turnaround_secs: None,
     /// };
-    /// cache.save(&data).expect("persist snapshot");
+    /// cache.save("2025-01", &data).expect("persist snapshot");
     /// ```
-    pub fn save(&self, data: &CachedData) -> Result<()> {
+    pub fn save(&self, key: &str, data: &CachedData) -> Result<()> {
         if data.prs.len() > self.max_prs_in_cache {
-            bail!(
-                "Too many PRs to cache: {}. Max {}",
-                data.prs.len(),
-                self.max_prs_in_cache
-            );
+            return Err(Error::CacheTooLarge {
+                count: data.prs.len(),
+                max: self.max_prs_in_cache,
+            });
+        }
+
+        let cache_file = self.get_cache_file_path(key);
+        let json = serde_json::to_string_pretty(data).map_err(|e| {
+            Error::serialize(
+                format!("Failed to serialize cache data for month {}", data.month),
+                e,
+            )
+        })?;
+        let _guard = SAVE_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Per-process name so concurrent runs don't write into each other's temp file.
+        let temp_file = self.cache_dir.join(format!(
+            "{}{}.{}.tmp",
+            key,
+            SNAPSHOT_SUFFIX,
+            std::process::id()
+        ));
+        // Flush to disk before the rename; otherwise a crash can persist the rename ahead of the
+        // data and leave an empty snapshot behind.
+        let written = fs::File::create(&temp_file)
+            .and_then(|file| {
+                let mut encoder = GzEncoder::new(file, Compression::default());
+                encoder.write_all(json.as_bytes())?;
+                encoder.finish()?.sync_all()
+            })
+            .map_err(|e| Error::io(format!("Failed to write cache file: {:?}", temp_file), e))
+            .and_then(|()| {
+                fs::rename(&temp_file, &cache_file).map_err(|e| {
+                    Error::io(format!("Failed to write cache file: {:?}", cache_file), e)
+                })
+            });
+        if written.is_err() {
+            let _ = fs::remove_file(&temp_file);
+        } else {
+            // Best effort: a leftover legacy file is shadowed by the compressed one anyway.
+            let _ = fs::remove_file(self.get_legacy_cache_file_path(key));
         }
 
-        let cache_file = self.get_cache_file_path(&data.month)?;
-        let json = serde_json::to_string_pretty(data)
-            .with_context(|| format!("Failed to serialize cache data for month {}", data.month))?;
-        fs::write(&cache_file, json)
-            .with_context(|| format!("Failed to write cache file: {:?}", cache_file))?;
+        written
+    }
+
+    /// Months with a whole-month snapshot stored under `key(month)`, oldest first, so the key
+    /// picks the scope: [`cache_key`] for your own PRs, [`author_cache_key`] for someone else's.
+    /// Day windows and snapshots under other scopes are left out.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::cache::{Cache, cache_key};
+    /// # use gh_log::github::DateField;
+    /// let cache = Cache::default().expect("cache directory");
+    /// let months = cache.cached_months(|month| cache_key(month, DateField::Created));
+    /// for month in months.expect("read cache dir") {
+    ///     println!("{}", month);
+    /// }
+    /// ```
+    pub fn cached_months(&self, key: impl Fn(&str) -> String) -> Result<Vec<String>> {
+        let mut months: Vec<String> = list_cache_files(&self.cache_dir)?
+            .into_iter()
+            .filter_map(|file| {
+                let file_key = snapshot_key(&file.name)?;
+                let month = file_key.get(..7)?;
+                let is_month =
+                    chrono::NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d").is_ok();
+                (is_month && key(month) == file_key).then(|| month.to_string())
+            })
+            .collect();
+        months.sort();
+        // A month can have both a compressed and a legacy file until its next save.
+        months.dedup();
+        Ok(months)
+    }
+
+    /// Snapshots of the last `n` [`cached_months`](Cache::cached_months) under `key`, oldest
+    /// first. Snapshots are returned whatever their age or version; unreadable ones are
+    /// skipped so one bad file does not hide the rest.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::cache::{Cache, cache_key};
+    /// # use gh_log::github::DateField;
+    /// let cache = Cache::default().expect("cache directory");
+    /// let snapshots = cache.load_recent_months(6, |month| cache_key(month, DateField::Created));
+    /// for snapshot in snapshots.expect("read cache dir") {
+    ///     println!("{}: {} PRs", snapshot.month, snapshot.prs.len());
+    /// }
+    /// ```
+    pub fn load_recent_months(
+        &self,
+        n: usize,
+        key: impl Fn(&str) -> String,
+    ) -> Result<Vec<CachedData>> {
+        let months = self.cached_months(&key)?;
+        let mut snapshots = Vec::new();
+        for month in &months[months.len().saturating_sub(n)..] {
+            match self.load_any(&key(month)) {
+                Ok(Some(snapshot)) => snapshots.push(snapshot),
+                Ok(None) | Err(Error::CacheCorrupt(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(snapshots)
+    }
+
+    fn get_cache_file_path(&self, key: &str) -> PathBuf {
+        self.cache_dir.join(format!("{}{}", key, SNAPSHOT_SUFFIX))
+    }
+
+    fn get_legacy_cache_file_path(&self, key: &str) -> PathBuf {
+        self.cache_dir
+            .join(format!("{}{}", key, LEGACY_SNAPSHOT_SUFFIX))
+    }
 
+    fn remove_snapshot(&self, key: &str) -> Result<()> {
+        for cache_file in [
+            self.get_cache_file_path(key),
+            self.get_legacy_cache_file_path(key),
+        ] {
+            match fs::remove_file(&cache_file) {
+                Ok(()) => {}
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => {
+                    return Err(Error::io(format!("Failed to remove file for {}", key), e));
+                }
+            }
+        }
         Ok(())
     }
+}
+
+/// Cache key of a snapshot file name, compressed or legacy; `None` for anything else.
+fn snapshot_key(file_name: &str) -> Option<&str> {
+    file_name
+        .strip_suffix(SNAPSHOT_SUFFIX)
+        .or_else(|| file_name.strip_suffix(LEGACY_SNAPSHOT_SUFFIX))
+}
 
-    fn get_cache_file_path(&self, month: &str) -> Result<PathBuf> {
-        Ok(self.cache_dir.join(format!("{}.json", month)))
+/// Build the cache key for a month fetched by the given date field.
+///
+/// Created-date snapshots keep the bare `YYYY-MM` key so existing cache files stay valid;
+/// merged-date snapshots get a suffix because the same month holds a different set of PRs.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::cache::cache_key;
+/// # use gh_log::github::DateField;
+/// assert_eq!(cache_key("2025-01", DateField::Created), "2025-01");
+/// assert_eq!(cache_key("2025-01", DateField::Merged), "2025-01.merged");
+/// ```
+pub fn cache_key(month: &str, date_field: DateField) -> String {
+    match date_field {
+        DateField::Created => month.to_string(),
+        DateField::Merged => format!("{}.merged", month),
     }
 }
 
-fn is_cache_fresh(month: &str, cache_time: DateTime<Utc>) -> bool {
-    let now = Utc::now();
+/// Build the cache key for the PRs you reviewed (rather than authored) in a month.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::cache::reviewed_cache_key;
+/// # use gh_log::github::DateField;
+/// assert_eq!(reviewed_cache_key("2025-01", DateField::Created), "2025-01.reviewed");
+/// assert_eq!(reviewed_cache_key("2025-01", DateField::Merged), "2025-01.merged.reviewed");
+/// ```
+pub fn reviewed_cache_key(month: &str, date_field: DateField) -> String {
+    format!("{}.reviewed", cache_key(month, date_field))
+}
+
+/// Build the cache key for another user's PRs in a month (`print --author-file`). Logins are
+/// case-insensitive on GitHub, so the key is lowercased.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::cache::author_cache_key;
+/// # use gh_log::github::DateField;
+/// assert_eq!(author_cache_key("2025-01", DateField::Created, "Octocat"), "2025-01.author-octocat");
+/// ```
+pub fn author_cache_key(month: &str, date_field: DateField, login: &str) -> String {
+    format!(
+        "{}.author-{}",
+        cache_key(month, date_field),
+        login.to_lowercase()
+    )
+}
+
+/// Narrow any of the keys above to PRs in one organization's repositories (`--org`), so scoped
+/// snapshots never stand in for unscoped ones. Organization names are case-insensitive.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::cache::{cache_key, org_cache_key};
+/// # use gh_log::github::DateField;
+/// let key = cache_key("2025-01", DateField::Merged);
+/// assert_eq!(org_cache_key(&key, "Rust-Lang"), "2025-01.merged.org-rust-lang");
+/// ```
+pub fn org_cache_key(key: &str, org: &str) -> String {
+    format!("{}.org-{}", key, org.to_lowercase())
+}
+
+/// One snapshot file found in the cache directory, as reported by `gh-log doctor`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheFileInfo {
+    /// File name, e.g. `2025-01.json.gz` (or `2025-01.json` from older versions).
+    pub name: String,
+    /// Size on disk in bytes.
+    pub size_bytes: u64,
+    /// Last modification time.
+    pub modified: DateTime<Utc>,
+}
+
+/// List the `*.json.gz` snapshots (and legacy `*.json` ones) in `cache_dir`, sorted by name.
+///
+/// Entries whose metadata cannot be read are skipped rather than failing the whole listing.
+///
+/// # Examples
+/// ```rust,no_run
+/// # use gh_log::cache::list_cache_files;
+/// # use std::path::Path;
+/// for file in list_cache_files(Path::new("/tmp/gh-log-cache")).expect("read cache dir") {
+///     println!("{} {}", file.name, file.size_bytes);
+/// }
+/// ```
+pub fn list_cache_files(cache_dir: &Path) -> Result<Vec<CacheFileInfo>> {
+    let entries = fs::read_dir(cache_dir).map_err(|e| {
+        Error::io(
+            format!("Failed to read cache directory: {:?}", cache_dir),
+            e,
+        )
+    })?;
+
+    let mut files: Vec<CacheFileInfo> = entries
+        .filter_map(|e| e.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            snapshot_key(&name)?;
+            let metadata = entry.metadata().ok()?;
+            let modified = metadata.modified().ok()?;
+            Some(CacheFileInfo {
+                name,
+                size_bytes: metadata.len(),
+                modified: modified.into(),
+            })
+        })
+        .collect();
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(files)
+}
+
+/// Format a byte count with binary units, e.g. `1.5 KiB`.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::cache::format_size;
+/// assert_eq!(format_size(512), "512 B");
+/// assert_eq!(format_size(1536), "1.5 KiB");
+/// ```
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+// Best effort: a leftover that cannot be inspected or removed is simply tried again next run.
+fn remove_stale_temp_files(cache_dir: &Path) {
+    let Ok(entries) = fs::read_dir(cache_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != "tmp") {
+            continue;
+        }
+        let stale = entry
+            .metadata()
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age.as_secs() >= STALE_TEMP_FILE_SECS);
+        if stale {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+fn is_cache_fresh(month: &str, cache_time: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    // A timestamp ahead of `now` means the clock moved between runs; its age is unknown, so
+    // refetch instead of letting a negative age slip under every TTL comparison.
+    if cache_time > now {
+        return false;
+    }
     let age = now - cache_time;
 
+    // Day windows ("2025-01-15", "2025-01-13..2025-01-19") and ranges age with the month they end
+    // in; one that runs into this month or past it is still filling up.
+    let month = month
+        .rsplit("..")
+        .next()
+        .and_then(|end| end.get(..7))
+        .unwrap_or(month);
     let current_month = now.format("%Y-%m").to_string();
     let last_month = (now - Duration::days(LAST_MONTH_LOOKBACK_DAYS))
         .format("%Y-%m")
         .to_string();
 
     match month {
-        m if m == current_month => age < Duration::hours(CURRENT_MONTH_CACHE_TTL_HOURS),
+        m if m >= current_month.as_str() => age < Duration::hours(CURRENT_MONTH_CACHE_TTL_HOURS),
         m if m == last_month => age < Duration::hours(PREVIOUS_MONTH_CACHE_TTL_HOURS),
         _ => true,
     }
@@ -196,15 +632,22 @@ mod tests {
             number: 1,
             title: "Test PR".to_string(),
             body: None,
+            author: None,
             repository: Repository {
                 name_with_owner: "test/repo".to_string(),
             },
             created_at: fixed_time,
             updated_at: fixed_time,
+            merged_at: None,
+            state: None,
             additions: 10,
             deletions: 5,
             changed_files: 2,
-            reviews: Reviews { nodes: vec![] },
+            reviews: Reviews {
+                nodes: vec![],
+                total_count: None,
+            },
+            labels: vec![],
         }
     }
 
@@ -212,10 +655,13 @@ mod tests {
         use chrono::TimeZone;
         let fixed_time = Utc.with_ymd_and_hms(2025, 1, 15, 10, 0, 0).unwrap();
         CachedData {
+            version: CACHE_VERSION,
             month: month.to_string(),
             timestamp: fixed_time,
             prs: (0..pr_count).map(|_| create_test_pr()).collect(),
-            reviewed_count: 0,
+            reviewed_count: Some(0),
+            reviewed_by_repo: None,
+            review_turnaround_secs: None,
         }
     }
 
@@ -225,14 +671,111 @@ mod tests {
         let current_month = now.format("%Y-%m").to_string();
 
         let cache_time = now - Duration::hours(1);
-        assert!(is_cache_fresh(&current_month, cache_time));
+        assert!(is_cache_fresh(&current_month, cache_time, now));
 
         let cache_time = now - Duration::hours(7);
-        assert!(!is_cache_fresh(&current_month, cache_time));
+        assert!(!is_cache_fresh(&current_month, cache_time, now));
 
         let old_month = "2020-01";
         let cache_time = now - Duration::days(365);
-        assert!(is_cache_fresh(old_month, cache_time));
+        assert!(is_cache_fresh(old_month, cache_time, now));
+    }
+
+    #[test]
+    fn test_cache_freshness_with_skewed_clock() {
+        let now = Utc::now();
+        let current_month = now.format("%Y-%m").to_string();
+
+        // Written by a clock running ahead: its age is unknown, so it is never fresh.
+        let future = now + Duration::days(2);
+        assert!(!is_cache_fresh(&current_month, future, now));
+        assert!(!is_cache_fresh("2020-01", future, now));
+
+        let far_past = now - Duration::days(400);
+        assert!(!is_cache_fresh(&current_month, far_past, now));
+        assert!(is_cache_fresh("2020-01", far_past, now));
+    }
+
+    #[test]
+    fn test_load_reports_snapshots_from_the_future() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        let mut data = create_test_cached_data("2020-01", 1);
+        data.timestamp = Utc::now() + Duration::days(2);
+        cache.save("2020-01", &data).unwrap();
+
+        assert!(matches!(
+            cache.load("2020-01"),
+            Err(Error::CacheFromFuture { key, .. }) if key == "2020-01"
+        ));
+        // Offline runs still get the data.
+        assert_eq!(cache.load_any("2020-01").unwrap().unwrap().prs.len(), 1);
+    }
+
+    #[test]
+    fn test_load_any_serves_stale_snapshots() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        let current_month = Utc::now().format("%Y-%m").to_string();
+        let mut data = create_test_cached_data(&current_month, 2);
+        data.timestamp = Utc::now() - Duration::days(2);
+        cache.save(&current_month, &data).unwrap();
+
+        let offline = cache.load_any(&current_month).unwrap().unwrap();
+        assert_eq!(offline.prs.len(), 2);
+        assert!(cache.load(&current_month).unwrap().is_none());
+        assert!(cache.load_any(&current_month).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_day_windows_expire_with_their_month() {
+        let now = Utc::now();
+        let today = now.format("%Y-%m-%d").to_string();
+        let stale = now - Duration::hours(7);
+
+        assert!(!is_cache_fresh(&today, stale, now));
+        assert!(!is_cache_fresh(
+            &format!("{}..{}", today, today),
+            stale,
+            now
+        ));
+        assert!(is_cache_fresh("2020-01-06..2020-01-12", stale, now));
+    }
+
+    #[test]
+    fn test_current_year_and_quarter_refresh_like_the_current_month() {
+        use crate::data::{quarter_range, year_range};
+        use chrono::Datelike;
+        let now = Utc::now();
+        let stale = now - Duration::hours(7);
+        let year = year_range(now.year());
+        let quarter = quarter_range(now.year(), now.month0() / 3 + 1).unwrap();
+
+        assert!(!is_cache_fresh(&year, stale, now));
+        assert!(!is_cache_fresh(&quarter, stale, now));
+        assert!(is_cache_fresh(&year_range(2020), stale, now));
+
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        let mut data = create_test_cached_data(&year, 1);
+        data.timestamp = stale;
+        cache.save(&year, &data).unwrap();
+        assert!(cache.load(&year).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_ranges_into_the_future_age_like_the_current_month() {
+        let now = Utc::now();
+        let stale = now - Duration::hours(7);
+        let start = (now - Duration::days(40)).format("%Y-%m-%d");
+        let end = (now + Duration::days(40)).format("%Y-%m-%d");
+
+        assert!(!is_cache_fresh(&format!("{}..{}", start, end), stale, now));
+        assert!(is_cache_fresh(
+            &format!("{}..{}", start, end),
+            now - Duration::hours(1),
+            now
+        ));
     }
 
     #[test]
@@ -241,23 +784,72 @@ mod tests {
         let cache = Cache::new(temp_dir.path().to_path_buf(), 3).unwrap();
 
         let data = create_test_cached_data("2025-01", 2);
-        cache.save(&data).unwrap();
+        cache.save("2025-01", &data).unwrap();
 
         let loaded = cache.load("2025-01").unwrap();
         assert!(loaded.is_some());
 
-        let cache_file = cache.get_cache_file_path("2025-01").unwrap();
-        let json = fs::read_to_string(cache_file).unwrap();
+        let cache_file = cache.get_cache_file_path("2025-01");
+        let mut json = String::new();
+        GzDecoder::new(fs::File::open(cache_file).unwrap())
+            .read_to_string(&mut json)
+            .unwrap();
         insta::assert_snapshot!(json);
     }
 
+    #[test]
+    fn test_large_body_roundtrips_compressed() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        let mut data = create_test_cached_data("2025-01", 3);
+        for pr in &mut data.prs {
+            pr.body = Some("- [x] Tests added\n- [x] Docs updated\n".repeat(5_000));
+        }
+
+        cache.save("2025-01", &data).unwrap();
+
+        let compressed = fs::metadata(cache.get_cache_file_path("2025-01"))
+            .unwrap()
+            .len();
+        let json = serde_json::to_string_pretty(&data).unwrap();
+        assert!(compressed * 10 < json.len() as u64);
+        let loaded = cache.load("2025-01").unwrap().unwrap();
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&data).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_legacy_json_snapshot_is_read_and_replaced() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        let data = create_test_cached_data("2025-01", 2);
+        let legacy_file = cache.get_legacy_cache_file_path("2025-01");
+        fs::write(&legacy_file, serde_json::to_string(&data).unwrap()).unwrap();
+
+        assert_eq!(cache.load_any("2025-01").unwrap().unwrap().prs.len(), 2);
+        assert_eq!(
+            cache
+                .cached_months(|month| cache_key(month, DateField::Created))
+                .unwrap(),
+            ["2025-01"]
+        );
+
+        cache
+            .save("2025-01", &create_test_cached_data("2025-01", 1))
+            .unwrap();
+        assert!(!legacy_file.exists());
+        assert_eq!(cache.load_any("2025-01").unwrap().unwrap().prs.len(), 1);
+    }
+
     #[test]
     fn test_save_fails_with_too_many_prs() {
         let temp_dir = TempDir::new().unwrap();
         let cache = Cache::new(temp_dir.path().to_path_buf(), 10).unwrap();
 
         let data = create_test_cached_data("2025-01", 11);
-        let result = cache.save(&data);
+        let result = cache.save("2025-01", &data);
 
         assert!(result.is_err());
         insta::assert_snapshot!(result.unwrap_err());
@@ -273,14 +865,17 @@ mod tests {
         let stale_timestamp = now - Duration::hours(10);
 
         let stale_data = CachedData {
+            version: CACHE_VERSION,
             month: current_month.clone(),
             timestamp: stale_timestamp,
             prs: vec![create_test_pr()],
-            reviewed_count: 0,
+            reviewed_count: Some(0),
+            reviewed_by_repo: None,
+            review_turnaround_secs: None,
         };
 
-        cache.save(&stale_data).unwrap();
-        let cache_file = cache.get_cache_file_path(&current_month).unwrap();
+        cache.save(&current_month, &stale_data).unwrap();
+        let cache_file = cache.get_cache_file_path(&current_month);
         assert!(cache_file.exists());
 
         let result = cache.load(&current_month).unwrap();
@@ -289,15 +884,226 @@ mod tests {
     }
 
     #[test]
-    fn test_corrupted_cache_file_returns_error() {
+    fn test_corrupted_cache_file_is_a_miss() {
         let temp_dir = TempDir::new().unwrap();
         let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
 
-        let cache_file = cache.get_cache_file_path("2025-01").unwrap();
-        fs::write(&cache_file, "{ invalid json }").unwrap();
+        let cache_file = cache.get_cache_file_path("2025-01");
+        fs::write(&cache_file, "{ \"month\": \"2025-01\", \"pr").unwrap();
 
-        let result = cache.load("2025-01");
-        assert!(result.is_err());
-        insta::assert_snapshot!(result.unwrap_err());
+        let result = cache.load_any("2025-01");
+        assert!(
+            matches!(&result, Err(Error::CacheCorrupt(path)) if *path == cache_file),
+            "expected CacheCorrupt for {:?}, got {:?}",
+            cache_file,
+            result
+        );
+
+        assert!(cache.load("2025-01").unwrap().is_none());
+        assert!(!cache_file.exists());
+    }
+
+    #[test]
+    fn test_cached_months_skips_other_snapshots() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        for key in [
+            "2025-02",
+            "2024-12",
+            "2025-01.merged",
+            "2025-01.reviewed",
+            "2025-01.author-octocat",
+            "2025-01-06..2025-01-12",
+        ] {
+            fs::write(cache.get_cache_file_path(key), "{}").unwrap();
+        }
+
+        assert_eq!(
+            cache
+                .cached_months(|month| cache_key(month, DateField::Created))
+                .unwrap(),
+            ["2024-12", "2025-02"]
+        );
+        assert_eq!(
+            cache
+                .cached_months(|month| cache_key(month, DateField::Merged))
+                .unwrap(),
+            ["2025-01"]
+        );
+        assert_eq!(
+            cache
+                .cached_months(|month| author_cache_key(month, DateField::Created, "octocat"))
+                .unwrap(),
+            ["2025-01"]
+        );
+    }
+
+    #[test]
+    fn test_load_recent_months_keeps_newest_readable_snapshots() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        for month in ["2024-11", "2024-12", "2025-02"] {
+            let snapshot = format!(
+                r#"{{"version":{},"month":"{}","timestamp":"2025-03-01T00:00:00Z","prs":[],"reviewed_count":0}}"#,
+                CACHE_VERSION, month
+            );
+            fs::write(cache.get_legacy_cache_file_path(month), snapshot).unwrap();
+        }
+        fs::write(cache.get_cache_file_path("2025-01"), "not json").unwrap();
+
+        let months: Vec<String> = cache
+            .load_recent_months(3, |month| cache_key(month, DateField::Created))
+            .unwrap()
+            .into_iter()
+            .map(|snapshot| snapshot.month)
+            .collect();
+        assert_eq!(months, ["2024-12", "2025-02"]);
+        assert!(
+            cache
+                .load_recent_months(3, |month| cache_key(month, DateField::Merged))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_unversioned_cache_file_is_discarded() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+
+        let cache_file = cache.get_legacy_cache_file_path("2020-01");
+        let v0 =
+            r#"{"month":"2020-01","timestamp":"2020-02-01T00:00:00Z","prs":[],"reviewed_count":0}"#;
+        fs::write(&cache_file, v0).unwrap();
+
+        assert_eq!(cache.load_any("2020-01").unwrap().unwrap().version, 0);
+        assert!(cache.load("2020-01").unwrap().is_none());
+        assert!(!cache_file.exists());
+    }
+
+    #[test]
+    fn test_save_leaves_no_temp_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        let data = CachedData {
+            version: CACHE_VERSION,
+            month: "2025-01".to_string(),
+            timestamp: Utc::now(),
+            prs: vec![create_test_pr()],
+            reviewed_count: Some(1),
+            reviewed_by_repo: None,
+            review_turnaround_secs: None,
+        };
+
+        cache.save("2025-01", &data).unwrap();
+        cache.save("2025-01", &data).unwrap();
+
+        let names: Vec<String> = fs::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["2025-01.json.gz"]);
+        assert_eq!(cache.load("2025-01").unwrap().unwrap().prs.len(), 1);
+    }
+
+    #[test]
+    fn test_new_removes_stale_temp_files_only() {
+        let temp_dir = TempDir::new().unwrap();
+        let stale = temp_dir.path().join("2025-01.json.4242.tmp");
+        let fresh = temp_dir.path().join("2025-02.json.4243.tmp");
+        fs::write(&stale, "{ \"month\"").unwrap();
+        fs::write(&fresh, "{ \"month\"").unwrap();
+        let two_hours_ago =
+            std::time::SystemTime::now() - std::time::Duration::from_secs(2 * 60 * 60);
+        fs::File::options()
+            .write(true)
+            .open(&stale)
+            .unwrap()
+            .set_modified(two_hours_ago)
+            .unwrap();
+
+        Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+
+        assert!(!stale.exists());
+        assert!(fresh.exists());
+    }
+
+    #[test]
+    fn test_merged_snapshot_is_stored_separately() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+
+        let created_key = cache_key("2025-01", DateField::Created);
+        let merged_key = cache_key("2025-01", DateField::Merged);
+        cache
+            .save(&created_key, &create_test_cached_data("2025-01", 2))
+            .unwrap();
+        cache
+            .save(&merged_key, &create_test_cached_data("2025-01", 1))
+            .unwrap();
+
+        assert_eq!(cache.load(&created_key).unwrap().unwrap().prs.len(), 2);
+        assert_eq!(cache.load(&merged_key).unwrap().unwrap().prs.len(), 1);
+    }
+
+    #[test]
+    fn test_data_source_describe() {
+        let now = Utc::now();
+        let cases = [
+            (Duration::seconds(20), "Data as of just now"),
+            (Duration::minutes(45), "Data as of 45m ago"),
+            (Duration::hours(6), "Data as of 6h ago"),
+            (Duration::days(3), "Data as of 3d ago"),
+        ];
+        for (age, expected) in cases {
+            assert_eq!(DataSource::Cache(now - age).describe(now), expected);
+        }
+        assert_eq!(DataSource::Fresh.describe(now), "fresh");
+    }
+
+    #[test]
+    fn test_list_cache_files_reports_sizes() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        cache
+            .save("2025-02", &create_test_cached_data("2025-02", 3))
+            .unwrap();
+        cache
+            .save("2025-01", &create_test_cached_data("2025-01", 1))
+            .unwrap();
+        fs::write(temp_dir.path().join("notes.txt"), "not a snapshot").unwrap();
+
+        let files = list_cache_files(temp_dir.path()).unwrap();
+
+        let names: Vec<&str> = files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["2025-01.json.gz", "2025-02.json.gz"]);
+        assert!(files[1].size_bytes > files[0].size_bytes);
+        assert_eq!(
+            files[0].size_bytes,
+            fs::metadata(temp_dir.path().join("2025-01.json.gz"))
+                .unwrap()
+                .len()
+        );
+    }
+
+    #[test]
+    fn test_format_size_units() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1024), "1.0 KiB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn test_snapshot_without_reviewed_count_roundtrips() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+
+        let mut data = create_test_cached_data("2025-01", 1);
+        data.reviewed_count = None;
+        cache.save("2025-01", &data).unwrap();
+
+        let loaded = cache.load("2025-01").unwrap().unwrap();
+        assert_eq!(loaded.reviewed_count, None);
     }
 }
@@ -3,13 +3,24 @@
 //! Loads the on-disk TOML config, applies repo/title filters, and keeps size thresholds consistent
 //! across the CLI.
 
-use anyhow::{Context, Result};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Local, Months, NaiveDate, Offset, TimeZone, Utc,
+};
 use directories::ProjectDirs;
+use ratatui::style::Color;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::{fs, panic};
 
+use crate::data::{GroupBy, PRSize, RepoSort, SortOrder};
+use crate::error::{Error, Result};
+use crate::github::{Author, DateField, StateFilter};
+
+/// Furthest back `[defaults] month_offset` reaches: ten years.
+const MAX_MONTH_OFFSET: u32 = 120;
+
 /// Config mirrors the on-disk TOML layout, exposes filters and size thresholds, and keeps the resolved path cached.
 /// CLI commands load it once so they can print or rewrite the same file without reparsing directory hints from scratch.
 ///
@@ -27,6 +38,30 @@ pub struct Config {
     /// Size thresholds that bucket PRs into S/M/L/XL bands for analytics output.
     #[serde(default)]
     pub size: SizeConfig,
+    /// Login aliases that should be counted as a single person.
+    #[serde(default)]
+    pub identity: IdentityConfig,
+    /// Knobs for derived metrics such as the review balance ratio.
+    #[serde(default)]
+    pub analytics: AnalyticsConfig,
+    /// Goals that turn passive stats into warnings when a month misses them.
+    #[serde(default)]
+    pub targets: TargetsConfig,
+    /// Review habits to aim for, shown next to the review balance.
+    #[serde(default)]
+    pub review: ReviewConfig,
+    /// How to reach the GitHub CLI when it is not simply `gh` on `PATH`.
+    #[serde(default)]
+    pub gh: GhConfig,
+    /// How report output decorates PRs, e.g. linking issue keys found in titles.
+    #[serde(default)]
+    pub display: DisplayConfig,
+    /// Colors of the `view` TUI, for terminals where the defaults are hard to read.
+    #[serde(default)]
+    pub theme: ThemeConfig,
+    /// Flag values saved with `--save-config`, used when the flag is not given on the command line.
+    #[serde(default, skip_serializing_if = "DefaultsConfig::is_empty")]
+    pub defaults: DefaultsConfig,
     /// Cached on-disk location of the underlying TOML file for reuse by CLI commands.
     #[serde(skip)]
     config_path: PathBuf,
@@ -50,6 +85,16 @@ pub struct Config {
 /// Checklist: keep `validate()` and `matches_patterns()` in sync when adding new filter fields.
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct FilterConfig {
+    /// When non-empty, only PRs in these repositories are reported.
+    #[serde(default)]
+    pub include_repos: Vec<String>,
+    /// When non-empty, only PRs whose titles match one of these regexes are reported.
+    #[serde(default)]
+    pub include_patterns: Vec<String>,
+    /// When non-empty, only PRs carrying at least one of these GitHub labels are reported.
+    /// Names match case-insensitively, as on GitHub.
+    #[serde(default)]
+    pub include_labels: Vec<String>,
     /// Repository names removed entirely from analytics output.
     #[serde(default)]
     pub exclude_repos: Vec<String>,
@@ -62,17 +107,732 @@ pub struct FilterConfig {
     /// Regexes that keep PRs visible yet exclude them from key performance metrics.
     #[serde(default)]
     pub ignore_patterns: Vec<String>,
+    /// Extra regexes for bot logins, on top of GitHub's own bot accounts and `[bot]` suffixes.
+    #[serde(default)]
+    pub bot_patterns: Vec<String>,
+    /// Keep bot-authored PRs and bot reviewers instead of dropping them (`--include-bots`).
+    #[serde(default)]
+    pub include_bots: bool,
+    /// Only report PRs in this state (`--state`); all of them by default.
+    #[serde(default)]
+    pub state: StateFilter,
+}
+
+/// Groups of GitHub logins that belong to the same person, e.g. an old and a new account.
+/// The first login in each group is the canonical name shown in reports.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::IdentityConfig;
+/// let identity = IdentityConfig {
+///     aliases: vec![vec!["alice".into(), "alice-old".into()]],
+/// };
+/// assert_eq!(identity.canonical_login("alice-old"), "alice");
+/// assert_eq!(identity.canonical_login("bob"), "bob");
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IdentityConfig {
+    /// Alias groups; each inner list maps every login to its first entry.
+    #[serde(default)]
+    pub aliases: Vec<Vec<String>>,
+}
+
+impl IdentityConfig {
+    /// Resolve a login to the canonical name of its alias group, or return it unchanged.
+    pub fn canonical_login<'a>(&'a self, login: &'a str) -> &'a str {
+        self.aliases
+            .iter()
+            .find(|group| group.iter().any(|alias| alias == login))
+            .and_then(|group| group.first())
+            .map_or(login, String::as_str)
+    }
+}
+
+/// Settings for derived metrics that have more than one reasonable definition.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::{AnalyticsConfig, ReviewBalanceBase};
+/// let analytics: AnalyticsConfig = toml::from_str("review_balance_base = \"merged\"").unwrap();
+/// assert_eq!(analytics.review_balance_base, ReviewBalanceBase::Merged);
+/// assert_eq!(analytics.lead_time_buckets(), [1, 4, 24, 72]);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AnalyticsConfig {
+    /// Denominator for "Review Balance" (PRs reviewed per PR of your own).
+    #[serde(default)]
+    pub review_balance_base: ReviewBalanceBase,
+    /// Lead-time histogram edges in hours, ascending; `[1, 4, 24, 72]` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead_time_buckets: Option<Vec<u32>>,
+}
+
+impl AnalyticsConfig {
+    const DEFAULT_LEAD_TIME_BUCKETS: [u32; 4] = [1, 4, 24, 72];
+
+    fn validate(&self) -> Result<()> {
+        let Some(edges) = &self.lead_time_buckets else {
+            return Ok(());
+        };
+        let ascending = edges.windows(2).all(|pair| pair[0] < pair[1]);
+        if edges.is_empty() || edges[0] == 0 || !ascending {
+            return Err(Error::InvalidConfigValue {
+                field: "analytics.lead_time_buckets",
+                message: format!("{:?} must be non-empty, ascending hours above zero", edges),
+            });
+        }
+        Ok(())
+    }
+
+    /// Histogram edges in hours: PRs under the first edge, between each pair, and past the last.
+    pub fn lead_time_buckets(&self) -> &[u32] {
+        self.lead_time_buckets
+            .as_deref()
+            .unwrap_or(&Self::DEFAULT_LEAD_TIME_BUCKETS)
+    }
+}
+
+/// Which of your own PRs the review balance is measured against.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewBalanceBase {
+    /// Every PR counted in metrics (ignored PRs excluded).
+    #[default]
+    Created,
+    /// Counted PRs that have been merged.
+    Merged,
+    /// Counted PRs that received at least one review, i.e. ones that asked for reviewer time.
+    ReviewedEligible,
+}
+
+impl ReviewBalanceBase {
+    /// Short noun used when printing the ratio, e.g. "3 reviewed / 2 merged".
+    pub fn label(self) -> &'static str {
+        match self {
+            ReviewBalanceBase::Created => "created",
+            ReviewBalanceBase::Merged => "merged",
+            ReviewBalanceBase::ReviewedEligible => "review-eligible",
+        }
+    }
+}
+
+/// Optional goals checked against each month; unset targets never warn.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::TargetsConfig;
+/// let targets: TargetsConfig = toml::from_str("max_xl_ratio = 0.2").unwrap();
+/// assert_eq!(targets.max_xl_ratio, Some(0.2));
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TargetsConfig {
+    /// Highest acceptable share of XL PRs, as a fraction between 0 and 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_xl_ratio: Option<f64>,
+}
+
+impl TargetsConfig {
+    fn validate(&self) -> Result<()> {
+        if let Some(ratio) = self.max_xl_ratio
+            && !(0.0..=1.0).contains(&ratio)
+        {
+            return Err(Error::InvalidConfigValue {
+                field: "targets.max_xl_ratio",
+                message: format!("{} is not between 0 and 1", ratio),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Review balance goal: the summary colors the ratio green at or above `target_ratio` and red
+/// below it.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::ReviewConfig;
+/// let review: ReviewConfig = toml::from_str("target_ratio = 1.0").unwrap();
+/// assert_eq!(review.meets_target(1.5), Some(true));
+/// assert_eq!(review.meets_target(0.5), Some(false));
+/// assert_eq!(ReviewConfig::default().meets_target(0.5), None);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ReviewConfig {
+    /// PRs reviewed per PR of your own to aim for, e.g. `1.0` to review as much as you create.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_ratio: Option<f64>,
+}
+
+impl ReviewConfig {
+    fn validate(&self) -> Result<()> {
+        if let Some(ratio) = self.target_ratio
+            && !(ratio.is_finite() && ratio > 0.0)
+        {
+            return Err(Error::InvalidConfigValue {
+                field: "review.target_ratio",
+                message: format!("{} is not a positive number", ratio),
+            });
+        }
+        Ok(())
+    }
+
+    /// Whether `balance` reaches `target_ratio`; `None` when no target is set.
+    pub fn meets_target(&self, balance: f64) -> Option<bool> {
+        self.target_ratio.map(|target| balance >= target)
+    }
+}
+
+/// Saved flag values that sit between an explicit flag and the built-in default.
+///
+/// Precedence is explicit flag > saved default > built-in default. `view --save-config` and
+/// `print --save-config` write the flags given on that run here; `month_offset` is only ever set
+/// by hand.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::DefaultsConfig;
+/// # use gh_log::github::DateField;
+/// let defaults: DefaultsConfig = toml::from_str(r#"by = "merged""#).unwrap();
+/// assert_eq!(defaults.by, Some(DateField::Merged));
+/// assert_eq!(defaults.group_by, None);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultsConfig {
+    /// `--by`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by: Option<DateField>,
+    /// `print --group-by`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<GroupBy>,
+    /// `--sort-repos`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_repos: Option<RepoSort>,
+    /// `--sort-order`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<SortOrder>,
+    /// `print --format`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<PrintFormat>,
+    /// Months before the current one that `--month` falls back to: 1 reports last month.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub month_offset: Option<u32>,
+}
+
+/// `print --format` choices (and `[defaults] format`); `--json`, `--csv`, `--markdown` and
+/// `--html` remain as shorthands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PrintFormat {
+    Text,
+    Json,
+    Csv,
+    Table,
+    Markdown,
+    Html,
+}
+
+/// Flags `--save-config` writes to the section that already holds the setting, rather than to
+/// `[defaults]`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SavedSettings {
+    /// `--state`, written as `[filter] state`.
+    pub state: Option<StateFilter>,
+    /// `--tz`, written as `[display] timezone`.
+    pub timezone: Option<Timezone>,
+}
+
+impl DefaultsConfig {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Values from `self` where set, `other` otherwise.
+    pub fn or(self, other: Self) -> Self {
+        DefaultsConfig {
+            by: self.by.or(other.by),
+            group_by: self.group_by.or(other.group_by),
+            sort_repos: self.sort_repos.or(other.sort_repos),
+            sort_order: self.sort_order.or(other.sort_order),
+            format: self.format.or(other.format),
+            month_offset: self.month_offset.or(other.month_offset),
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let Some(offset) = self.month_offset
+            && offset > MAX_MONTH_OFFSET
+        {
+            return Err(Error::InvalidConfigValue {
+                field: "defaults.month_offset",
+                message: format!("{} is more than {} months back", offset, MAX_MONTH_OFFSET),
+            });
+        }
+        Ok(())
+    }
+
+    /// The `YYYY-MM` month `today` falls in, moved back by `month_offset` months.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use gh_log::config::DefaultsConfig;
+    /// let today = chrono::NaiveDate::from_ymd_opt(2026, 1, 20).unwrap();
+    /// let last_month = DefaultsConfig { month_offset: Some(1), ..Default::default() };
+    /// assert_eq!(last_month.month(today), "2025-12");
+    /// assert_eq!(DefaultsConfig::default().month(today), "2026-01");
+    /// ```
+    pub fn month(&self, today: NaiveDate) -> String {
+        let offset = Months::new(self.month_offset.unwrap_or(0));
+        today
+            .with_day(1)
+            .and_then(|first| first.checked_sub_months(offset))
+            .unwrap_or(today)
+            .format("%Y-%m")
+            .to_string()
+    }
+}
+
+/// Location of the GitHub CLI executable.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::GhConfig;
+/// let gh: GhConfig = toml::from_str(r#"binary = "/opt/gh/bin/gh""#).unwrap();
+/// assert_eq!(gh.binary.as_deref(), Some(std::path::Path::new("/opt/gh/bin/gh")));
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GhConfig {
+    /// Full path to `gh`; `GH_LOG_GH_PATH` takes precedence when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binary: Option<PathBuf>,
+    /// Reviews fetched per PR (1-100); higher values cost more API time but count every reviewer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_fetch_limit: Option<usize>,
+}
+
+impl GhConfig {
+    /// GitHub rejects connection page sizes above 100.
+    const MAX_REVIEW_FETCH_LIMIT: usize = 100;
+
+    fn validate(&self) -> Result<()> {
+        if let Some(limit) = self.review_fetch_limit
+            && !(1..=Self::MAX_REVIEW_FETCH_LIMIT).contains(&limit)
+        {
+            return Err(Error::InvalidConfigValue {
+                field: "gh.review_fetch_limit",
+                message: format!(
+                    "{} is not between 1 and {}",
+                    limit,
+                    Self::MAX_REVIEW_FETCH_LIMIT
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Issue-tracker keys to pull out of PR titles, such as `[PROJ-123]`, and where they link to.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::{DisplayConfig, extract_issues};
+/// let mut display = DisplayConfig::default();
+/// display.issue_url_template = Some("https://jira.example.com/browse/{id}".to_string());
+/// display.set_issue_pattern(r"\[([A-Z]+-\d+)\]").unwrap();
+/// let re = display.issue_regex().unwrap();
+/// assert_eq!(extract_issues(re, "[PROJ-123] Fix login"), ["PROJ-123"]);
+/// assert_eq!(
+///     display.issue_url("PROJ-123").as_deref(),
+///     Some("https://jira.example.com/browse/PROJ-123")
+/// );
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DisplayConfig {
+    /// Regex matched against titles; the first capture group is the issue id, or the whole match
+    /// when the pattern has no groups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_pattern: Option<String>,
+    /// `issue_pattern` compiled once when the config is loaded.
+    #[serde(skip)]
+    issue_regex: Option<Regex>,
+    /// Link for an issue id, with `{id}` standing in for it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_url_template: Option<String>,
+    /// Tag after the title of an ignored PR in the TUI lists; `""` leaves just the dimming.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignored_marker: Option<String>,
+    /// Zone for dates and week boundaries; the system's local zone when unset (`--tz`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<Timezone>,
+}
+
+/// Colors of the TUI by role, resolved from `[theme]` with [`ThemeConfig::theme`]. The defaults
+/// suit a dark terminal. Label chips are the one exception: they cycle through a fixed palette so
+/// a label keeps its color everywhere, and the values on the Trend bars are drawn in black over
+/// the bar's own color.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::Theme;
+/// use ratatui::style::Color;
+/// assert_eq!(Theme::default().size_xl, Color::Red);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub size_s: Color,
+    pub size_m: Color,
+    pub size_l: Color,
+    pub size_xl: Color,
+    /// PRs GitHub reported no line counts for.
+    pub size_unknown: Color,
+    /// Repository names, PR references and totals.
+    pub accent: Color,
+    /// Lead times, warnings and prompts.
+    pub highlight: Color,
+    /// PR counts and frequency.
+    pub count: Color,
+    /// Section rules, group headers and key hints.
+    pub header: Color,
+    /// Borders, dates and secondary notes.
+    pub muted: Color,
+    /// The view mode next to the month in the header.
+    pub mode: Color,
+    /// The add:delete ratio.
+    pub ratio: Color,
+    /// The review balance when no `[review] target_ratio` is set.
+    pub balance: Color,
+    /// A review balance that meets its target.
+    pub on_target: Color,
+    /// Load errors, weeks without PRs and a review balance short of its target.
+    pub alert: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            size_s: Color::Green,
+            size_m: Color::Blue,
+            size_l: Color::Yellow,
+            size_xl: Color::Red,
+            size_unknown: Color::Magenta,
+            accent: Color::Blue,
+            highlight: Color::Yellow,
+            count: Color::Green,
+            header: Color::Gray,
+            muted: Color::DarkGray,
+            mode: Color::Cyan,
+            ratio: Color::Magenta,
+            balance: Color::Cyan,
+            on_target: Color::Green,
+            alert: Color::Red,
+        }
+    }
+}
+
+impl Theme {
+    /// The color of a size bucket.
+    pub fn size(&self, size: PRSize) -> Color {
+        match size {
+            PRSize::S => self.size_s,
+            PRSize::M => self.size_m,
+            PRSize::L => self.size_l,
+            PRSize::XL => self.size_xl,
+            PRSize::Unknown => self.size_unknown,
+        }
+    }
+}
+
+/// Colors of the `view` TUI by role. Each is a color name such as `"lightblue"` or `"darkgray"`,
+/// an ANSI index such as `"33"`, or `"#rrggbb"`; roles left unset keep their [`Theme::default`]
+/// color.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::ThemeConfig;
+/// use ratatui::style::Color;
+/// let theme: ThemeConfig = toml::from_str(r#"header = "black""#).unwrap();
+/// assert_eq!(theme.theme().header, Color::Black);
+/// assert_eq!(theme.theme().size_s, Color::Green);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ThemeConfig {
+    /// Size S PRs; default green.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_s: Option<String>,
+    /// Size M PRs; default blue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_m: Option<String>,
+    /// Size L PRs; default yellow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_l: Option<String>,
+    /// Size XL PRs; default red.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_xl: Option<String>,
+    /// PRs without line counts; default magenta.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_unknown: Option<String>,
+    /// Repository names, PR references and totals; default blue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<String>,
+    /// Lead times, warnings and prompts; default yellow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub highlight: Option<String>,
+    /// PR counts and frequency; default green.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<String>,
+    /// Section rules, group headers and key hints; default gray.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// Borders, dates and secondary notes; default darkgray.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted: Option<String>,
+    /// The view mode in the header; default cyan.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    /// The add:delete ratio; default magenta.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ratio: Option<String>,
+    /// The review balance without a target ratio; default cyan.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<String>,
+    /// A review balance that meets its target; default green.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_target: Option<String>,
+    /// Load errors, weeks without PRs and a missed review target; default red.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert: Option<String>,
+}
+
+impl ThemeConfig {
+    fn roles(&self) -> [(&'static str, &Option<String>); 15] {
+        [
+            ("theme.size_s", &self.size_s),
+            ("theme.size_m", &self.size_m),
+            ("theme.size_l", &self.size_l),
+            ("theme.size_xl", &self.size_xl),
+            ("theme.size_unknown", &self.size_unknown),
+            ("theme.accent", &self.accent),
+            ("theme.highlight", &self.highlight),
+            ("theme.count", &self.count),
+            ("theme.header", &self.header),
+            ("theme.muted", &self.muted),
+            ("theme.mode", &self.mode),
+            ("theme.ratio", &self.ratio),
+            ("theme.balance", &self.balance),
+            ("theme.on_target", &self.on_target),
+            ("theme.alert", &self.alert),
+        ]
+    }
+
+    fn validate(&self) -> Result<()> {
+        for (field, name) in self.roles() {
+            if let Some(name) = name
+                && name.parse::<ratatui::style::Color>().is_err()
+            {
+                return Err(Error::InvalidConfigValue {
+                    field,
+                    message: format!(
+                        "'{}' is not a color; use a name such as lightblue, an index 0-255, or #rrggbb",
+                        name
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The configured colors over the defaults. Names that do not parse fall back to the default
+    /// too, though loading the config already rejects them.
+    pub fn theme(&self) -> Theme {
+        let defaults = Theme::default();
+        let color = |name: &Option<String>, default| {
+            name.as_deref()
+                .and_then(|name| name.parse().ok())
+                .unwrap_or(default)
+        };
+        Theme {
+            size_s: color(&self.size_s, defaults.size_s),
+            size_m: color(&self.size_m, defaults.size_m),
+            size_l: color(&self.size_l, defaults.size_l),
+            size_xl: color(&self.size_xl, defaults.size_xl),
+            size_unknown: color(&self.size_unknown, defaults.size_unknown),
+            accent: color(&self.accent, defaults.accent),
+            highlight: color(&self.highlight, defaults.highlight),
+            count: color(&self.count, defaults.count),
+            header: color(&self.header, defaults.header),
+            muted: color(&self.muted, defaults.muted),
+            mode: color(&self.mode, defaults.mode),
+            ratio: color(&self.ratio, defaults.ratio),
+            balance: color(&self.balance, defaults.balance),
+            on_target: color(&self.on_target, defaults.on_target),
+            alert: color(&self.alert, defaults.alert),
+        }
+    }
+}
+
+/// Set `[table] key` in `doc` to `value`'s TOML string, creating the table when missing.
+fn set_string(
+    doc: &mut toml_edit::DocumentMut,
+    table: &'static str,
+    key: &str,
+    value: impl Serialize,
+) -> Result<()> {
+    let value = match toml::Value::try_from(value) {
+        Ok(toml::Value::String(value)) => value,
+        Ok(other) => other.to_string(),
+        Err(e) => {
+            return Err(Error::InvalidConfigValue {
+                field: table,
+                message: e.to_string(),
+            });
+        }
+    };
+    if !doc.contains_table(table) {
+        doc[table] = toml_edit::table();
+    }
+    doc[table][key] = toml_edit::value(value);
+    Ok(())
+}
+
+/// Zone that PR dates are shown in and weeks are cut in: the system zone, UTC, or a fixed offset
+/// such as `+02:00`.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::Timezone;
+/// use chrono::{TimeZone, Utc};
+/// let tz: Timezone = "-05:00".parse().unwrap();
+/// let sunday_night = Utc.with_ymd_and_hms(2025, 1, 13, 2, 0, 0).unwrap();
+/// assert_eq!(tz.wall_clock(sunday_night).format("%a %d").to_string(), "Sun 12");
+/// assert!("Mars/Olympus".parse::<Timezone>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Timezone {
+    #[default]
+    Local,
+    Utc,
+    Fixed(FixedOffset),
+}
+
+impl Timezone {
+    /// `at` as read on a clock in this zone, kept in a `DateTime<Utc>` so the report code can
+    /// bucket and format it without knowing the zone.
+    pub fn wall_clock(self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let offset = match self {
+            Timezone::Local => Local
+                .offset_from_utc_datetime(&at.naive_utc())
+                .fix()
+                .local_minus_utc(),
+            Timezone::Utc => 0,
+            Timezone::Fixed(offset) => offset.local_minus_utc(),
+        };
+        at + Duration::seconds(offset.into())
+    }
+}
+
+impl std::str::FromStr for Timezone {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "local" => Ok(Timezone::Local),
+            "utc" | "z" => Ok(Timezone::Utc),
+            _ => s.parse::<FixedOffset>().map(Timezone::Fixed).map_err(|_| {
+                format!(
+                    "'{}' is not a timezone; use local, utc, or an offset such as +02:00",
+                    s
+                )
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for Timezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Timezone::Local => f.write_str("local"),
+            Timezone::Utc => f.write_str("utc"),
+            Timezone::Fixed(offset) => write!(f, "{}", offset),
+        }
+    }
+}
+
+impl TryFrom<String> for Timezone {
+    type Error = String;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Timezone> for String {
+    fn from(tz: Timezone) -> Self {
+        tz.to_string()
+    }
+}
+
+impl DisplayConfig {
+    fn validate(&mut self) -> Result<()> {
+        if let Some(pattern) = self.issue_pattern.clone() {
+            self.set_issue_pattern(&pattern)?;
+        }
+        if let Some(template) = &self.issue_url_template
+            && !template.contains("{id}")
+        {
+            return Err(Error::InvalidConfigValue {
+                field: "display.issue_url_template",
+                message: format!("'{}' has no {{id}} placeholder", template),
+            });
+        }
+        Ok(())
+    }
+
+    /// Zone for dates and week boundaries, the system's local zone unless configured.
+    pub fn timezone(&self) -> Timezone {
+        self.timezone.unwrap_or_default()
+    }
+
+    /// Tag shown after an ignored PR's title, `(ignored)` unless configured.
+    pub fn ignored_marker(&self) -> &str {
+        self.ignored_marker.as_deref().unwrap_or("(ignored)")
+    }
+
+    /// Compile `pattern` and make it the `issue_pattern`, failing with
+    /// [`Error::InvalidPattern`] when it is not a valid regex.
+    pub fn set_issue_pattern(&mut self, pattern: &str) -> Result<()> {
+        let re = Regex::new(pattern).map_err(|source| Error::InvalidPattern {
+            field: "display.issue_pattern",
+            pattern: pattern.to_string(),
+            source,
+        })?;
+        self.issue_pattern = Some(pattern.to_string());
+        self.issue_regex = Some(re);
+        Ok(())
+    }
+
+    /// The compiled `issue_pattern`, once the config has been loaded.
+    pub fn issue_regex(&self) -> Option<&Regex> {
+        self.issue_regex.as_ref()
+    }
+
+    /// Link for issue `id`, when an `issue_url_template` is set.
+    pub fn issue_url(&self, id: &str) -> Option<String> {
+        self.issue_url_template
+            .as_ref()
+            .map(|template| template.replace("{id}", id))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-/// Size thresholds (in total line changes) used to categorize pull requests so every output mode
-/// labels PRs as S/M/L/XL the same way.
+/// Size thresholds (in total line changes and changed files) used to categorize pull requests so
+/// every output mode labels PRs as S/M/L/XL the same way.
 ///
 /// # Examples
 /// ```rust
 /// # use gh_log::config::SizeConfig;
-/// let sizes = SizeConfig::new(50, 200, 500);
+/// let sizes = SizeConfig::new(50, 200, 500, 15, 25)?;
 /// assert!(sizes.small < sizes.medium && sizes.medium < sizes.large);
+/// # Ok::<_, gh_log::Error>(())
 /// ```
 pub struct SizeConfig {
     /// Maximum line-change count that still qualifies as a small (S) pull request.
@@ -81,43 +841,167 @@ pub struct SizeConfig {
     pub medium: u32,
     /// Maximum line-change count considered large (L); values above this are treated as XL.
     pub large: u32,
+    /// Changed-file count that makes a pull request at least large (L), whatever its line count.
+    #[serde(default = "default_files_large")]
+    pub files_large: u32,
+    /// Changed-file count that makes a pull request XL outright.
+    #[serde(default = "default_files_xl")]
+    pub files_xl: u32,
+    /// Names shown for each bucket; see [`crate::data::size_label`].
+    #[serde(default)]
+    pub labels: SizeLabels,
+    /// Line thresholds for specific repositories (`owner/repo`), written as
+    /// `[size.overrides."owner/repo"]` tables. Unset entries fall back to the values above.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub overrides: HashMap<String, SizeOverride>,
 }
 
-impl FilterConfig {
-    fn validate(&self) -> anyhow::Result<()> {
-        for pattern in &self.exclude_patterns {
-            Regex::new(pattern)
-                .with_context(|| format!("Invalid exclude_pattern: '{}'", pattern))?;
-        }
+/// Per-repository replacements for the `small`/`medium`/`large` line thresholds.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::SizeConfig;
+/// let sizes: SizeConfig = toml::from_str(
+///     "small = 50\nmedium = 200\nlarge = 500\n[overrides.\"acme/generated\"]\nlarge = 5000\n",
+/// )
+/// .unwrap();
+/// assert_eq!(sizes.line_thresholds("acme/generated"), (50, 200, 5000));
+/// assert_eq!(sizes.line_thresholds("acme/api"), (50, 200, 500));
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct SizeOverride {
+    pub small: Option<u32>,
+    pub medium: Option<u32>,
+    pub large: Option<u32>,
+}
 
-        for pattern in &self.ignore_patterns {
-            Regex::new(pattern)
-                .with_context(|| format!("Invalid ignore_pattern: '{}'", pattern))?;
+/// Display names for the size buckets, for teams that say "XS/S/M/L" instead of "S/M/L/XL".
+/// Unset entries keep their default name.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::SizeLabels;
+/// let labels: SizeLabels = toml::from_str(r#"s = "XS""#).unwrap();
+/// assert_eq!((labels.s.as_str(), labels.xl.as_str()), ("XS", "XL"));
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct SizeLabels {
+    pub s: String,
+    pub m: String,
+    pub l: String,
+    pub xl: String,
+}
+
+impl Default for SizeLabels {
+    fn default() -> Self {
+        Self {
+            s: "S".to_string(),
+            m: "M".to_string(),
+            l: "L".to_string(),
+            xl: "XL".to_string(),
         }
+    }
+}
 
+impl FilterConfig {
+    /// Check that every pattern compiles, as loading the config does. Call it again after adding
+    /// patterns at runtime.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use gh_log::config::FilterConfig;
+    /// let filters = FilterConfig {
+    ///     exclude_patterns: vec!["[wip".into()],
+    ///     ..Default::default()
+    /// };
+    /// assert!(filters.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        validate_patterns("include_pattern", &self.include_patterns)?;
+        validate_patterns("exclude_pattern", &self.exclude_patterns)?;
+        validate_patterns("ignore_pattern", &self.ignore_patterns)?;
+        validate_patterns("bot_pattern", &self.bot_patterns)?;
         Ok(())
     }
 }
 
 impl SizeConfig {
-    /// Build size thresholds and assert they increase strictly.
+    fn validate(&self) -> Result<()> {
+        if !(self.small < self.medium && self.medium < self.large) {
+            return Err(Error::InvalidConfigValue {
+                field: "size",
+                message: format!(
+                    "thresholds must be ascending: small < medium < large (got {}, {}, {})",
+                    self.small, self.medium, self.large
+                ),
+            });
+        }
+        for repo in self.overrides.keys() {
+            let (small, medium, large) = self.line_thresholds(repo);
+            if !(small < medium && medium < large) {
+                return Err(Error::InvalidConfigValue {
+                    field: "size",
+                    message: format!(
+                        "thresholds for {} must be ascending: small < medium < large (got {}, {}, {})",
+                        repo, small, medium, large
+                    ),
+                });
+            }
+        }
+        if self.files_large >= self.files_xl {
+            return Err(Error::InvalidConfigValue {
+                field: "size",
+                message: format!(
+                    "file thresholds must be ascending: files_large < files_xl (got {}, {})",
+                    self.files_large, self.files_xl
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Build size thresholds, rejecting line or file thresholds that do not increase strictly.
     ///
     /// # Examples
     /// ```rust
     /// use gh_log::config::SizeConfig;
-    /// let sizes = SizeConfig::new(50, 200, 500);
+    /// let sizes = SizeConfig::new(50, 200, 500, 15, 25)?;
     /// assert_eq!(sizes.large, 500);
+    /// assert!(SizeConfig::new(200, 100, 500, 15, 25).is_err());
+    /// assert!(SizeConfig::new(50, 200, 500, 25, 15).is_err());
+    /// # Ok::<_, gh_log::Error>(())
     /// ```
-    pub fn new(small: u32, medium: u32, large: u32) -> Self {
-        assert!(
-            small < medium && medium < large,
-            "Thresholds must be in ascending order"
-        );
-
-        Self {
+    pub fn new(
+        small: u32,
+        medium: u32,
+        large: u32,
+        files_large: u32,
+        files_xl: u32,
+    ) -> Result<Self> {
+        let sizes = Self {
             small,
             medium,
             large,
+            files_large,
+            files_xl,
+            labels: SizeLabels::default(),
+            overrides: HashMap::new(),
+        };
+        sizes.validate()?;
+        Ok(sizes)
+    }
+
+    /// The `(small, medium, large)` line thresholds for `repo`, taking any override for it into
+    /// account.
+    pub fn line_thresholds(&self, repo: &str) -> (u32, u32, u32) {
+        match self.overrides.get(repo) {
+            Some(o) => (
+                o.small.unwrap_or(self.small),
+                o.medium.unwrap_or(self.medium),
+                o.large.unwrap_or(self.large),
+            ),
+            None => (self.small, self.medium, self.large),
         }
     }
 }
@@ -128,10 +1012,22 @@ impl Default for SizeConfig {
             small: 50,
             medium: 200,
             large: 500,
+            files_large: default_files_large(),
+            files_xl: default_files_xl(),
+            labels: SizeLabels::default(),
+            overrides: HashMap::new(),
         }
     }
 }
 
+fn default_files_large() -> u32 {
+    15
+}
+
+fn default_files_xl() -> u32 {
+    25
+}
+
 impl Config {
     /// Load configuration from the standard OS directory, creating a template when missing.
     ///
@@ -141,9 +1037,10 @@ impl Config {
     /// let cfg = Config::default().expect("load config");
     /// println!("{}", cfg.size.medium);
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Result<Self> {
         let project_dirs =
-            ProjectDirs::from("", "", "gh-log").context("Failed to determine config directory")?;
+            ProjectDirs::from("", "", "gh-log").ok_or(Error::NoProjectDirs("config"))?;
         let config_dir = project_dirs.config_dir().to_path_buf();
 
         Self::new(config_dir)
@@ -159,8 +1056,12 @@ impl Config {
     /// let cfg = Config::new(dir).expect("load config");
     /// ```
     pub fn new(config_dir: PathBuf) -> Result<Self> {
-        fs::create_dir_all(&config_dir)
-            .with_context(|| format!("Failed to create config directory: {:?}", config_dir))?;
+        fs::create_dir_all(&config_dir).map_err(|e| {
+            Error::io(
+                format!("Failed to create config directory: {:?}", config_dir),
+                e,
+            )
+        })?;
 
         let config_path = config_dir.join("config.toml");
         if !config_path.exists() {
@@ -169,20 +1070,139 @@ impl Config {
         }
 
         let contents = fs::read_to_string(&config_path)
-            .with_context(|| format!("Failed to read config file: {:?}", config_path))?;
+            .map_err(|e| Error::io(format!("Failed to read config file: {:?}", config_path), e))?;
 
-        let mut config: Config = toml::from_str(&contents)
-            .with_context(|| format!("Failed to parse config file: {:?}", config_path))?;
+        let mut config: Config =
+            toml::from_str(&contents).map_err(|source| Error::ConfigParse {
+                path: config_path.clone(),
+                source,
+            })?;
 
-        config
-            .filter
-            .validate()
-            .context("Invalid regex patterns in config")?;
+        config.filter.validate()?;
+        config.size.validate()?;
+        config.targets.validate()?;
+        config.review.validate()?;
+        config.gh.validate()?;
+        config.display.validate()?;
+        config.theme.validate()?;
+        config.analytics.validate()?;
+        config.defaults.validate()?;
 
         config.config_path = config_path;
         Ok(config)
     }
 
+    /// Merge `flags` into the `[defaults]` section, and `settings` into their own sections, and
+    /// write it back to `config.toml`, keeping the file's comments and layout. Values not named
+    /// in `flags` or `settings` stay as they were.
+    pub fn save_defaults(&mut self, flags: DefaultsConfig, settings: SavedSettings) -> Result<()> {
+        let contents = fs::read_to_string(&self.config_path).map_err(|e| {
+            Error::io(
+                format!("Failed to read config file: {:?}", self.config_path),
+                e,
+            )
+        })?;
+        let mut doc: toml_edit::DocumentMut =
+            contents
+                .parse()
+                .map_err(|e: toml_edit::TomlError| Error::InvalidConfigValue {
+                    field: "defaults",
+                    message: e.to_string(),
+                })?;
+
+        let values = toml::Table::try_from(flags).map_err(|e| Error::InvalidConfigValue {
+            field: "defaults",
+            message: e.to_string(),
+        })?;
+        if !doc.contains_table("defaults") {
+            doc["defaults"] = toml_edit::table();
+        }
+        for (key, value) in values {
+            if let toml::Value::String(value) = value {
+                doc["defaults"][key.as_str()] = toml_edit::value(value);
+            }
+        }
+        if let Some(state) = settings.state {
+            set_string(&mut doc, "filter", "state", state)?;
+        }
+        if let Some(timezone) = settings.timezone {
+            set_string(&mut doc, "display", "timezone", timezone)?;
+        }
+
+        fs::write(&self.config_path, doc.to_string()).map_err(|e| {
+            Error::io(
+                format!("Failed to write config file: {:?}", self.config_path),
+                e,
+            )
+        })?;
+        self.defaults = flags.or(self.defaults);
+        if let Some(state) = settings.state {
+            self.filter.state = state;
+        }
+        if settings.timezone.is_some() {
+            self.display.timezone = settings.timezone;
+        }
+        Ok(())
+    }
+
+    /// Location of `config.toml`.
+    pub fn path(&self) -> &std::path::Path {
+        &self.config_path
+    }
+
+    /// Returns `true` when `filter.include_repos` is empty or lists the repository.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::config::Config;
+    /// let cfg = Config::default().expect("load config");
+    /// let keep_repo = cfg.should_include_repo("example/service");
+    /// println!("keep repo: {}", keep_repo);
+    /// ```
+    pub fn should_include_repo(&self, repo_name: &str) -> bool {
+        self.filter.include_repos.is_empty()
+            || self
+                .filter
+                .include_repos
+                .iter()
+                .any(|repo| repo == repo_name)
+    }
+
+    /// Returns `true` when `filter.include_patterns` is empty or any of its patterns matches the
+    /// pull request title.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::config::Config;
+    /// let cfg = Config::default().expect("load config");
+    /// let keep_title = cfg.should_include_pr_title("feat: add export");
+    /// println!("keep title: {}", keep_title);
+    /// ```
+    pub fn should_include_pr_title(&self, title: &str) -> bool {
+        self.filter.include_patterns.is_empty()
+            || self.matches_patterns(title, &self.filter.include_patterns)
+    }
+
+    /// Returns `true` when `filter.include_labels` is empty or names one of `labels`, ignoring
+    /// case.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::config::Config;
+    /// let cfg = Config::default().expect("load config");
+    /// let keep = cfg.should_include_labels(&["bug".to_string()]);
+    /// println!("keep labeled PR: {}", keep);
+    /// ```
+    pub fn should_include_labels(&self, labels: &[String]) -> bool {
+        self.filter.include_labels.is_empty()
+            || labels.iter().any(|label| {
+                self.filter
+                    .include_labels
+                    .iter()
+                    .any(|wanted| wanted.eq_ignore_ascii_case(label))
+            })
+    }
+
     /// Returns `true` when the repository is listed under `filter.exclude_repos`.
     ///
     /// # Examples
@@ -235,6 +1255,24 @@ impl Config {
         self.matches_patterns(title, &self.filter.ignore_patterns)
     }
 
+    /// Returns `true` when `author` should be dropped as a bot: GitHub marks it as one, its login
+    /// ends in `[bot]`, or it matches `filter.bot_patterns`. Always `false` with `include_bots`.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::config::Config;
+    /// # use gh_log::github::Author;
+    /// let cfg = Config::default().expect("load config");
+    /// let author = Author { login: "renovate[bot]".into(), typename: None };
+    /// println!("drop: {}", cfg.should_exclude_bot(&author));
+    /// ```
+    pub fn should_exclude_bot(&self, author: &Author) -> bool {
+        if self.filter.include_bots {
+            return false;
+        }
+        author.is_bot() || self.matches_patterns(&author.login, &self.filter.bot_patterns)
+    }
+
     fn matches_patterns(&self, text: &str, patterns: &[String]) -> bool {
         // validate() already proved each pattern compiles; recompiling here keeps the helper
         // side-effect free, and the tiny lists make the cost imperceptible.
@@ -247,6 +1285,21 @@ impl Config {
     }
 }
 
+/// Issue ids that `re` finds in `title`, in order of appearance and without repeats. Each id is the
+/// first capture group, or the whole match when the pattern has no groups.
+pub fn extract_issues(re: &Regex, title: &str) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for captures in re.captures_iter(title) {
+        let id = captures.get(1).or_else(|| captures.get(0));
+        if let Some(id) = id.map(|m| m.as_str().to_string())
+            && !ids.contains(&id)
+        {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
 /// Write a sample configuration file to the given path, seeding default filters and size thresholds.
 ///
 /// Overwrites any existing file contents so first-time users start with a documented template.
@@ -257,36 +1310,106 @@ pub fn example(config_path: &PathBuf) -> Result<()> {
             exclude_patterns: vec!["^test:".to_string(), "^tmp:".to_string()],
             ignore_repos: vec!["username/private".to_string(), "username/notes".to_string()],
             ignore_patterns: vec!["^docs:".to_string(), "^meeting:".to_string()],
+            ..Default::default()
         },
-        size: SizeConfig::new(50, 200, 500),
+        size: SizeConfig::default(),
+        identity: IdentityConfig::default(),
+        analytics: AnalyticsConfig::default(),
+        targets: TargetsConfig::default(),
+        review: ReviewConfig::default(),
+        gh: GhConfig::default(),
+        display: DisplayConfig::default(),
+        theme: ThemeConfig::default(),
+        defaults: DefaultsConfig::default(),
         config_path: config_path.clone(),
     };
 
     let toml_string = toml::to_string_pretty(&example_config)
-        .with_context(|| "Failed to serialize example config")?;
+        .map_err(|e| Error::serialize("Failed to serialize example config", e))?;
 
     let comment = "# gh-log configuration\n\
                   # \n\
                   # [filter]\n\
+                  # include_* = when set, only matching PRs are shown (checked first)\n\
                   # exclude_* = not shown at all (filtered out completely)\n\
                   # ignore_*  = shown but not counted in metrics\n\
                   # \n\
+                  # include_repos = [\"username/api\", \"username/web\"]  # Only these repos\n\
+                  # include_patterns = [\"^feat:\", \"^fix:\"]  # Only these titles (regex)\n\
+                  # include_labels = [\"bug\", \"feature\"]  # Only PRs with one of these labels\n\
                   # exclude_repos = [\"username/spam\"]  # Not shown\n\
                   # exclude_patterns = [\"^test:\", \"^tmp:\"]  # Not shown (regex)\n\
                   # ignore_repos = [\"username/private\"]  # Shown but not in metrics\n\
                   # ignore_patterns = [\"^docs:\", \"^meeting:\"]  # Shown but not in metrics (regex)\n\
+                  # bot_patterns = [\"^ci-\"]  # Extra bot logins (regex); bots are excluded by default\n\
+                  # include_bots = false  # Keep bot PRs and reviewers (same as --include-bots)\n\
+                  # state = \"merged\"  # Only merged, open, or closed PRs (same as --state); default all\n\
                   # \n\
                   # [size]\n\
                   # small = 50    # S: <= 50 lines changed\n\
                   # medium = 200  # M: 51-200 lines\n\
-                  # large = 500   # L: 201-500 lines, XL: > 500 lines\n\n";
+                  # large = 500   # L: 201-500 lines, XL: > 500 lines\n\
+                  # files_large = 15  # At least L from 15 changed files\n\
+                  # files_xl = 25     # XL from 25 changed files\n\
+                  # labels = { s = \"XS\", m = \"S\", l = \"M\", xl = \"L\" }  # Rename the buckets\n\
+                  # \n\
+                  # [size.overrides.\"username/generated\"]\n\
+                  # large = 5000  # Per-repo thresholds; unset ones use [size]\n\
+                  # \n\
+                  # [identity]\n\
+                  # aliases = [[\"new-login\", \"old-login\"]]  # Count as one person (first wins)\n\
+                  # \n\
+                  # [analytics]\n\
+                  # review_balance_base = \"created\"  # or \"merged\", \"reviewed_eligible\"\n\
+                  # lead_time_buckets = [1, 4, 24, 72]  # Histogram edges in hours\n\
+                  # \n\
+                  # [targets]\n\
+                  # max_xl_ratio = 0.2  # Warn when more than 20% of PRs are XL\n\
+                  # \n\
+                  # [review]\n\
+                  # target_ratio = 1.0  # Review Balance turns red below this, green at or above\n\
+                  # \n\
+                  # [gh]\n\
+                  # binary = \"/opt/homebrew/bin/gh\"  # When gh is not on PATH (GH_LOG_GH_PATH wins)\n\
+                  # review_fetch_limit = 50  # Reviews fetched per PR (default 10, max 100)\n\
+                  # \n\
+                  # [display]\n\
+                  # issue_pattern = '\\[([A-Z]+-\\d+)\\]'  # Issue ids in titles (regex; first group is the id)\n\
+                  # issue_url_template = \"https://jira.example.com/browse/{id}\"  # Link for each id\n\
+                  # ignored_marker = \"(ignored)\"  # Tag on ignored PRs in the TUI lists\n\
+                  # timezone = \"+02:00\"  # Dates and weeks in this zone: local (default), utc, or an offset\n\
+                  # \n\
+                  # [theme]  # view colors: names (lightblue, darkgray), 0-255, or #rrggbb\n\
+                  # header = \"black\"  # Section rules and key hints (default gray)\n\
+                  # size_l = \"#b58900\"  # Also size_s, size_m, size_xl, size_unknown\n\
+                  # accent = \"blue\"  # Also highlight, count, muted, mode, ratio, balance, on_target, alert\n\
+                  # \n\
+                  # [defaults]  # Written by --save-config; explicit flags still win\n\
+                  # by = \"merged\"\n\
+                  # format = \"json\"  # print output when no format flag is given\n\
+                  # month_offset = 1  # Report last month when --month is not given\n\n";
 
-    fs::write(config_path, format!("{}{}", comment, toml_string))
-        .with_context(|| format!("Failed to write example config: {:?}", config_path))?;
+    fs::write(config_path, format!("{}{}", comment, toml_string)).map_err(|e| {
+        Error::io(
+            format!("Failed to write example config: {:?}", config_path),
+            e,
+        )
+    })?;
 
     Ok(())
 }
 
+fn validate_patterns(field: &'static str, patterns: &[String]) -> Result<()> {
+    for pattern in patterns {
+        Regex::new(pattern).map_err(|source| Error::InvalidPattern {
+            field,
+            pattern: pattern.clone(),
+            source,
+        })?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,6 +1419,14 @@ mod tests {
         Config {
             filter,
             size,
+            identity: IdentityConfig::default(),
+            analytics: AnalyticsConfig::default(),
+            targets: TargetsConfig::default(),
+            review: ReviewConfig::default(),
+            gh: GhConfig::default(),
+            display: DisplayConfig::default(),
+            theme: ThemeConfig::default(),
+            defaults: DefaultsConfig::default(),
             config_path,
         }
     }
@@ -329,6 +1460,27 @@ large = 600
         assert_eq!(config.size.small, 75);
         assert_eq!(config.size.medium, 250);
         assert_eq!(config.size.large, 600);
+        assert!(config.identity.aliases.is_empty());
+    }
+
+    #[test]
+    fn test_config_parses_identity_aliases() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            config_dir.join("config.toml"),
+            r#"
+[identity]
+aliases = [["alice", "alice-old"], ["bob", "bob-work", "bobby"]]
+"#,
+        )
+        .unwrap();
+
+        let config = Config::new(config_dir).unwrap();
+
+        assert_eq!(config.identity.canonical_login("alice-old"), "alice");
+        assert_eq!(config.identity.canonical_login("bobby"), "bob");
+        assert_eq!(config.identity.canonical_login("carol"), "carol");
     }
 
     #[test]
@@ -348,15 +1500,175 @@ large = 600
     }
 
     #[test]
-    #[should_panic(expected = "Thresholds must be in ascending order")]
-    fn test_same_thresholds_panics() {
-        let _config = SizeConfig::new(100, 100, 100);
+    fn test_same_thresholds_are_rejected() {
+        let result = SizeConfig::new(100, 100, 100, 15, 25);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidConfigValue { field: "size", .. })
+        ));
+    }
+
+    #[test]
+    fn test_timezone_round_trips_through_toml() {
+        let display: DisplayConfig = toml::from_str(r#"timezone = "+05:30""#).unwrap();
+        let tz = display.timezone();
+        assert_eq!(tz.to_string(), "+05:30");
+        assert_eq!(
+            toml::to_string(&display).unwrap().trim(),
+            r#"timezone = "+05:30""#
+        );
+        assert_eq!(DisplayConfig::default().timezone(), Timezone::Local);
+        assert_eq!("UTC".parse::<Timezone>(), Ok(Timezone::Utc));
+        assert!(toml::from_str::<DisplayConfig>(r#"timezone = "Europe/Nowhere""#).is_err());
+    }
+
+    #[test]
+    fn test_file_thresholds_must_ascend() {
+        assert!(SizeConfig::new(50, 200, 500, 10, 40).is_ok());
+        let result = SizeConfig::new(50, 200, 500, 25, 25);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidConfigValue { field: "size", .. })
+        ));
+        let sizes: SizeConfig = toml::from_str("small = 1\nmedium = 2\nlarge = 3").unwrap();
+        assert_eq!((sizes.files_large, sizes.files_xl), (15, 25));
+    }
+
+    #[test]
+    fn test_descending_thresholds_are_rejected() {
+        let result = SizeConfig::new(500, 200, 100, 15, 25);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidConfigValue { field: "size", .. })
+        ));
+    }
+
+    #[test]
+    fn test_out_of_order_sizes_fail_config_load_cleanly() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            config_dir.join("config.toml"),
+            "[size]\nsmall = 200\nmedium = 100\nlarge = 500\n",
+        )
+        .unwrap();
+
+        let err = Config::new(config_dir).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid config value for size: thresholds must be ascending: small < medium < large (got 200, 100, 500)"
+        );
+    }
+
+    #[test]
+    fn test_size_overrides_load_and_are_validated() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            config_dir.join("config.toml"),
+            "[size]\nsmall = 50\nmedium = 200\nlarge = 500\n\n[size.overrides.\"acme/infra\"]\nsmall = 10\nmedium = 40\nlarge = 100\n",
+        )
+        .unwrap();
+        let config = Config::new(config_dir.clone()).unwrap();
+        assert_eq!(config.size.line_thresholds("acme/infra"), (10, 40, 100));
+        assert_eq!(config.size.line_thresholds("acme/web"), (50, 200, 500));
+
+        fs::write(
+            config_dir.join("config.toml"),
+            "[size]\nsmall = 50\nmedium = 200\nlarge = 500\n\n[size.overrides.\"acme/infra\"]\nmedium = 20\n",
+        )
+        .unwrap();
+        let err = Config::new(config_dir).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid config value for size: thresholds for acme/infra must be ascending: small < medium < large (got 50, 20, 500)"
+        );
+    }
+
+    #[test]
+    fn test_save_defaults_keeps_comments_and_merges() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            config_dir.join("config.toml"),
+            "# my filters\n[filter]\nexclude_repos = [\"me/scratch\"]\n\n[defaults]\nby = \"merged\"\n",
+        )
+        .unwrap();
+
+        let mut config = Config::new(config_dir.clone()).unwrap();
+        assert_eq!(config.defaults.by, Some(DateField::Merged));
+        config
+            .save_defaults(
+                DefaultsConfig {
+                    sort_repos: Some(RepoSort::LeadTime),
+                    group_by: Some(GroupBy::Repo),
+                    format: Some(PrintFormat::Json),
+                    ..Default::default()
+                },
+                SavedSettings {
+                    state: Some(StateFilter::Merged),
+                    timezone: Some(Timezone::Utc),
+                },
+            )
+            .unwrap();
+
+        let written = fs::read_to_string(config_dir.join("config.toml")).unwrap();
+        assert!(written.starts_with("# my filters\n"), "{written}");
+        let reloaded = Config::new(config_dir).unwrap();
+        assert_eq!(reloaded.filter.exclude_repos, ["me/scratch"]);
+        assert_eq!(reloaded.filter.state, StateFilter::Merged);
+        assert_eq!(reloaded.display.timezone, Some(Timezone::Utc));
+        assert_eq!(
+            reloaded.defaults,
+            DefaultsConfig {
+                by: Some(DateField::Merged),
+                group_by: Some(GroupBy::Repo),
+                sort_repos: Some(RepoSort::LeadTime),
+                sort_order: None,
+                format: Some(PrintFormat::Json),
+                month_offset: None,
+            }
+        );
+        assert_eq!(config.defaults, reloaded.defaults);
+    }
+
+    #[test]
+    fn test_month_offset_loads_and_is_bounded() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            config_dir.join("config.toml"),
+            "[defaults]\nmonth_offset = 1\nformat = \"csv\"\n",
+        )
+        .unwrap();
+        let config = Config::new(config_dir.clone()).unwrap();
+        assert_eq!(config.defaults.format, Some(PrintFormat::Csv));
+        let march = chrono::NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        assert_eq!(config.defaults.month(march), "2025-02");
+
+        fs::write(
+            config_dir.join("config.toml"),
+            "[defaults]\nmonth_offset = 500\n",
+        )
+        .unwrap();
+        let err = Config::new(config_dir).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid config value for defaults.month_offset: 500 is more than 120 months back"
+        );
     }
 
     #[test]
-    #[should_panic(expected = "Thresholds must be in ascending order")]
-    fn test_descending_thresholds_panics() {
-        let _config = SizeConfig::new(500, 200, 100);
+    fn test_lead_time_buckets_must_ascend() {
+        let analytics = |edges: Vec<u32>| AnalyticsConfig {
+            lead_time_buckets: Some(edges),
+            ..Default::default()
+        };
+        assert!(analytics(vec![2, 8, 48]).validate().is_ok());
+        for edges in [vec![], vec![0, 4], vec![4, 4], vec![24, 1]] {
+            assert!(analytics(edges).validate().is_err());
+        }
+        assert!(AnalyticsConfig::default().validate().is_ok());
     }
 
     #[test]
@@ -393,6 +1705,178 @@ large = 600
         insta::assert_snapshot!(result.unwrap_err().to_string());
     }
 
+    #[test]
+    fn test_validate_invalid_include_pattern() {
+        let filters = FilterConfig {
+            include_patterns: vec!["(feat".to_string()],
+            ..Default::default()
+        };
+        let err = filters.validate().unwrap_err();
+        assert!(err.to_string().contains("include_pattern"), "{err}");
+    }
+
+    #[test]
+    fn test_include_lists_default_to_everything() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = create_test_config(
+            FilterConfig::default(),
+            SizeConfig::default(),
+            temp_dir.path().join("config.toml"),
+        );
+        assert!(config.should_include_repo("any/repo"));
+        assert!(config.should_include_pr_title("anything"));
+
+        config.filter.include_repos = vec!["me/api".to_string()];
+        config.filter.include_patterns = vec!["^feat:".to_string()];
+        assert!(config.should_include_repo("me/api"));
+        assert!(!config.should_include_repo("me/api-old"));
+        assert!(config.should_include_pr_title("feat: export"));
+        assert!(!config.should_include_pr_title("fix: feat: typo"));
+    }
+
+    #[test]
+    fn test_should_exclude_bot() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = create_test_config(
+            FilterConfig {
+                bot_patterns: vec!["^ci-".to_string()],
+                ..Default::default()
+            },
+            SizeConfig::default(),
+            temp_dir.path().join("config.toml"),
+        );
+        let author = |login: &str, typename: Option<&str>| Author {
+            login: login.to_string(),
+            typename: typename.map(str::to_string),
+        };
+
+        assert!(config.should_exclude_bot(&author("dependabot", Some("Bot"))));
+        assert!(config.should_exclude_bot(&author("renovate[bot]", None)));
+        assert!(config.should_exclude_bot(&author("ci-runner", Some("User"))));
+        assert!(!config.should_exclude_bot(&author("alice", Some("User"))));
+
+        config.filter.include_bots = true;
+        assert!(!config.should_exclude_bot(&author("dependabot", Some("Bot"))));
+    }
+
+    #[test]
+    fn test_max_xl_ratio_out_of_range_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            config_dir.join("config.toml"),
+            "[targets]\nmax_xl_ratio = 30\n",
+        )
+        .unwrap();
+
+        let result = Config::new(config_dir);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidConfigValue {
+                field: "targets.max_xl_ratio",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_review_target_ratio_must_be_positive() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            config_dir.join("config.toml"),
+            "[review]\ntarget_ratio = 1.5\n",
+        )
+        .unwrap();
+        let config = Config::new(config_dir.clone()).unwrap();
+        assert_eq!(config.review.target_ratio, Some(1.5));
+
+        fs::write(
+            config_dir.join("config.toml"),
+            "[review]\ntarget_ratio = 0\n",
+        )
+        .unwrap();
+        let result = Config::new(config_dir);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidConfigValue {
+                field: "review.target_ratio",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_theme_colors_are_parsed_and_checked() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            config_dir.join("config.toml"),
+            "[theme]\nheader = \"black\"\nsize_l = \"#b58900\"\nmuted = \"245\"\nalert = \"magenta\"\n",
+        )
+        .unwrap();
+        let theme = Config::new(config_dir.clone()).unwrap().theme.theme();
+        assert_eq!(theme.header, Color::Black);
+        assert_eq!(theme.size_l, Color::Rgb(0xb5, 0x89, 0x00));
+        assert_eq!(theme.muted, Color::Indexed(245));
+        assert_eq!(theme.alert, Color::Magenta);
+        assert_eq!(theme.size_s, Theme::default().size_s);
+
+        fs::write(
+            config_dir.join("config.toml"),
+            "[theme]\naccent = \"blurple\"\n",
+        )
+        .unwrap();
+        let result = Config::new(config_dir);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidConfigValue {
+                field: "theme.accent",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_review_fetch_limit_above_github_cap_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            config_dir.join("config.toml"),
+            "[gh]\nreview_fetch_limit = 500\n",
+        )
+        .unwrap();
+
+        let result = Config::new(config_dir);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidConfigValue {
+                field: "gh.review_fetch_limit",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_issue_pattern_is_rejected_at_load() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().to_path_buf();
+        fs::write(
+            config_dir.join("config.toml"),
+            "[display]\nissue_pattern = '[PROJ-'\n",
+        )
+        .unwrap();
+
+        let result = Config::new(config_dir);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidPattern {
+                field: "display.issue_pattern",
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn test_validate_all_valid_patterns() {
         let temp_dir = TempDir::new().unwrap();
@@ -12,28 +12,28 @@
 //! Centralizing aggregation logic keeps CLI commands thin and guarantees that every output mode
 //! reports identical numbers.
 
-use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+use chrono::{DateTime, Datelike, Days, Duration, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fmt;
 
 use crate::{
-    config::{Config, SizeConfig},
-    github,
+    config::{Config, ReviewBalanceBase, SizeConfig, TargetsConfig, extract_issues},
+    github::{self, DateField},
 };
 
-/// Number of changed files that upgrades a pull request to the Large bucket.
-const CHANGED_FILES_L_THRESHOLD: u32 = 15;
-/// Number of changed files that immediately categorizes a pull request as XL.
-const CHANGED_FILES_XL_THRESHOLD: u32 = 25;
-
 /// Size bucket for a pull request based on line and changed-file thresholds.
-/// Maps to S/M/L/XL labels used across the UI and exporters.
+/// Maps to S/M/L/XL labels used across the UI and exporters; `Display` gives the default names,
+/// [`size_label`] the configured ones.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PRSize {
     S,
     M,
     L,
     XL,
+    /// GitHub reported changed files but no line counts, which happens when it caps or omits the
+    /// metrics for very large PRs. Shown as `?` rather than guessing a bucket.
+    Unknown,
 }
 
 impl fmt::Display for PRSize {
@@ -43,43 +43,74 @@ impl fmt::Display for PRSize {
             PRSize::M => write!(f, "M"),
             PRSize::L => write!(f, "L"),
             PRSize::XL => write!(f, "XL"),
+            PRSize::Unknown => write!(f, "?"),
         }
     }
 }
 
-/// Compute the size bucket for a pull request using configured thresholds.
+/// Name to show for `size`, honoring `[size] labels`. Use this rather than the `Display` impl
+/// anywhere users see sizes.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::config::SizeConfig;
+/// # use gh_log::data::{PRSize, size_label};
+/// let mut sizes = SizeConfig::default();
+/// sizes.labels.s = "XS".to_string();
+/// assert_eq!(size_label(PRSize::S, &sizes), "XS");
+/// assert_eq!(size_label(PRSize::Unknown, &sizes), "?");
+/// ```
+pub fn size_label(size: PRSize, cfg: &SizeConfig) -> &str {
+    match size {
+        PRSize::S => &cfg.labels.s,
+        PRSize::M => &cfg.labels.m,
+        PRSize::L => &cfg.labels.l,
+        PRSize::XL => &cfg.labels.xl,
+        PRSize::Unknown => "?",
+    }
+}
+
+/// Compute the size bucket for a pull request in `repo` using configured thresholds, including
+/// any per-repo override.
 ///
 /// # Examples
 /// ```rust
 /// # use gh_log::config::SizeConfig;
 /// # use gh_log::data::{compute_pr_size, PRSize};
-/// let sizes = SizeConfig::new(50, 200, 500);
-/// let bucket = compute_pr_size(30, 10, 2, &sizes);
+/// let sizes = SizeConfig::new(50, 200, 500, 15, 25)?;
+/// let bucket = compute_pr_size(30, 10, 2, "acme/api", &sizes);
 /// assert_eq!(bucket, PRSize::S);
+/// # Ok::<_, gh_log::Error>(())
 /// ```
 pub fn compute_pr_size(
     additions: u32,
     deletions: u32,
     changed_files: u32,
+    repo: &str,
     size_config: &SizeConfig,
 ) -> PRSize {
+    if additions == 0 && deletions == 0 && changed_files > 0 {
+        return PRSize::Unknown;
+    }
+
+    let (small, medium, large) = size_config.line_thresholds(repo);
     let total_lines = additions + deletions;
-    if changed_files >= CHANGED_FILES_XL_THRESHOLD {
+    if changed_files >= size_config.files_xl {
         return PRSize::XL;
     }
 
-    if changed_files >= CHANGED_FILES_L_THRESHOLD {
-        if total_lines > size_config.large {
+    if changed_files >= size_config.files_large {
+        if total_lines > large {
             return PRSize::XL;
         }
         return PRSize::L;
     }
 
-    if total_lines <= size_config.small {
+    if total_lines <= small {
         PRSize::S
-    } else if total_lines <= size_config.medium {
+    } else if total_lines <= medium {
         PRSize::M
-    } else if total_lines <= size_config.large {
+    } else if total_lines <= large {
         PRSize::L
     } else {
         PRSize::XL
@@ -98,6 +129,7 @@ pub struct WeekData {
     pub size_m: usize,
     pub size_l: usize,
     pub size_xl: usize,
+    pub size_unknown: usize,
 }
 
 /// Aggregated pull request metrics scoped to a single repository.
@@ -106,18 +138,36 @@ pub struct RepoData {
     pub name: String,
     pub pr_count: usize,
     pub avg_lead_time: Duration,
+    /// Median lead time; together with p90 it shows skew the average hides.
+    pub p50_lead_time: Duration,
+    pub p90_lead_time: Duration,
     pub size_s: usize,
     pub size_m: usize,
     pub size_l: usize,
     pub size_xl: usize,
+    pub size_unknown: usize,
+    /// PRs you reviewed in this repo; `None` until [`MonthData::set_reviewed_by_repo`] runs.
+    pub reviewed_count: Option<usize>,
 }
 
 impl RepoData {
-    /// Render the repo's size distribution as "xS xM xL xXL".
-    pub fn format_size_distribution(&self) -> String {
-        format!(
-            "{}S {}M {}L {}XL",
-            self.size_s, self.size_m, self.size_l, self.size_xl
+    /// PRs you reviewed here per PR you authored, e.g. 0.5 when you took twice what you gave.
+    /// `None` without per-repo review counts or authored PRs.
+    pub fn review_balance(&self) -> Option<f64> {
+        let reviewed = self.reviewed_count?;
+        (self.pr_count > 0).then(|| reviewed as f64 / self.pr_count as f64)
+    }
+
+    /// Render the repo's size distribution as "xS xM xL xXL" (using `sizes.labels`), plus "x?" when
+    /// some sizes are unknown.
+    pub fn format_size_distribution(&self, sizes: &SizeConfig) -> String {
+        format_size_counts(
+            sizes,
+            self.size_s,
+            self.size_m,
+            self.size_l,
+            self.size_xl,
+            self.size_unknown,
         )
     }
 }
@@ -129,10 +179,14 @@ pub struct ReviewerData {
     pub pr_count: usize,
 }
 
+/// The PRs each reviewer left a review on, as `(repo, number)` in PR order, keyed by login.
+pub type ReviewerPrs = BTreeMap<String, Vec<(String, u32)>>;
+
 /// Detailed record for a single pull request used in list and detail views.
 #[derive(Debug, Clone)]
 pub struct PRDetail {
     pub created_at: DateTime<Utc>,
+    pub merged_at: Option<DateTime<Utc>>,
     pub repo: String,
     pub number: u32,
     pub title: String,
@@ -141,21 +195,366 @@ pub struct PRDetail {
     pub additions: u32,
     pub deletions: u32,
     pub changed_files: u32,
+    /// Issue ids found in the title via `[display] issue_pattern`.
+    pub linked_issues: Vec<String>,
+    /// GitHub label names.
+    pub labels: Vec<String>,
+    /// Which search found the PR in an `--also-merged` report; `None` in single-search reports.
+    pub matched_by: Option<MatchedBy>,
+    /// Still open: `lead_time` runs to now and is left out of lead-time metrics.
+    pub open: bool,
+    /// Matched `ignore_repos`/`ignore_patterns`: listed, but left out of every metric.
+    pub ignored: bool,
 }
 
 impl PRDetail {
+    /// The PR's page on github.com.
+    pub fn url(&self) -> String {
+        format!("https://github.com/{}/pull/{}", self.repo, self.number)
+    }
+
     /// Determine this PR's size bucket using the shared thresholds.
     pub fn size(&self, size_config: &SizeConfig) -> PRSize {
         compute_pr_size(
             self.additions,
             self.deletions,
             self.changed_files,
+            &self.repo,
             size_config,
         )
     }
+
+    /// Lines added per line deleted; `None` when nothing was deleted.
+    pub fn add_delete_ratio(&self) -> Option<f64> {
+        add_delete_ratio(u64::from(self.additions), u64::from(self.deletions))
+    }
+
+    /// Line changes as `+120/-30`.
+    pub fn churn(&self) -> String {
+        format!("+{}/-{}", self.additions, self.deletions)
+    }
+}
+
+fn add_delete_ratio(additions: u64, deletions: u64) -> Option<f64> {
+    (deletions > 0).then(|| additions as f64 / deletions as f64)
+}
+
+/// Render an add:delete ratio as "4.0:1", "+only" when nothing was deleted, or "n/a" when there
+/// were no line changes at all.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::data::format_add_delete_ratio;
+/// assert_eq!(format_add_delete_ratio(120, 30), "4.0:1");
+/// assert_eq!(format_add_delete_ratio(120, 0), "+only");
+/// assert_eq!(format_add_delete_ratio(0, 0), "n/a");
+/// ```
+pub fn format_add_delete_ratio(additions: u64, deletions: u64) -> String {
+    match add_delete_ratio(additions, deletions) {
+        Some(ratio) => format!("{:.1}:1", ratio),
+        None if additions > 0 => "+only".to_string(),
+        None => "n/a".to_string(),
+    }
+}
+
+/// Render total lines changed as "+12,340 / -3,210 lines", for a sense of code volume that the
+/// size buckets hide.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::data::format_line_totals;
+/// assert_eq!(format_line_totals(12_340, 3_210), "+12,340 / -3,210 lines");
+/// assert_eq!(format_line_totals(7, 0), "+7 / -0 lines");
+/// ```
+pub fn format_line_totals(additions: u64, deletions: u64) -> String {
+    format!(
+        "+{} / -{} lines",
+        group_thousands(additions),
+        group_thousands(deletions)
+    )
+}
+
+fn group_thousands(n: u64) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (idx, digit) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Column the repository table is ordered by (`--sort-repos`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RepoSort {
+    /// Most PRs first.
+    #[default]
+    Count,
+    /// Alphabetical by `owner/repo`.
+    Name,
+    /// Slowest average lead time first.
+    #[value(alias = "lead_time")]
+    #[serde(alias = "lead_time")]
+    LeadTime,
+}
+
+/// Direction for [`RepoSort`]; each key has a natural default, noted on its variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// How to order the repository table: a key plus an optional override of its natural direction.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RepoOrder {
+    pub key: RepoSort,
+    pub order: Option<SortOrder>,
+}
+
+/// Key `print --sort` orders PRs by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PrSort {
+    /// Hours from creation to merge (or to now, while open).
+    LeadTime,
+    /// Size bucket, then lines changed within a bucket; `?` sizes sort first.
+    Size,
+    /// Creation date.
+    Date,
+    /// `owner/repo`, then PR number.
+    Repo,
+}
+
+/// How to order the flat PR list of `print --sort`: a key, ascending unless `descending` is set.
+#[derive(Debug, Clone, Copy)]
+pub struct PrOrder {
+    pub key: PrSort,
+    pub descending: bool,
+}
+
+impl PrOrder {
+    /// Heading for the sorted list, e.g. `PRs by size (descending)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use gh_log::data::{PrOrder, PrSort};
+    /// let order = PrOrder { key: PrSort::LeadTime, descending: true };
+    /// assert_eq!(order.title(), "PRs by lead time (descending)");
+    /// ```
+    pub fn title(&self) -> String {
+        let key = match self.key {
+            PrSort::LeadTime => "lead time",
+            PrSort::Size => "size",
+            PrSort::Date => "date",
+            PrSort::Repo => "repo",
+        };
+        let direction = if self.descending {
+            "descending"
+        } else {
+            "ascending"
+        };
+        format!("PRs by {} ({})", key, direction)
+    }
+
+    /// Compare two PRs by this order. Ties compare equal so a stable sort keeps them in week
+    /// order.
+    pub fn compare(&self, a: &PRDetail, b: &PRDetail, sizes: &SizeConfig) -> std::cmp::Ordering {
+        let size_rank = |pr: &PRDetail| {
+            let bucket = match pr.size(sizes) {
+                PRSize::Unknown => 0,
+                PRSize::S => 1,
+                PRSize::M => 2,
+                PRSize::L => 3,
+                PRSize::XL => 4,
+            };
+            (bucket, pr.additions + pr.deletions)
+        };
+        let ordering = match self.key {
+            PrSort::LeadTime => a.lead_time.cmp(&b.lead_time),
+            PrSort::Size => size_rank(a).cmp(&size_rank(b)),
+            PrSort::Date => a.created_at.cmp(&b.created_at),
+            PrSort::Repo => (&a.repo, a.number).cmp(&(&b.repo, b.number)),
+        };
+        if self.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+/// Dimension `print` sections its PR listing by (`--group-by`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupBy {
+    /// Calendar weeks, with per-week totals.
+    #[default]
+    Week,
+    /// Repositories, in repository-table order.
+    Repo,
+    /// Size buckets, smallest first.
+    Size,
+    /// Conventional title prefix such as `docs:` or `feat(api):`.
+    Label,
+    /// Day the PR was created, or merged under `--by merged`.
+    Day,
+}
+
+/// A titled section of PRs produced by [`MonthData::group_prs`].
+#[derive(Debug)]
+pub struct PrGroup {
+    pub title: String,
+    pub prs: Vec<PRDetail>,
+}
+
+impl PrGroup {
+    pub fn avg_lead_time(&self) -> Duration {
+        let lead_times: Vec<Duration> = self
+            .prs
+            .iter()
+            .filter(|pr| !pr.open)
+            .map(|pr| pr.lead_time)
+            .collect();
+        avg_duration(&lead_times)
+    }
+}
+
+/// Title prefix before the first `:` (e.g. `docs` in "docs: fix typo"), lowercased; `None` for
+/// titles without one.
+fn title_label(title: &str) -> Option<String> {
+    let (prefix, _) = title.split_once(':')?;
+    let prefix = prefix.trim();
+    // A long or spaced prefix is a sentence with a colon in it, not a label.
+    if prefix.is_empty() || prefix.len() > 20 || prefix.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(prefix.to_lowercase())
+}
+
+/// Which date search put a PR in a report that combines created and merged PRs
+/// (`print --also-merged`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchedBy {
+    /// Opened in the month.
+    Created,
+    /// Opened earlier, merged in the month.
+    Merged,
+    /// Opened and merged in the month.
+    Both,
+}
+
+impl MatchedBy {
+    pub fn label(self) -> &'static str {
+        match self {
+            MatchedBy::Created => "created",
+            MatchedBy::Merged => "merged",
+            MatchedBy::Both => "created+merged",
+        }
+    }
+}
+
+/// One PR called out in the summary, such as the month's fastest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrHighlight {
+    pub number: u32,
+    pub title: String,
+    pub repo: String,
+    pub lead_time: Duration,
+}
+
+impl From<&PRData> for PrHighlight {
+    fn from(pr: &PRData) -> Self {
+        PrHighlight {
+            number: pr.number,
+            title: pr.title.clone(),
+            repo: pr.repo_name.clone(),
+            lead_time: pr.lead_time,
+        }
+    }
+}
+
+/// The counted PRs with the shortest and longest lead times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeadTimeExtremes {
+    pub fastest: PrHighlight,
+    pub slowest: PrHighlight,
+}
+
+/// How quickly you got to the PRs you reviewed: creation to your first review on each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewTurnaround {
+    pub median: Duration,
+    pub mean: Duration,
+    /// Reviewed PRs with a known first review.
+    pub count: usize,
+}
+
+/// One bar of the lead-time histogram, e.g. "4h–1d" with 3 PRs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeadTimeBucket {
+    pub label: String,
+    pub count: usize,
+}
+
+/// The month's earliest counted PR and how far into the report window it landed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FirstPr {
+    pub pr: PrHighlight,
+    /// When the PR entered the report: created, or merged under `--by merged`.
+    pub at: DateTime<Utc>,
+    /// Whole days between the start of the month (or window) and `at`.
+    pub days_in: i64,
+}
+
+impl FirstPr {
+    /// "Jan 3 (2 days in)".
+    pub fn describe(&self) -> String {
+        let unit = if self.days_in == 1 { "day" } else { "days" };
+        format!(
+            "{} ({} {} in)",
+            self.at.format("%b %-d"),
+            self.days_in,
+            unit
+        )
+    }
+}
+
+/// One person's totals in a report that covers several authors (`print --author-file`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthorSummary {
+    pub login: String,
+    pub total_prs: usize,
+    pub avg_lead_time: Duration,
+    /// PRs this person reviewed; `None` when the review query was skipped.
+    pub reviewed_count: Option<usize>,
+    /// Reviewed PRs per own PR against the configured base; `None` without a reviewed count or
+    /// without own PRs to divide by.
+    pub review_balance: Option<f64>,
+}
+
+impl AuthorSummary {
+    /// Summarize `report`, built from `login`'s PRs alone.
+    pub fn new(login: &str, report: &MonthData, base: ReviewBalanceBase) -> Self {
+        let review_balance = report
+            .reviewed_count
+            .filter(|_| report.review_balance_denominator(base) > 0)
+            .map(|_| report.review_balance(base));
+        AuthorSummary {
+            login: login.to_string(),
+            total_prs: report.total_prs,
+            avg_lead_time: report.avg_lead_time,
+            reviewed_count: report.reviewed_count,
+            review_balance,
+        }
+    }
 }
 
-/// Month-level aggregation consumed by the TUI and export commands.
+/// Month-level aggregation consumed by the TUI and export commands. Its dates read as wall-clock
+/// times in `[display] timezone`.
 #[derive(Debug)]
 pub struct MonthData {
     pub month_start: DateTime<Utc>,
@@ -166,19 +565,201 @@ pub struct MonthData {
     pub size_m: usize,
     pub size_l: usize,
     pub size_xl: usize,
+    pub size_unknown: usize,
     pub weeks: Vec<WeekData>,
     pub repos: Vec<RepoData>,
     pub prs_by_week: Vec<Vec<PRDetail>>,
     pub prs_by_repo: Vec<Vec<PRDetail>>,
     pub reviewers: Vec<ReviewerData>,
-    pub reviewed_count: usize,
+    /// PRs each of `reviewers` left a review on.
+    pub reviewer_prs: ReviewerPrs,
+    /// PRs you reviewed this month; `None` when the review query was skipped (`--no-reviewed`).
+    pub reviewed_count: Option<usize>,
+    /// Counted PRs (ignored ones excluded) that have been merged.
+    pub merged_prs: usize,
+    /// Counted PRs that received at least one review.
+    pub review_eligible_prs: usize,
+    /// PRs whose review list was cut short by the fetch limit, so `reviewers` undercounts them.
+    pub truncated_reviews: usize,
+    /// Lines added and deleted across counted PRs.
+    pub additions: u64,
+    pub deletions: u64,
+    /// Fastest and slowest counted PRs; `None` for an empty month.
+    pub extremes: Option<LeadTimeExtremes>,
+    /// Earliest counted PR; `None` for an empty month.
+    pub first_pr: Option<FirstPr>,
+    /// Counted PRs by lead time, one bucket per `[analytics] lead_time_buckets` range;
+    /// empty for an empty month.
+    pub lead_time_histogram: Vec<LeadTimeBucket>,
+    /// Counted PRs still open, which are left out of every lead-time figure.
+    pub open_prs: usize,
+    /// Mean time from creation to the first review by someone else, over counted PRs that have
+    /// one; `None` when none of them was reviewed.
+    pub avg_time_to_first_review: Option<Duration>,
+    /// Per-person totals when the report combines several authors; empty otherwise.
+    pub authors: Vec<AuthorSummary>,
+    /// Your review turnaround on others' PRs; `None` until [`MonthData::set_review_turnaround`]
+    /// runs with at least one review.
+    pub review_turnaround: Option<ReviewTurnaround>,
+    /// `weeks` holds one bucket per calendar month rather than per week, as in
+    /// [`build_history_data`].
+    pub monthly_buckets: bool,
+    /// Per-calendar-month totals of a year report from [`build_year_data`]; empty otherwise.
+    pub months: Vec<WeekData>,
+    /// First and last instant of a rolling report from [`build_window_data`]; `None` for a
+    /// calendar month.
+    pub window: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    /// Order of the flat PR list set by [`MonthData::sort_prs`]; `None` lists PRs by week.
+    pub pr_order: Option<PrOrder>,
+    /// Set when the PR search stopped at this many PRs (`--limit`), so the report covers only
+    /// part of the month.
+    pub pr_limit: Option<usize>,
+    /// PRs (`owner/repo#123`) that end before they were created; their lead time counts as 0.
+    pub skewed_prs: Vec<String>,
 }
 
 impl MonthData {
-    fn empty(month: &str) -> Self {
-        let parts: Vec<&str> = month.split('-').collect();
-        let year: i32 = parts[0].parse().unwrap();
-        let month: u32 = parts[1].parse().unwrap();
+    /// Fill in each repository's reviewed count from `by_repo` (keyed by `owner/repo`); repos
+    /// missing from it get zero.
+    pub fn set_reviewed_by_repo(&mut self, by_repo: &BTreeMap<String, usize>) {
+        for repo in &mut self.repos {
+            repo.reviewed_count = Some(by_repo.get(&repo.name).copied().unwrap_or(0));
+        }
+    }
+
+    /// Summarize review turnaround from per-PR `secs` (creation to first review); an empty list
+    /// leaves it `None`.
+    pub fn set_review_turnaround(&mut self, secs: &[i64]) {
+        let turnarounds: Vec<Duration> = secs.iter().map(|&s| Duration::seconds(s)).collect();
+        if turnarounds.is_empty() {
+            self.review_turnaround = None;
+            return;
+        }
+        self.review_turnaround = Some(ReviewTurnaround {
+            median: percentile(&turnarounds, 50.0),
+            mean: avg_duration(&turnarounds),
+            count: turnarounds.len(),
+        });
+    }
+
+    /// Split the month's PRs into sections by `key`. Weeks and repos reuse the prebuilt groups;
+    /// sizes, labels, and days are folded on demand. `date_field` picks the day a PR lands on.
+    pub fn group_prs(
+        &self,
+        key: GroupBy,
+        sizes: &SizeConfig,
+        date_field: DateField,
+    ) -> Vec<PrGroup> {
+        let all_prs = || self.prs_by_week.iter().flatten().cloned();
+        match key {
+            GroupBy::Week => self
+                .weeks
+                .iter()
+                .zip(&self.prs_by_week)
+                .map(|(week, prs)| PrGroup {
+                    title: format!("Week {}", week.week_num),
+                    prs: prs.clone(),
+                })
+                .collect(),
+            GroupBy::Repo => self
+                .repos
+                .iter()
+                .zip(&self.prs_by_repo)
+                .map(|(repo, prs)| PrGroup {
+                    title: repo.name.clone(),
+                    prs: prs.clone(),
+                })
+                .collect(),
+            GroupBy::Size => [PRSize::S, PRSize::M, PRSize::L, PRSize::XL, PRSize::Unknown]
+                .into_iter()
+                .map(|size| PrGroup {
+                    title: size_label(size, sizes).to_string(),
+                    prs: all_prs().filter(|pr| pr.size(sizes) == size).collect(),
+                })
+                .filter(|group| !group.prs.is_empty())
+                .collect(),
+            GroupBy::Label => {
+                let mut by_label: BTreeMap<String, Vec<PRDetail>> = BTreeMap::new();
+                for pr in all_prs() {
+                    let label = title_label(&pr.title).unwrap_or_else(|| "(no label)".to_string());
+                    by_label.entry(label).or_default().push(pr);
+                }
+                let mut groups: Vec<PrGroup> = by_label
+                    .into_iter()
+                    .map(|(title, prs)| PrGroup { title, prs })
+                    .collect();
+                // Busiest labels first; BTreeMap order breaks ties alphabetically.
+                groups.sort_by_key(|group| std::cmp::Reverse(group.prs.len()));
+                groups
+            }
+            GroupBy::Day => {
+                let mut by_day: BTreeMap<chrono::NaiveDate, Vec<PRDetail>> = BTreeMap::new();
+                for pr in all_prs() {
+                    let at = match (date_field, pr.matched_by) {
+                        (DateField::Merged, _) | (_, Some(MatchedBy::Merged)) => {
+                            pr.merged_at.unwrap_or(pr.created_at)
+                        }
+                        (DateField::Created, _) => pr.created_at,
+                    };
+                    by_day.entry(at.date_naive()).or_default().push(pr);
+                }
+                by_day
+                    .into_iter()
+                    .map(|(day, prs)| PrGroup {
+                        title: day.format("%Y-%m-%d (%a)").to_string(),
+                        prs,
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// List the PRs as one flat list in `order` ([`MonthData::sorted_prs`]) wherever the report
+    /// would list them by week. Weeks and repositories keep their PRs in date order.
+    pub fn sort_prs(&mut self, order: PrOrder) {
+        self.pr_order = Some(order);
+    }
+
+    /// Every PR as one list: in [`MonthData::sort_prs`] order when set, otherwise by week.
+    pub fn sorted_prs(&self, sizes: &SizeConfig) -> Vec<&PRDetail> {
+        let mut prs: Vec<&PRDetail> = self.prs_by_week.iter().flatten().collect();
+        if let Some(order) = self.pr_order {
+            prs.sort_by(|a, b| order.compare(a, b, sizes));
+        }
+        prs
+    }
+
+    /// Reorder `repos` by `order`, moving `prs_by_repo` in lockstep so the two stay index-aligned.
+    /// Ties always fall back to the repo name so the output is stable.
+    pub fn sort_repos(&mut self, order: RepoOrder) {
+        let descending = match order.order {
+            Some(direction) => direction == SortOrder::Desc,
+            None => order.key != RepoSort::Name,
+        };
+        let mut paired: Vec<(RepoData, Vec<PRDetail>)> = std::mem::take(&mut self.repos)
+            .into_iter()
+            .zip(std::mem::take(&mut self.prs_by_repo))
+            .collect();
+        paired.sort_by(|(a, _), (b, _)| {
+            let primary = match order.key {
+                RepoSort::Count => a.pr_count.cmp(&b.pr_count),
+                RepoSort::Name => a.name.cmp(&b.name),
+                RepoSort::LeadTime => a.avg_lead_time.cmp(&b.avg_lead_time),
+            };
+            let primary = if descending {
+                primary.reverse()
+            } else {
+                primary
+            };
+            primary.then_with(|| a.name.cmp(&b.name))
+        });
+        (self.repos, self.prs_by_repo) = paired.into_iter().unzip();
+    }
+
+    fn empty(month: &str, reviewed_count: Option<usize>) -> Self {
+        // Every report label starts with `YYYY-MM`, whatever range follows it.
+        let year: i32 = month[..4].parse().unwrap();
+        let month: u32 = month[5..7].parse().unwrap();
         let month_start = Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).unwrap();
 
         Self {
@@ -190,24 +771,178 @@ impl MonthData {
             size_m: 0,
             size_l: 0,
             size_xl: 0,
+            size_unknown: 0,
             weeks: Vec::new(),
             repos: Vec::new(),
             prs_by_week: Vec::new(),
             prs_by_repo: Vec::new(),
             reviewers: Vec::new(),
-            reviewed_count: 0,
+            reviewer_prs: ReviewerPrs::new(),
+            reviewed_count,
+            merged_prs: 0,
+            review_eligible_prs: 0,
+            truncated_reviews: 0,
+            additions: 0,
+            deletions: 0,
+            extremes: None,
+            first_pr: None,
+            lead_time_histogram: Vec::new(),
+            open_prs: 0,
+            avg_time_to_first_review: None,
+            authors: Vec::new(),
+            review_turnaround: None,
+            monthly_buckets: false,
+            months: Vec::new(),
+            window: None,
+            pr_order: None,
+            pr_limit: None,
+            skewed_prs: Vec::new(),
         }
     }
 
-    /// Render the month-wide size distribution as "xS xM xL xXL".
-    pub fn format_size_distribution(&self) -> String {
-        format!(
-            "{}S {}M {}L {}XL",
-            self.size_s, self.size_m, self.size_l, self.size_xl
+    /// PRs reviewed per PR of your own, measured against the chosen denominator.
+    /// Returns 0.0 when the denominator is empty or the reviewed count is unknown.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::config::ReviewBalanceBase;
+    /// # use gh_log::data::MonthData;
+    /// # fn demo(month: &MonthData) {
+    /// println!("{:.1}:1", month.review_balance(ReviewBalanceBase::Merged));
+    /// # }
+    /// ```
+    pub fn review_balance(&self, base: ReviewBalanceBase) -> f64 {
+        let denominator = self.review_balance_denominator(base);
+        if denominator == 0 {
+            return 0.0;
+        }
+        self.reviewed_count.unwrap_or(0) as f64 / denominator as f64
+    }
+
+    /// Messages for every target in `targets` this month misses, plus a note when reviewer counts
+    /// are incomplete, ready to show to the user.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::config::Config;
+    /// # use gh_log::data::MonthData;
+    /// # fn demo(month: &MonthData, cfg: &Config) {
+    /// for warning in month.warnings(&cfg.targets) {
+    ///     eprintln!("⚠ {}", warning);
+    /// }
+    /// # }
+    /// ```
+    pub fn warnings(&self, targets: &TargetsConfig) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if let Some(limit) = self.pr_limit {
+            warnings.push(format!(
+                "Showing the first {} PRs only (--limit) — stats do not cover the whole month",
+                limit
+            ));
+        }
+        if let Some(max_xl_ratio) = targets.max_xl_ratio
+            && self.total_prs > 0
+        {
+            let xl_ratio = self.size_xl as f64 / self.total_prs as f64;
+            if xl_ratio > max_xl_ratio {
+                warnings.push(format!(
+                    "{:.0}% of PRs were XL — consider smaller PRs",
+                    xl_ratio * 100.0
+                ));
+            }
+        }
+        for pr in &self.skewed_prs {
+            warnings.push(format!(
+                "PR {} ends before it was created — its lead time counts as 0",
+                pr
+            ));
+        }
+        if self.truncated_reviews > 0 {
+            warnings.push(format!(
+                "{} PRs have more reviews than were fetched — reviewer counts are low (raise [gh] review_fetch_limit)",
+                self.truncated_reviews
+            ));
+        }
+        warnings
+    }
+
+    /// Lines added per line deleted this month; `None` when nothing was deleted.
+    pub fn add_delete_ratio(&self) -> Option<f64> {
+        add_delete_ratio(self.additions, self.deletions)
+    }
+
+    /// Number of your own PRs the review balance divides by for `base`.
+    pub fn review_balance_denominator(&self, base: ReviewBalanceBase) -> usize {
+        match base {
+            ReviewBalanceBase::Created => self.total_prs,
+            ReviewBalanceBase::Merged => self.merged_prs,
+            ReviewBalanceBase::ReviewedEligible => self.review_eligible_prs,
+        }
+    }
+
+    /// Render the month-wide size distribution as "xS xM xL xXL" (using `sizes.labels`), plus "x?"
+    /// when some sizes are unknown.
+    pub fn format_size_distribution(&self, sizes: &SizeConfig) -> String {
+        format_size_counts(
+            sizes,
+            self.size_s,
+            self.size_m,
+            self.size_l,
+            self.size_xl,
+            self.size_unknown,
         )
     }
 }
 
+fn format_size_counts(
+    sizes: &SizeConfig,
+    s: usize,
+    m: usize,
+    l: usize,
+    xl: usize,
+    unknown: usize,
+) -> String {
+    let labels = &sizes.labels;
+    let mut out = format!(
+        "{}{} {}{} {}{} {}{}",
+        s, labels.s, m, labels.m, l, labels.l, xl, labels.xl
+    );
+    if unknown > 0 {
+        out.push_str(&format!(" {}?", unknown));
+    }
+    out
+}
+
+/// Pick out the PRs whose number is in `numbers`, ignoring every config filter, for a targeted
+/// lookup. PR numbers are per repository, so one number can match PRs in several repos.
+///
+/// # Examples
+/// ```rust,no_run
+/// # use gh_log::data::select_prs;
+/// # use gh_log::github::DateField;
+/// # fn demo(prs: &[gh_log::github::PullRequest]) {
+/// for pr in select_prs(prs, &[123, 456], DateField::Created) {
+///     println!("#{} {}", pr.number, pr.title);
+/// }
+/// # }
+/// ```
+pub fn select_prs(
+    prs: &[github::PullRequest],
+    numbers: &[u32],
+    date_field: DateField,
+) -> Vec<PRDetail> {
+    let wanted: Vec<github::PullRequest> = prs
+        .iter()
+        .filter(|pr| numbers.contains(&pr.number))
+        .cloned()
+        .collect();
+    build_pr_data(&wanted, date_field, &mut Vec::new())
+        .unwrap_or_default()
+        .iter()
+        .map(PRDetail::from)
+        .collect()
+}
+
 fn avg_duration(durations: &[Duration]) -> Duration {
     if durations.is_empty() {
         return Duration::zero();
@@ -216,81 +951,485 @@ fn avg_duration(durations: &[Duration]) -> Duration {
     Duration::seconds(total_seconds / durations.len() as i64)
 }
 
+/// Nearest-rank percentile (`p` in 0..=100) of `durations`, or zero when empty.
+fn percentile(durations: &[Duration], p: f64) -> Duration {
+    if durations.is_empty() {
+        return Duration::zero();
+    }
+    let mut sorted = durations.to_vec();
+    sorted.sort();
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
 #[derive(Clone)]
 struct PRData {
     number: u32,
     title: String,
     body: Option<String>,
     created_at: DateTime<Utc>,
+    merged_at: Option<DateTime<Utc>>,
+    /// Date that places the PR in a week: created or merged, depending on the `--by` choice.
+    bucket_at: DateTime<Utc>,
     lead_time: Duration,
     repo_name: String,
     additions: u32,
     deletions: u32,
     changed_files: u32,
+    linked_issues: Vec<String>,
+    labels: Vec<String>,
+    matched_by: Option<MatchedBy>,
+    ignored: bool,
+    open: bool,
 }
 
 /// Aggregate raw pull requests into month-level analytics, honoring the provided filters.
-/// Returns `MonthData::empty` when no pull requests remain after exclusions.
+/// Returns `MonthData::empty` when no pull requests remain after exclusions. `date_field` decides
+/// whether weeks are keyed by creation or merge date.
+///
+/// The result depends only on the arguments, so a cached snapshot can be rebuilt under a different
+/// [`Config`] (new filters or size thresholds) without going back to GitHub.
 ///
 /// # Examples
 /// ```rust,no_run
 /// # use gh_log::config::Config;
 /// # use gh_log::data::build_month_data;
-/// # use gh_log::github::PullRequest;
+/// # use gh_log::github::{DateField, PullRequest};
 /// # fn demo(cfg: &Config, prs: Vec<PullRequest>) {
-/// let month = build_month_data("2025-01", prs, 0, cfg);
+/// let month = build_month_data("2025-01", prs, Some(0), DateField::Created, cfg);
 /// println!("Total PRs: {}", month.total_prs);
 /// # }
 /// ```
 pub fn build_month_data(
     month: &str,
-    mut prs: Vec<github::PullRequest>,
-    reviewed_count: usize,
+    prs: Vec<github::PullRequest>,
+    reviewed_count: Option<usize>,
+    date_field: DateField,
     cfg: &Config,
 ) -> MonthData {
-    if prs.is_empty() {
-        return MonthData::empty(month);
+    build_report(
+        month,
+        prs,
+        reviewed_count,
+        date_field,
+        cfg,
+        Buckets::CalendarWeeks,
+        None,
+    )
+}
+
+/// Like [`build_month_data`], but for a rolling `YYYY-MM-DD..YYYY-MM-DD` window such as the one
+/// [`trailing_weeks`] returns: weeks are 7-day buckets counted from the window start rather than
+/// Monday-Sunday calendar weeks, and every bucket is listed even when it has no PRs.
+pub fn build_window_data(
+    window: &str,
+    prs: Vec<github::PullRequest>,
+    reviewed_count: Option<usize>,
+    date_field: DateField,
+    cfg: &Config,
+) -> MonthData {
+    build_window_report(window, prs, reviewed_count, date_field, cfg, false)
+}
+
+/// Like [`build_window_data`], but for a whole year such as the one [`year_range`] returns:
+/// alongside the 7-day buckets, `months` totals each calendar month of the window.
+pub fn build_year_data(
+    window: &str,
+    prs: Vec<github::PullRequest>,
+    reviewed_count: Option<usize>,
+    date_field: DateField,
+    cfg: &Config,
+) -> MonthData {
+    build_window_report(window, prs, reviewed_count, date_field, cfg, true)
+}
+
+fn build_window_report(
+    window: &str,
+    prs: Vec<github::PullRequest>,
+    reviewed_count: Option<usize>,
+    date_field: DateField,
+    cfg: &Config,
+    by_month: bool,
+) -> MonthData {
+    let bounds = window_bounds(window);
+    let buckets = bounds.map_or(Buckets::CalendarWeeks, |(start, end)| Buckets::Window {
+        start,
+        end,
+        by_month,
+    });
+    let mut data = build_report(window, prs, reviewed_count, date_field, cfg, buckets, None);
+    data.window = bounds;
+    data
+}
+
+/// Like [`build_month_data`], but for PRs spanning many months (`print --all-cached`): `weeks`
+/// holds one bucket per calendar month from the first PR to the last, empty months included.
+/// `label` names the report and must start with `YYYY-MM`, e.g. `2025-01..2025-12`.
+pub fn build_history_data(
+    label: &str,
+    prs: Vec<github::PullRequest>,
+    reviewed_count: Option<usize>,
+    date_field: DateField,
+    cfg: &Config,
+) -> MonthData {
+    let mut data = build_report(
+        label,
+        prs,
+        reviewed_count,
+        date_field,
+        cfg,
+        Buckets::Months,
+        None,
+    );
+    data.monthly_buckets = true;
+    data
+}
+
+/// Like [`build_month_data`], but over the union of the PRs created in the month (`created`) and
+/// those merged in it (`merged`), so long-running PRs opened earlier are not missed. Each PR keeps
+/// the [`MatchedBy`] that found it and lands in the week of the date that matched; PRs found by
+/// both searches count once.
+pub fn build_also_merged_data(
+    month: &str,
+    created: Vec<github::PullRequest>,
+    merged: Vec<github::PullRequest>,
+    reviewed_count: Option<usize>,
+    cfg: &Config,
+) -> MonthData {
+    let mut matched: BTreeMap<(String, u32), MatchedBy> = BTreeMap::new();
+    let mut prs = Vec::with_capacity(created.len() + merged.len());
+    for pr in created {
+        matched.insert(
+            (pr.repository.name_with_owner.clone(), pr.number),
+            MatchedBy::Created,
+        );
+        prs.push(pr);
     }
+    for pr in merged {
+        let key = (pr.repository.name_with_owner.clone(), pr.number);
+        match matched.get_mut(&key) {
+            Some(found) => *found = MatchedBy::Both,
+            None => {
+                matched.insert(key, MatchedBy::Merged);
+                prs.push(pr);
+            }
+        }
+    }
+    build_report(
+        month,
+        prs,
+        reviewed_count,
+        DateField::Created,
+        cfg,
+        Buckets::CalendarWeeks,
+        Some(&matched),
+    )
+}
 
-    prs.retain(|pr| !cfg.should_exclude_pr_title(&pr.title));
-    prs.retain(|pr| !cfg.should_exclude_repo(&pr.repository.name_with_owner));
-    if prs.is_empty() {
-        return MonthData::empty(month);
+/// The `weeks`-week window ending on `today`, as a `created:`/`merged:` search range.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::data::trailing_weeks;
+/// let today = chrono::NaiveDate::from_ymd_opt(2026, 1, 10).unwrap();
+/// assert_eq!(trailing_weeks(today, 4), "2025-12-14..2026-01-10");
+/// ```
+pub fn trailing_weeks(today: NaiveDate, weeks: u32) -> String {
+    let start = today - Days::new(u64::from(weeks.max(1)) * 7 - 1);
+    format!("{}..{}", start.format("%Y-%m-%d"), today.format("%Y-%m-%d"))
+}
+
+/// Quarter `quarter` (1-4) of `year` as a `created:`/`merged:` search range; `None` for any other
+/// quarter number.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::data::quarter_range;
+/// assert_eq!(quarter_range(2025, 1).as_deref(), Some("2025-01-01..2025-03-31"));
+/// assert_eq!(quarter_range(2024, 4).as_deref(), Some("2024-10-01..2024-12-31"));
+/// assert_eq!(quarter_range(2025, 5), None);
+/// ```
+pub fn quarter_range(year: i32, quarter: u32) -> Option<String> {
+    if !(1..=4).contains(&quarter) {
+        return None;
     }
+    let start = NaiveDate::from_ymd_opt(year, (quarter - 1) * 3 + 1, 1)?;
+    let end = start + chrono::Months::new(3) - Days::new(1);
+    Some(format!(
+        "{}..{}",
+        start.format("%Y-%m-%d"),
+        end.format("%Y-%m-%d")
+    ))
+}
 
-    let reviewers = extract_reviewers(&prs);
-    let pr_data = match build_pr_data(&prs) {
-        Some(data) => data,
-        None => return MonthData::empty(month),
-    };
+/// The whole of `year` as a `created:`/`merged:` search range.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::data::year_range;
+/// assert_eq!(year_range(2025), "2025-01-01..2025-12-31");
+/// ```
+pub fn year_range(year: i32) -> String {
+    format!("{year:04}-01-01..{year:04}-12-31")
+}
 
-    // Keep ignored repos/titles visible in detail views but drop them from KPI calculations.
-    let pr_data_for_metrics: Vec<PRData> = pr_data
-        .iter()
-        .filter(|pr| {
-            !cfg.should_ignore_repo(&pr.repo_name) && !cfg.should_ignore_pr_title(&pr.title)
-        })
-        .cloned()
-        .collect();
+/// What a compared metric counts, so renderers can format its values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricUnit {
+    /// A plain number of PRs.
+    Count,
+    /// Hours, shown as a duration.
+    Hours,
+    /// PRs per week.
+    PerWeek,
+}
 
-    let first_pr_date = pr_data.first().unwrap().created_at;
-    let last_pr_date = pr_data.last().unwrap().created_at;
+/// One metric in two reports, the first being the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricDelta {
+    pub metric: &'static str,
+    pub unit: MetricUnit,
+    pub a: f64,
+    pub b: f64,
+}
 
-    let metrics_first_pr_date = pr_data_for_metrics
-        .first()
-        .map(|pr| pr.created_at)
-        .unwrap_or(first_pr_date);
-    let metrics_last_pr_date = pr_data_for_metrics
-        .last()
-        .map(|pr| pr.created_at)
-        .unwrap_or(last_pr_date);
+impl MetricDelta {
+    /// `b - a`.
+    pub fn change(&self) -> f64 {
+        self.b - self.a
+    }
 
-    let by_week = group_prs_by_week(&pr_data, first_pr_date, last_pr_date);
-    let by_repo = group_prs_by_repo(&pr_data);
-    let by_repo_for_metrics = group_prs_by_repo(&pr_data_for_metrics);
+    /// Change relative to `a` in percent; `None` when `a` is zero.
+    pub fn percent_change(&self) -> Option<f64> {
+        (self.a != 0.0).then(|| self.change() / self.a * 100.0)
+    }
+}
 
-    // Calculate metrics using only non-ignored PRs
-    let month_start = Utc
+/// Headline metrics of two reports side by side (`gh-log compare`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthComparison {
+    pub metrics: Vec<MetricDelta>,
+}
+
+/// Line up the headline metrics of `a` (the baseline) and `b`. The reviewed count is left out
+/// when either report skipped the review query.
+///
+/// # Examples
+/// ```rust,no_run
+/// # use gh_log::data::{MonthData, compare_months};
+/// # fn run(january: MonthData, february: MonthData) {
+/// for delta in compare_months(&january, &february).metrics {
+///     println!("{}: {:+.1}", delta.metric, delta.change());
+/// }
+/// # }
+/// ```
+pub fn compare_months(a: &MonthData, b: &MonthData) -> MonthComparison {
+    let hours = |d: Duration| d.num_seconds() as f64 / 3600.0;
+    let metric = |metric, unit, a, b| MetricDelta { metric, unit, a, b };
+    let mut metrics = vec![
+        metric(
+            "Total PRs",
+            MetricUnit::Count,
+            a.total_prs as f64,
+            b.total_prs as f64,
+        ),
+        metric(
+            "Avg Lead Time",
+            MetricUnit::Hours,
+            hours(a.avg_lead_time),
+            hours(b.avg_lead_time),
+        ),
+        metric("Frequency", MetricUnit::PerWeek, a.frequency, b.frequency),
+        metric(
+            "Size S",
+            MetricUnit::Count,
+            a.size_s as f64,
+            b.size_s as f64,
+        ),
+        metric(
+            "Size M",
+            MetricUnit::Count,
+            a.size_m as f64,
+            b.size_m as f64,
+        ),
+        metric(
+            "Size L",
+            MetricUnit::Count,
+            a.size_l as f64,
+            b.size_l as f64,
+        ),
+        metric(
+            "Size XL",
+            MetricUnit::Count,
+            a.size_xl as f64,
+            b.size_xl as f64,
+        ),
+    ];
+    if a.size_unknown + b.size_unknown > 0 {
+        metrics.push(metric(
+            "Size ?",
+            MetricUnit::Count,
+            a.size_unknown as f64,
+            b.size_unknown as f64,
+        ));
+    }
+    if let (Some(a_reviewed), Some(b_reviewed)) = (a.reviewed_count, b.reviewed_count) {
+        metrics.push(metric(
+            "Reviewed",
+            MetricUnit::Count,
+            a_reviewed as f64,
+            b_reviewed as f64,
+        ));
+    }
+    MonthComparison { metrics }
+}
+
+/// How [`build_report`] splits PRs into the report's `weeks`.
+#[derive(Debug, Clone, Copy)]
+enum Buckets {
+    /// Monday-Sunday weeks, trimmed to the month (or days) the label names.
+    CalendarWeeks,
+    /// 7-day buckets counted from the start of a rolling window, given as its first and last
+    /// instant; `by_month` also totals each calendar month of the window.
+    Window {
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        by_month: bool,
+    },
+    /// Calendar months, for reports spanning many of them.
+    Months,
+}
+
+fn build_report(
+    month: &str,
+    mut prs: Vec<github::PullRequest>,
+    reviewed_count: Option<usize>,
+    date_field: DateField,
+    cfg: &Config,
+    buckets: Buckets,
+    matched: Option<&BTreeMap<(String, u32), MatchedBy>>,
+) -> MonthData {
+    if prs.is_empty() {
+        return MonthData::empty(month, reviewed_count);
+    }
+
+    prs.retain(|pr| {
+        cfg.should_include_repo(&pr.repository.name_with_owner)
+            && cfg.should_include_pr_title(&pr.title)
+            && cfg.should_include_labels(&pr.labels)
+    });
+    prs.retain(|pr| !cfg.should_exclude_pr_title(&pr.title));
+    prs.retain(|pr| !cfg.should_exclude_repo(&pr.repository.name_with_owner));
+    prs.retain(|pr| cfg.filter.state.keeps(pr.state()));
+    prs.retain(|pr| {
+        pr.author
+            .as_ref()
+            .is_none_or(|author| !cfg.should_exclude_bot(author))
+    });
+    if prs.is_empty() {
+        return MonthData::empty(month, reviewed_count);
+    }
+
+    let (reviewers, reviewer_prs) = extract_reviewers(&prs, cfg);
+    let counted_prs: Vec<&github::PullRequest> = prs
+        .iter()
+        .filter(|pr| {
+            !cfg.should_ignore_repo(&pr.repository.name_with_owner)
+                && !cfg.should_ignore_pr_title(&pr.title)
+        })
+        .collect();
+    let merged_prs = counted_prs
+        .iter()
+        .filter(|pr| pr.merged_at.is_some())
+        .count();
+    let review_eligible_prs = counted_prs
+        .iter()
+        .filter(|pr| !pr.reviews.nodes.is_empty())
+        .count();
+    let truncated_reviews = prs.iter().filter(|pr| pr.reviews.is_truncated()).count();
+    // Unreviewed PRs have no first review, so they are left out rather than counted as zero.
+    let first_review_waits: Vec<Duration> = counted_prs
+        .iter()
+        .filter_map(|pr| time_to_first_review(pr, cfg))
+        .collect();
+    let mut skewed_prs = Vec::new();
+    let mut pr_data = match build_pr_data(&prs, date_field, &mut skewed_prs) {
+        Some(data) => data,
+        None => return MonthData::empty(month, reviewed_count),
+    };
+    // Lead times are measured above; from here on dates are read on the configured clock, so a
+    // PR opened on Sunday evening lands in that Sunday's week.
+    let tz = cfg.display.timezone();
+    for pr in &mut pr_data {
+        pr.created_at = tz.wall_clock(pr.created_at);
+        pr.merged_at = pr.merged_at.map(|at| tz.wall_clock(at));
+        pr.bucket_at = tz.wall_clock(pr.bucket_at);
+    }
+    let issue_re = cfg.display.issue_regex();
+    for pr in &mut pr_data {
+        pr.linked_issues = issue_re.map_or_else(Vec::new, |re| extract_issues(re, &pr.title));
+        pr.ignored = cfg.should_ignore_repo(&pr.repo_name) || cfg.should_ignore_pr_title(&pr.title);
+    }
+    if let Some(matched) = matched {
+        for pr in &mut pr_data {
+            pr.matched_by = matched.get(&(pr.repo_name.clone(), pr.number)).copied();
+            // A PR opened before the month belongs to the week it was merged in.
+            if pr.matched_by == Some(MatchedBy::Merged) {
+                pr.bucket_at = pr.merged_at.unwrap_or(pr.created_at);
+            }
+        }
+        pr_data.sort_by_key(|pr| pr.bucket_at);
+    }
+
+    // Keep ignored repos/titles visible in detail views but drop them from KPI calculations.
+    let pr_data_for_metrics: Vec<PRData> =
+        pr_data.iter().filter(|pr| !pr.ignored).cloned().collect();
+
+    let first_pr_date = pr_data.first().unwrap().bucket_at;
+    let last_pr_date = pr_data.last().unwrap().bucket_at;
+
+    let metrics_first_pr_date = pr_data_for_metrics
+        .first()
+        .map(|pr| pr.bucket_at)
+        .unwrap_or(first_pr_date);
+    let metrics_last_pr_date = pr_data_for_metrics
+        .last()
+        .map(|pr| pr.bucket_at)
+        .unwrap_or(last_pr_date);
+
+    let by_week = match buckets {
+        // Rolling windows list every bucket, so an idle stretch shows up as empty weeks.
+        Buckets::Window { start, end, .. } => {
+            let end = end.max(last_pr_date);
+            group_prs_by_week(&pr_data, start, end, start.min(first_pr_date), end)
+        }
+        Buckets::Months => group_prs_by_month(&pr_data, first_pr_date, last_pr_date),
+        Buckets::CalendarWeeks => {
+            // Weeks never reach outside the requested month, unless a PR itself does (GitHub can
+            // match a PR a few hours across the boundary in UTC), so a boundary week does not
+            // suggest missing days.
+            let (range_start, range_end) = match month_bounds(month) {
+                Some((start, end)) => (start.min(first_pr_date), end.max(last_pr_date)),
+                None => (first_pr_date, last_pr_date),
+            };
+            // Number weeks from the start of the month rather than the first PR, so quiet early
+            // weeks show up as empty and "Week 1" means the same thing every month. Weeks that
+            // have not started yet are left out.
+            let weeks_end = range_end.min(tz.wall_clock(Utc::now())).max(last_pr_date);
+            group_prs_by_week(
+                &pr_data,
+                start_of_week(range_start),
+                weeks_end,
+                range_start,
+                range_end,
+            )
+        }
+    };
+    let by_repo = group_prs_by_repo(&pr_data);
+    let by_repo_for_metrics = group_prs_by_repo(&pr_data_for_metrics);
+
+    // Calculate metrics using only non-ignored PRs
+    let month_start = Utc
         .with_ymd_and_hms(
             metrics_first_pr_date.year(),
             metrics_first_pr_date.month(),
@@ -300,9 +1439,34 @@ pub fn build_month_data(
             0,
         )
         .unwrap();
-    let lead_times_for_metrics: Vec<Duration> =
-        pr_data_for_metrics.iter().map(|pr| pr.lead_time).collect();
+    // Open PRs still count as PRs, but their lead time is not final, so it stays out of the
+    // lead-time figures.
+    let closed_for_metrics: Vec<PRData> = pr_data_for_metrics
+        .iter()
+        .filter(|pr| !pr.open)
+        .cloned()
+        .collect();
+    let lead_times_for_metrics = closed_lead_times(&closed_for_metrics);
+    let additions = pr_data_for_metrics
+        .iter()
+        .map(|pr| u64::from(pr.additions))
+        .sum();
+    let deletions = pr_data_for_metrics
+        .iter()
+        .map(|pr| u64::from(pr.deletions))
+        .sum();
     let avg_lead_time = avg_duration(&lead_times_for_metrics);
+    let extremes = lead_time_extremes(&closed_for_metrics);
+    // Rolling windows count from their own start rather than the calendar month's.
+    let window_start = match buckets {
+        Buckets::Window { start, .. } => start,
+        Buckets::CalendarWeeks | Buckets::Months => month_start,
+    };
+    let first_pr = pr_data_for_metrics.first().map(|pr| FirstPr {
+        pr: pr.into(),
+        at: pr.bucket_at,
+        days_in: (pr.bucket_at - window_start).num_days().max(0),
+    });
     // Frequency is PRs per week — divide the count by (days / 7) so long spans do not skew the rate.
     let frequency = if pr_data_for_metrics.is_empty() {
         0.0
@@ -314,9 +1478,22 @@ pub fn build_month_data(
     };
 
     let week_data = build_week_data(&by_week, cfg);
+    // A year report also totals each calendar month next to its 7-day buckets.
+    let months = match buckets {
+        Buckets::Window {
+            start,
+            end,
+            by_month: true,
+        } => build_week_data(
+            &group_prs_by_month(&pr_data, start, end.max(last_pr_date)),
+            cfg,
+        ),
+        _ => Vec::new(),
+    };
     let pr_details_by_week = build_pr_details_by_week(&by_week);
     let repos = build_repo_data(&by_repo, &by_repo_for_metrics, cfg);
-    let (size_s, size_m, size_l, size_xl) = compute_size_counts(&pr_data_for_metrics, cfg);
+    let (size_s, size_m, size_l, size_xl, size_unknown) =
+        compute_size_counts(&pr_data_for_metrics, cfg);
     let prs_by_repo = build_prs_by_repo(&repos, &by_repo);
 
     MonthData {
@@ -328,28 +1505,157 @@ pub fn build_month_data(
         size_m,
         size_l,
         size_xl,
+        size_unknown,
         weeks: week_data,
         repos,
         prs_by_week: pr_details_by_week,
         prs_by_repo,
         reviewers,
+        reviewer_prs,
         reviewed_count,
+        merged_prs,
+        review_eligible_prs,
+        truncated_reviews,
+        additions,
+        deletions,
+        extremes,
+        first_pr,
+        lead_time_histogram: lead_time_histogram(
+            &closed_for_metrics,
+            cfg.analytics.lead_time_buckets(),
+        ),
+        open_prs: pr_data_for_metrics.len() - closed_for_metrics.len(),
+        avg_time_to_first_review: (!first_review_waits.is_empty())
+            .then(|| avg_duration(&first_review_waits)),
+        authors: Vec::new(),
+        review_turnaround: None,
+        monthly_buckets: false,
+        months,
+        window: None,
+        pr_order: None,
+        pr_limit: None,
+        skewed_prs,
     }
 }
 
-fn group_prs_by_week(
-    pr_data: &[PRData],
-    first_pr_date: DateTime<Utc>,
-    last_pr_date: DateTime<Utc>,
-) -> Vec<(DateTime<Utc>, DateTime<Utc>, Vec<PRData>)> {
-    let days_from_monday = first_pr_date.weekday().num_days_from_monday() as i64;
-    let week1_start = (first_pr_date - Duration::days(days_from_monday))
+/// Time from creation to the earliest review by anyone but the author or a bot; `None` when there
+/// is no such review or the cache predates review timestamps.
+fn time_to_first_review(pr: &github::PullRequest, cfg: &Config) -> Option<Duration> {
+    let author = pr
+        .author
+        .as_ref()
+        .map(|author| cfg.identity.canonical_login(&author.login));
+    pr.reviews
+        .nodes
+        .iter()
+        .filter(|review| Some(cfg.identity.canonical_login(&review.author.login)) != author)
+        .filter(|review| !cfg.should_exclude_bot(&review.author))
+        .filter_map(|review| review.submitted_at)
+        .min()
+        .map(|first| (first - pr.created_at).max(Duration::zero()))
+}
+
+/// Lead times of the PRs in `prs` that are no longer open.
+fn closed_lead_times(prs: &[PRData]) -> Vec<Duration> {
+    prs.iter()
+        .filter(|pr| !pr.open)
+        .map(|pr| pr.lead_time)
+        .collect()
+}
+
+/// Count PRs into the lead-time ranges `edges` (hours) mark out: under the first edge, each
+/// `[lower, upper)` pair, and the last edge or more.
+fn lead_time_histogram(prs: &[PRData], edges: &[u32]) -> Vec<LeadTimeBucket> {
+    let short = |hours: u32| {
+        if hours.is_multiple_of(24) {
+            format!("{}d", hours / 24)
+        } else {
+            format!("{}h", hours)
+        }
+    };
+    let mut buckets: Vec<LeadTimeBucket> = Vec::with_capacity(edges.len() + 1);
+    for (i, &edge) in edges.iter().enumerate() {
+        let label = match i {
+            0 => format!("<{}", short(edge)),
+            _ => format!("{}–{}", short(edges[i - 1]), short(edge)),
+        };
+        buckets.push(LeadTimeBucket { label, count: 0 });
+    }
+    if let Some(&last) = edges.last() {
+        buckets.push(LeadTimeBucket {
+            label: format!("{}+", short(last)),
+            count: 0,
+        });
+    }
+
+    for pr in prs {
+        let index = edges
+            .iter()
+            .take_while(|&&edge| pr.lead_time >= Duration::hours(i64::from(edge)))
+            .count();
+        buckets[index].count += 1;
+    }
+    buckets
+}
+
+/// Earliest-listed PR with the shortest lead time and the one with the longest, or `None` when
+/// there are no PRs.
+fn lead_time_extremes(prs: &[PRData]) -> Option<LeadTimeExtremes> {
+    let fastest = prs.iter().min_by_key(|pr| pr.lead_time)?;
+    // `max_by_key` keeps the last of equal maxima; reverse so ties go to the earliest PR too.
+    let slowest = prs.iter().rev().max_by_key(|pr| pr.lead_time)?;
+    Some(LeadTimeExtremes {
+        fastest: fastest.into(),
+        slowest: slowest.into(),
+    })
+}
+
+/// First and last instant of the report `month` names: the calendar month for `YYYY-MM`, or just
+/// the requested days for a day or day window.
+fn month_bounds(month: &str) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    if month.len() > 7 {
+        return window_bounds(month);
+    }
+    let (year, month) = month.split_once('-')?;
+    let start = Utc
+        .with_ymd_and_hms(year.parse().ok()?, month.parse().ok()?, 1, 0, 0, 0)
+        .single()?;
+    let end = start + chrono::Months::new(1) - Duration::seconds(1);
+    Some((start, end))
+}
+
+/// First and last instant of a `YYYY-MM-DD..YYYY-MM-DD` window or a single `YYYY-MM-DD` day.
+fn window_bounds(window: &str) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let (start, end) = window.split_once("..").unwrap_or((window, window));
+    let start = NaiveDate::parse_from_str(start, "%Y-%m-%d").ok()?;
+    let end = NaiveDate::parse_from_str(end, "%Y-%m-%d").ok()?;
+    Some((
+        start.and_hms_opt(0, 0, 0)?.and_utc(),
+        end.and_hms_opt(23, 59, 59)?.and_utc(),
+    ))
+}
+
+/// Midnight on the Monday of `date`'s week.
+fn start_of_week(date: DateTime<Utc>) -> DateTime<Utc> {
+    let days_from_monday = date.weekday().num_days_from_monday() as i64;
+    (date - Duration::days(days_from_monday))
         .date_naive()
         .and_hms_opt(0, 0, 0)
         .unwrap()
-        .and_utc();
+        .and_utc()
+}
 
-    let days_span = (last_pr_date - week1_start).num_days();
+/// Split PRs into 7-day weeks starting at `week1_start` and covering `last_date`. The first and
+/// last week are trimmed to `range_start..=range_end` so they only span days that belong to the
+/// report.
+fn group_prs_by_week(
+    pr_data: &[PRData],
+    week1_start: DateTime<Utc>,
+    last_date: DateTime<Utc>,
+    range_start: DateTime<Utc>,
+    range_end: DateTime<Utc>,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>, Vec<PRData>)> {
+    let days_span = (last_date - week1_start).num_days();
     let weeks_needed = ((days_span / 7) + 1).max(1) as usize;
 
     let mut weeks: Vec<(DateTime<Utc>, DateTime<Utc>, Vec<PRData>)> = Vec::new();
@@ -360,12 +1666,12 @@ fn group_prs_by_week(
             .and_hms_opt(23, 59, 59)
             .unwrap()
             .and_utc();
-        weeks.push((start, end, Vec::new()));
+        weeks.push((start.max(range_start), end.min(range_end), Vec::new()));
     }
 
     for pr in pr_data {
         for (start, end, prs) in &mut weeks {
-            if *start <= pr.created_at && pr.created_at <= *end {
+            if *start <= pr.bucket_at && pr.bucket_at <= *end {
                 prs.push(pr.clone());
                 break;
             }
@@ -375,6 +1681,34 @@ fn group_prs_by_week(
     weeks
 }
 
+/// Split PRs into calendar months, from the month of `first_date` through that of `last_date`.
+fn group_prs_by_month(
+    pr_data: &[PRData],
+    first_date: DateTime<Utc>,
+    last_date: DateTime<Utc>,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>, Vec<PRData>)> {
+    let mut months: Vec<(DateTime<Utc>, DateTime<Utc>, Vec<PRData>)> = Vec::new();
+    let mut start = Utc
+        .with_ymd_and_hms(first_date.year(), first_date.month(), 1, 0, 0, 0)
+        .unwrap();
+    while start <= last_date {
+        let next = start + chrono::Months::new(1);
+        months.push((start, next - Duration::seconds(1), Vec::new()));
+        start = next;
+    }
+
+    for pr in pr_data {
+        if let Some((_, _, prs)) = months
+            .iter_mut()
+            .find(|(start, end, _)| *start <= pr.bucket_at && pr.bucket_at <= *end)
+        {
+            prs.push(pr.clone());
+        }
+    }
+
+    months
+}
+
 fn group_prs_by_repo(pr_data: &[PRData]) -> BTreeMap<String, Vec<PRData>> {
     let mut by_repo: BTreeMap<String, Vec<PRData>> = BTreeMap::new();
     for pr in pr_data {
@@ -401,8 +1735,9 @@ fn build_week_data(
                 })
                 .cloned()
                 .collect();
-            let lead_times: Vec<Duration> = counted.iter().map(|pr| pr.lead_time).collect();
-            let (size_s, size_m, size_l, size_xl) = compute_size_counts(&counted, cfg);
+            let lead_times = closed_lead_times(&counted);
+            let (size_s, size_m, size_l, size_xl, size_unknown) =
+                compute_size_counts(&counted, cfg);
             WeekData {
                 week_num: i + 1,
                 week_start: *start,
@@ -413,6 +1748,7 @@ fn build_week_data(
                 size_m,
                 size_l,
                 size_xl,
+                size_unknown,
             }
         })
         .collect()
@@ -423,21 +1759,7 @@ fn build_pr_details_by_week(
 ) -> Vec<Vec<PRDetail>> {
     weeks
         .iter()
-        .map(|(_, _, prs)| {
-            prs.iter()
-                .map(|pr| PRDetail {
-                    created_at: pr.created_at,
-                    repo: pr.repo_name.clone(),
-                    number: pr.number,
-                    title: pr.title.clone(),
-                    body: pr.body.clone(),
-                    lead_time: pr.lead_time,
-                    additions: pr.additions,
-                    deletions: pr.deletions,
-                    changed_files: pr.changed_files,
-                })
-                .collect()
-        })
+        .map(|(_, _, prs)| prs.iter().map(PRDetail::from).collect())
         .collect()
 }
 
@@ -450,26 +1772,35 @@ fn build_repo_data(
         .keys()
         .map(|name| {
             if let Some(prs) = counted_repo.get(name) {
-                let lead_times: Vec<Duration> = prs.iter().map(|pr| pr.lead_time).collect();
-                let (size_s, size_m, size_l, size_xl) = compute_size_counts(prs.as_slice(), cfg);
+                let lead_times = closed_lead_times(prs);
+                let (size_s, size_m, size_l, size_xl, size_unknown) =
+                    compute_size_counts(prs.as_slice(), cfg);
                 RepoData {
                     name: name.clone(),
                     pr_count: prs.len(),
                     avg_lead_time: avg_duration(&lead_times),
+                    p50_lead_time: percentile(&lead_times, 50.0),
+                    p90_lead_time: percentile(&lead_times, 90.0),
                     size_s,
                     size_m,
                     size_l,
                     size_xl,
+                    size_unknown,
+                    reviewed_count: None,
                 }
             } else {
                 RepoData {
                     name: name.clone(),
                     pr_count: 0,
                     avg_lead_time: Duration::zero(),
+                    p50_lead_time: Duration::zero(),
+                    p90_lead_time: Duration::zero(),
                     size_s: 0,
                     size_m: 0,
                     size_l: 0,
                     size_xl: 0,
+                    size_unknown: 0,
+                    reviewed_count: None,
                 }
             }
         })
@@ -482,30 +1813,57 @@ fn build_repo_data(
     repos
 }
 
-fn compute_size_counts<T: AsRef<PRData>>(prs: &[T], cfg: &Config) -> (usize, usize, usize, usize) {
+fn compute_size_counts<T: AsRef<PRData>>(
+    prs: &[T],
+    cfg: &Config,
+) -> (usize, usize, usize, usize, usize) {
     let mut size_s = 0;
     let mut size_m = 0;
     let mut size_l = 0;
     let mut size_xl = 0;
+    let mut size_unknown = 0;
 
     for pr in prs {
         let pr = pr.as_ref();
-        match compute_pr_size(pr.additions, pr.deletions, pr.changed_files, &cfg.size) {
+        match compute_pr_size(
+            pr.additions,
+            pr.deletions,
+            pr.changed_files,
+            &pr.repo_name,
+            &cfg.size,
+        ) {
             PRSize::S => size_s += 1,
             PRSize::M => size_m += 1,
             PRSize::L => size_l += 1,
             PRSize::XL => size_xl += 1,
+            PRSize::Unknown => size_unknown += 1,
         }
     }
 
-    (size_s, size_m, size_l, size_xl)
+    (size_s, size_m, size_l, size_xl, size_unknown)
 }
 
-fn extract_reviewers(prs: &[crate::github::PullRequest]) -> Vec<ReviewerData> {
+/// Reviewers ranked by reviews left, and the PRs each of them reviewed.
+fn extract_reviewers(
+    prs: &[crate::github::PullRequest],
+    cfg: &Config,
+) -> (Vec<ReviewerData>, ReviewerPrs) {
     let mut reviewer_map: BTreeMap<String, usize> = BTreeMap::new();
+    let mut reviewer_prs = ReviewerPrs::new();
     for pr in prs {
         for review in &pr.reviews.nodes {
-            *reviewer_map.entry(review.author.login.clone()).or_insert(0) += 1;
+            if cfg.should_exclude_bot(&review.author) {
+                continue;
+            }
+            // Fold alias logins into one entry so split accounts are not ranked separately.
+            let login = cfg.identity.canonical_login(&review.author.login);
+            *reviewer_map.entry(login.to_string()).or_insert(0) += 1;
+            let reviewed = reviewer_prs.entry(login.to_string()).or_default();
+            let pr_ref = (pr.repository.name_with_owner.clone(), pr.number);
+            // Several reviews on one PR still list it once.
+            if !reviewed.contains(&pr_ref) {
+                reviewed.push(pr_ref);
+            }
         }
     }
 
@@ -516,8 +1874,8 @@ fn extract_reviewers(prs: &[crate::github::PullRequest]) -> Vec<ReviewerData> {
             pr_count: *count,
         })
         .collect();
-    reviewers.sort_by(|a, b| b.pr_count.cmp(&a.pr_count));
-    reviewers
+    reviewers.sort_by_key(|r| std::cmp::Reverse(r.pr_count));
+    (reviewers, reviewer_prs)
 }
 
 fn build_prs_by_repo(
@@ -529,59 +1887,88 @@ fn build_prs_by_repo(
         .map(|repo| {
             by_repo
                 .get(&repo.name)
-                .map(|repo_prs| {
-                    repo_prs
-                        .iter()
-                        .map(|pr| PRDetail {
-                            created_at: pr.created_at,
-                            repo: pr.repo_name.clone(),
-                            number: pr.number,
-                            title: pr.title.clone(),
-                            body: pr.body.clone(),
-                            lead_time: pr.lead_time,
-                            additions: pr.additions,
-                            deletions: pr.deletions,
-                            changed_files: pr.changed_files,
-                        })
-                        .collect()
-                })
+                .map(|repo_prs| repo_prs.iter().map(PRDetail::from).collect())
                 .unwrap_or_default()
         })
         .collect()
 }
 
+impl From<&PRData> for PRDetail {
+    fn from(pr: &PRData) -> Self {
+        PRDetail {
+            created_at: pr.created_at,
+            merged_at: pr.merged_at,
+            repo: pr.repo_name.clone(),
+            number: pr.number,
+            title: pr.title.clone(),
+            body: pr.body.clone(),
+            lead_time: pr.lead_time,
+            additions: pr.additions,
+            deletions: pr.deletions,
+            changed_files: pr.changed_files,
+            linked_issues: pr.linked_issues.clone(),
+            labels: pr.labels.clone(),
+            matched_by: pr.matched_by,
+            ignored: pr.ignored,
+            open: pr.open,
+        }
+    }
+}
+
 impl AsRef<PRData> for PRData {
     fn as_ref(&self) -> &PRData {
         self
     }
 }
 
-fn build_pr_data(prs: &[github::PullRequest]) -> Option<Vec<PRData>> {
+/// PRs whose lead time had to be clamped to 0 are added to `skewed` as `owner/repo#123`.
+fn build_pr_data(
+    prs: &[github::PullRequest],
+    date_field: DateField,
+    skewed: &mut Vec<String>,
+) -> Option<Vec<PRData>> {
+    let now = Utc::now();
     let mut pr_data: Vec<PRData> = Vec::with_capacity(prs.len());
     for pr in prs {
-        let lead_time = pr.updated_at - pr.created_at;
-        assert!(
-            lead_time >= Duration::zero(),
-            "Lead time must be non-negative"
-        );
-        assert!(
-            pr.updated_at >= pr.created_at,
-            "Updated date must be >= created date"
-        );
+        // `updatedAt` moves with every later comment or label, so it only stands in for the end
+        // of a PR closed without merging.
+        let open = pr.state() == github::PrState::Open;
+        let end = if open {
+            now.max(pr.created_at)
+        } else {
+            pr.merged_at.unwrap_or(pr.updated_at)
+        };
+        // Clock skew or backfilled timestamps can put the end a little before creation.
+        let mut lead_time = end - pr.created_at;
+        if lead_time < Duration::zero() {
+            skewed.push(format!("{}#{}", pr.repository.name_with_owner, pr.number));
+            lead_time = Duration::zero();
+        }
         pr_data.push(PRData {
             number: pr.number,
             title: pr.title.clone(),
             body: pr.body.clone(),
             created_at: pr.created_at,
+            merged_at: pr.merged_at,
+            // Merged-mode searches only return merged PRs, but fall back to creation defensively.
+            bucket_at: match date_field {
+                DateField::Created => pr.created_at,
+                DateField::Merged => pr.merged_at.unwrap_or(pr.created_at),
+            },
             lead_time,
             repo_name: pr.repository.name_with_owner.clone(),
             additions: pr.additions,
             deletions: pr.deletions,
             changed_files: pr.changed_files,
+            linked_issues: Vec::new(),
+            labels: pr.labels.clone(),
+            matched_by: None,
+            ignored: false,
+            open,
         });
     }
 
-    pr_data.sort_by_key(|pr| pr.created_at);
+    pr_data.sort_by_key(|pr| pr.bucket_at);
     Some(pr_data)
 }
 
@@ -590,6 +1977,13 @@ mod tests {
     use super::*;
     use crate::github::{Author, PullRequest, Repository, Review, Reviews};
 
+    /// The user's config, read on a UTC clock so dates do not depend on the machine's timezone.
+    fn test_config() -> Config {
+        let mut config = Config::default().unwrap();
+        config.display.timezone = Some(crate::config::Timezone::Utc);
+        config
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn create_test_pr(
         number: u32,
@@ -606,11 +2000,17 @@ mod tests {
             number,
             title: title.to_string(),
             body: Some(format!("Description for {}", title)),
+            author: Some(Author {
+                login: "me".to_string(),
+                typename: Some("User".to_string()),
+            }),
             repository: Repository {
                 name_with_owner: repo_name.to_string(),
             },
             created_at,
             updated_at,
+            merged_at: None,
+            state: None,
             additions,
             deletions,
             changed_files,
@@ -620,28 +2020,79 @@ mod tests {
                     .map(|login| Review {
                         author: Author {
                             login: login.to_string(),
+                            typename: None,
                         },
+                        submitted_at: None,
                     })
                     .collect(),
+                total_count: None,
             },
+            labels: vec![],
         }
     }
 
     #[test]
     fn test_build_month_data_empty_input() {
-        let config = Config::default().unwrap();
+        let config = test_config();
         let prs = vec![];
 
-        let result = build_month_data("2024-01", prs, 0, &config);
+        let result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
 
         assert_eq!(result.total_prs, 0);
         assert_eq!(result.weeks.len(), 0);
         assert_eq!(result.repos.len(), 0);
     }
 
+    #[test]
+    fn test_build_month_data_from_client() {
+        use crate::github::GithubClient;
+        use crate::github::mock::MockClient;
+
+        let config = test_config();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let mock = MockClient {
+            prs: vec![
+                create_test_pr(
+                    1,
+                    "Add feature",
+                    "owner/a",
+                    created,
+                    created,
+                    10,
+                    5,
+                    1,
+                    vec![],
+                ),
+                create_test_pr(2, "Fix bug", "owner/b", created, created, 10, 5, 1, vec![]),
+            ],
+            reviewed: crate::github::ReviewedPrs {
+                total: 3,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let client: &dyn GithubClient = &mock;
+
+        let prs = client.fetch_prs("2024-01", DateField::Created).unwrap().prs;
+        let reviewed = client
+            .fetch_reviewed_prs("2024-01", DateField::Created)
+            .unwrap();
+        let result = build_month_data(
+            "2024-01",
+            prs,
+            Some(reviewed.total),
+            DateField::Created,
+            &config,
+        );
+
+        assert_eq!(result.total_prs, 2);
+        assert_eq!(result.repos.len(), 2);
+        assert_eq!(result.reviewed_count, Some(3));
+    }
+
     #[test]
     fn test_build_month_data_single_pr() {
-        let config = Config::default().unwrap();
+        let config = test_config();
         let base_date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
 
         let prs = vec![create_test_pr(
@@ -656,11 +2107,11 @@ mod tests {
             vec!["reviewer1"],
         )];
 
-        let result = build_month_data("2024-01", prs, 1, &config);
+        let result = build_month_data("2024-01", prs, Some(1), DateField::Created, &config);
 
         assert_eq!(result.total_prs, 1);
         assert_eq!(result.size_s, 1);
-        assert_eq!(result.reviewed_count, 1);
+        assert_eq!(result.reviewed_count, Some(1));
         assert_eq!(result.reviewers.len(), 1);
         assert_eq!(result.reviewers[0].login, "reviewer1");
         assert_eq!(result.repos.len(), 1);
@@ -669,7 +2120,7 @@ mod tests {
 
     #[test]
     fn test_build_month_data_multiple_repos_sorted_by_pr_count() {
-        let config = Config::default().unwrap();
+        let config = test_config();
         let base_date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
 
         let prs = vec![
@@ -708,7 +2159,7 @@ mod tests {
             ),
         ];
 
-        let result = build_month_data("2024-01", prs, 0, &config);
+        let result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
 
         assert_eq!(result.total_prs, 3);
         assert_eq!(result.repos.len(), 2);
@@ -721,7 +2172,7 @@ mod tests {
 
     #[test]
     fn test_build_month_data_size_distribution() {
-        let config = Config::default().unwrap();
+        let config = test_config();
         let base_date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
 
         let prs = vec![
@@ -771,28 +2222,90 @@ mod tests {
             ),
         ];
 
-        let result = build_month_data("2024-01", prs, 0, &config);
+        let result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
 
         assert_eq!(result.total_prs, 4);
         assert_eq!(result.size_s, 1);
         assert_eq!(result.size_m, 1);
         assert_eq!(result.size_l, 1);
         assert_eq!(result.size_xl, 1);
-        assert_eq!(result.format_size_distribution(), "1S 1M 1L 1XL");
+        assert_eq!(
+            result.format_size_distribution(&config.size),
+            "1S 1M 1L 1XL"
+        );
+
+        let mut sizes = config.size.clone();
+        sizes.labels = toml::from_str(
+            r#"s = "XS"
+m = "S"
+l = "M"
+xl = "L""#,
+        )
+        .unwrap();
+        assert_eq!(result.format_size_distribution(&sizes), "1XS 1S 1M 1L");
     }
 
     #[test]
-    fn test_build_month_data_week_grouping() {
-        let config = Config::default().unwrap();
-        let base_date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(); // Monday
+    fn test_file_thresholds_come_from_config() {
+        let default = SizeConfig::default();
+        assert_eq!(compute_pr_size(10, 0, 15, "test/repo", &default), PRSize::L);
+        assert_eq!(
+            compute_pr_size(10, 0, 25, "test/repo", &default),
+            PRSize::XL
+        );
+
+        let strict = SizeConfig::new(50, 200, 500, 5, 10).unwrap();
+        assert_eq!(compute_pr_size(10, 0, 4, "test/repo", &strict), PRSize::S);
+        assert_eq!(compute_pr_size(10, 0, 5, "test/repo", &strict), PRSize::L);
+        assert_eq!(compute_pr_size(600, 0, 5, "test/repo", &strict), PRSize::XL);
+        assert_eq!(compute_pr_size(10, 0, 10, "test/repo", &strict), PRSize::XL);
+
+        let relaxed = SizeConfig::new(50, 200, 500, 40, 80).unwrap();
+        assert_eq!(compute_pr_size(10, 0, 25, "test/repo", &relaxed), PRSize::S);
+    }
+
+    #[test]
+    fn test_repo_override_replaces_line_thresholds() {
+        let mut sizes = SizeConfig::default();
+        sizes.overrides.insert(
+            "acme/generated".to_string(),
+            crate::config::SizeOverride {
+                large: Some(5000),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            compute_pr_size(600, 0, 3, "acme/generated", &sizes),
+            PRSize::L
+        );
+        assert_eq!(compute_pr_size(600, 0, 3, "acme/api", &sizes), PRSize::XL);
+        // Only the overridden threshold changes; small and medium still come from [size].
+        assert_eq!(
+            compute_pr_size(100, 0, 3, "acme/generated", &sizes),
+            PRSize::M
+        );
+    }
+
+    #[test]
+    fn test_missing_line_counts_are_unknown_size() {
+        let sizes = SizeConfig::new(50, 200, 500, 15, 25).unwrap();
+        assert_eq!(
+            compute_pr_size(0, 0, 40, "test/repo", &sizes),
+            PRSize::Unknown
+        );
+        assert_eq!(compute_pr_size(0, 0, 0, "test/repo", &sizes), PRSize::S);
+        assert_eq!(PRSize::Unknown.to_string(), "?");
 
+        let config = test_config();
+        let base_date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
         let prs = vec![
             create_test_pr(
                 1,
-                "Week 1 PR 1",
+                "Small PR",
                 "owner/repo",
                 base_date,
-                base_date + Duration::hours(2),
+                base_date + Duration::hours(1),
                 20,
                 10,
                 2,
@@ -800,67 +2313,1199 @@ mod tests {
             ),
             create_test_pr(
                 2,
-                "Week 1 PR 2",
+                "Huge PR",
                 "owner/repo",
-                base_date + Duration::days(2),
-                base_date + Duration::days(2) + Duration::hours(3),
-                30,
-                15,
-                3,
+                base_date + Duration::hours(1),
+                base_date + Duration::hours(3),
+                0,
+                0,
+                3000,
                 vec![],
             ),
+        ];
+
+        let result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
+
+        assert_eq!(result.size_xl, 0);
+        assert_eq!(result.size_unknown, 1);
+        assert_eq!(
+            result.format_size_distribution(&config.size),
+            "1S 0M 0L 0XL 1?"
+        );
+        assert_eq!(result.repos[0].size_unknown, 1);
+    }
+
+    #[test]
+    fn test_select_prs_ignores_filters() {
+        let base_date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let prs = vec![
             create_test_pr(
-                3,
-                "Week 2 PR",
+                7,
+                "test: excluded by title",
                 "owner/repo",
-                base_date + Duration::days(8),
-                base_date + Duration::days(8) + Duration::hours(4),
-                40,
+                base_date,
+                base_date + Duration::hours(1),
                 20,
-                4,
+                10,
+                2,
+                vec![],
+            ),
+            create_test_pr(
+                8,
+                "Other PR",
+                "owner/repo",
+                base_date,
+                base_date + Duration::hours(1),
+                20,
+                10,
+                2,
                 vec![],
             ),
         ];
 
-        let result = build_month_data("2024-01", prs, 0, &config);
+        let selected = select_prs(&prs, &[7, 99], DateField::Created);
 
-        assert_eq!(result.total_prs, 3);
-        assert!(result.weeks.len() >= 2);
-        assert_eq!(result.prs_by_week[0].len(), 2);
-        assert_eq!(result.prs_by_week[1].len(), 1);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].number, 7);
+        assert_eq!(selected[0].lead_time, Duration::hours(1));
     }
 
     #[test]
-    fn test_build_prs_by_repo() {
-        let mut by_repo = BTreeMap::new();
+    fn test_percentile_nearest_rank() {
+        let hours: Vec<Duration> = (1..=10).map(Duration::hours).collect();
+        assert_eq!(percentile(&hours, 50.0), Duration::hours(5));
+        assert_eq!(percentile(&hours, 90.0), Duration::hours(9));
+        assert_eq!(percentile(&hours[..1], 90.0), Duration::hours(1));
+        assert_eq!(percentile(&[], 50.0), Duration::zero());
+    }
 
-        by_repo.insert(
-            "owner/repo-a".to_string(),
-            vec![PRData {
-                number: 1,
-                title: "PR 1".to_string(),
-                body: None,
-                created_at: Utc::now(),
-                lead_time: Duration::hours(1),
-                repo_name: "owner/repo-a".to_string(),
-                additions: 10,
-                deletions: 5,
-                changed_files: 2,
-            }],
+    #[test]
+    fn test_boundary_weeks_stay_inside_the_month() {
+        let config = test_config();
+        let first = Utc.with_ymd_and_hms(2026, 1, 1, 9, 0, 0).unwrap(); // Thursday
+        let last = Utc.with_ymd_and_hms(2026, 1, 27, 9, 0, 0).unwrap(); // Tuesday
+        let prs = vec![
+            create_test_pr(
+                1,
+                "New year PR",
+                "owner/repo",
+                first,
+                first + Duration::hours(2),
+                20,
+                10,
+                2,
+                vec![],
+            ),
+            create_test_pr(
+                2,
+                "Late PR",
+                "owner/repo",
+                last,
+                last + Duration::hours(2),
+                20,
+                10,
+                2,
+                vec![],
+            ),
+        ];
+
+        let result = build_month_data("2026-01", prs, Some(0), DateField::Created, &config);
+
+        let week1 = &result.weeks[0];
+        assert_eq!(
+            week1.week_start,
+            Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            week1.week_end,
+            Utc.with_ymd_and_hms(2026, 1, 4, 23, 59, 59).unwrap()
         );
+        let last_week = result.weeks.last().unwrap();
+        assert_eq!(
+            last_week.week_end,
+            Utc.with_ymd_and_hms(2026, 1, 31, 23, 59, 59).unwrap()
+        );
+    }
 
-        by_repo.insert(
-            "owner/repo-b".to_string(),
-            vec![PRData {
-                number: 2,
-                title: "PR 2".to_string(),
-                body: None,
+    #[test]
+    fn test_sort_prs_orders_flat_listing() {
+        let config = test_config();
+        let monday = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let tuesday = monday + Duration::days(1);
+        let prs = vec![
+            create_test_pr(1, "docs: typo", "owner/b", monday, monday, 5, 1, 1, vec![]),
+            create_test_pr(
+                2,
+                "docs: guide",
+                "owner/a",
+                tuesday,
+                tuesday,
+                300,
+                10,
+                4,
+                vec![],
+            ),
+            create_test_pr(
+                3,
+                "Add parser",
+                "owner/a",
+                tuesday,
+                tuesday,
+                50,
+                10,
+                2,
+                vec![],
+            ),
+        ];
+        let mut result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
+        let numbers = |prs: Vec<&PRDetail>| prs.iter().map(|pr| pr.number).collect::<Vec<_>>();
+        assert_eq!(numbers(result.sorted_prs(&config.size)), [1, 2, 3]);
+
+        let biggest_first = PrOrder {
+            key: PrSort::Size,
+            descending: true,
+        };
+        result.sort_prs(biggest_first);
+        assert_eq!(numbers(result.sorted_prs(&config.size)), [2, 3, 1]);
+        // The weeks themselves stay in date order.
+        let week = result
+            .prs_by_week
+            .iter()
+            .find(|prs| !prs.is_empty())
+            .unwrap();
+        assert_eq!(numbers(week.iter().collect()), [1, 2, 3]);
+
+        let by_repo = PrOrder {
+            key: PrSort::Repo,
+            descending: false,
+        };
+        result.sort_prs(by_repo);
+        assert_eq!(numbers(result.sorted_prs(&config.size)), [2, 3, 1]);
+    }
+
+    #[test]
+    fn test_group_prs_by_label_size_and_day() {
+        let config = test_config();
+        let monday = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let tuesday = monday + Duration::days(1);
+        let prs = vec![
+            create_test_pr(
+                1,
+                "docs: typo",
+                "owner/repo",
+                monday,
+                monday,
+                5,
+                1,
+                1,
+                vec![],
+            ),
+            create_test_pr(
+                2,
+                "Docs: guide",
+                "owner/repo",
+                tuesday,
+                tuesday,
+                300,
+                10,
+                4,
+                vec![],
+            ),
+            create_test_pr(
+                3,
+                "Add parser",
+                "owner/repo",
+                tuesday,
+                tuesday,
+                5,
+                1,
+                1,
+                vec![],
+            ),
+        ];
+        let result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
+        let summary = |groups: Vec<PrGroup>| -> Vec<(String, usize)> {
+            groups
+                .into_iter()
+                .map(|group| (group.title, group.prs.len()))
+                .collect()
+        };
+
+        assert_eq!(
+            summary(result.group_prs(GroupBy::Label, &config.size, DateField::Created)),
+            [("docs".to_string(), 2), ("(no label)".to_string(), 1)]
+        );
+        assert_eq!(
+            summary(result.group_prs(GroupBy::Size, &config.size, DateField::Created)),
+            [("S".to_string(), 2), ("L".to_string(), 1)]
+        );
+        assert_eq!(
+            summary(result.group_prs(GroupBy::Day, &config.size, DateField::Created)),
+            [
+                ("2024-01-15 (Mon)".to_string(), 1),
+                ("2024-01-16 (Tue)".to_string(), 2)
+            ]
+        );
+        assert_eq!(title_label("Fix: handle a: b"), Some("fix".to_string()));
+        assert_eq!(title_label("Note to self: later"), None);
+    }
+
+    #[test]
+    fn test_also_merged_marks_why_each_pr_is_listed() {
+        let config = test_config();
+        let merged = |mut pr: PullRequest| {
+            pr.merged_at = Some(pr.updated_at);
+            pr
+        };
+        let dec = Utc.with_ymd_and_hms(2023, 12, 20, 10, 0, 0).unwrap();
+        let jan_3 = Utc.with_ymd_and_hms(2024, 1, 3, 10, 0, 0).unwrap();
+        let jan_22 = Utc.with_ymd_and_hms(2024, 1, 22, 10, 0, 0).unwrap();
+        let opened_only = create_test_pr(1, "Open", "owner/repo", jan_3, jan_3, 5, 1, 1, vec![]);
+        let both = merged(create_test_pr(
+            2,
+            "Quick",
+            "owner/repo",
+            jan_3,
+            jan_22,
+            5,
+            1,
+            1,
+            vec![],
+        ));
+        let long_lived = merged(create_test_pr(
+            3,
+            "Slow",
+            "owner/repo",
+            dec,
+            jan_22,
+            5,
+            1,
+            1,
+            vec![],
+        ));
+
+        let result = build_also_merged_data(
+            "2024-01",
+            vec![opened_only, both.clone()],
+            vec![both, long_lived],
+            None,
+            &config,
+        );
+        assert_eq!(result.total_prs, 3);
+        let matched: Vec<(u32, Option<MatchedBy>)> = result
+            .prs_by_week
+            .iter()
+            .flatten()
+            .map(|pr| (pr.number, pr.matched_by))
+            .collect();
+        assert_eq!(
+            matched,
+            [
+                (1, Some(MatchedBy::Created)),
+                (2, Some(MatchedBy::Both)),
+                (3, Some(MatchedBy::Merged)),
+            ]
+        );
+        // The December PR sits in the week it was merged, not before the month.
+        let week = result
+            .prs_by_week
+            .iter()
+            .position(|prs| prs.iter().any(|pr| pr.number == 3))
+            .unwrap();
+        assert_eq!(result.weeks[week].week_start.day(), 22);
+    }
+
+    #[test]
+    fn test_linked_issues_come_from_display_pattern() {
+        let mut config = test_config();
+        config
+            .display
+            .set_issue_pattern(r"\[([A-Z]+-\d+)\]")
+            .unwrap();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let prs = vec![create_test_pr(
+            1,
+            "[PROJ-1] [PROJ-2] Fix login",
+            "owner/repo",
+            created,
+            created,
+            5,
+            1,
+            1,
+            vec![],
+        )];
+
+        let result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
+        let pr = &result.prs_by_repo[0][0];
+        assert_eq!(pr.linked_issues, ["PROJ-1", "PROJ-2"]);
+    }
+
+    #[test]
+    fn test_lead_time_extremes() {
+        let config = test_config();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let prs = vec![
+            create_test_pr(
+                1,
+                "Quick",
+                "owner/repo",
+                created,
+                created + Duration::minutes(12),
+                5,
+                1,
+                1,
+                vec![],
+            ),
+            create_test_pr(
+                2,
+                "Slow",
+                "owner/repo",
+                created,
+                created + Duration::days(6),
+                5,
+                1,
+                1,
+                vec![],
+            ),
+            create_test_pr(
+                3,
+                "Middle",
+                "owner/repo",
+                created,
+                created + Duration::hours(3),
+                5,
+                1,
+                1,
+                vec![],
+            ),
+        ];
+
+        let result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
+        let extremes = result.extremes.expect("non-empty month has extremes");
+        assert_eq!(
+            (extremes.fastest.number, extremes.fastest.lead_time),
+            (1, Duration::minutes(12))
+        );
+        assert_eq!(
+            (extremes.slowest.number, extremes.slowest.lead_time),
+            (2, Duration::days(6))
+        );
+
+        let empty = build_month_data("2024-01", vec![], Some(0), DateField::Created, &config);
+        assert!(empty.extremes.is_none());
+    }
+
+    #[test]
+    fn test_review_turnaround_median_and_mean() {
+        let config = test_config();
+        let mut data = build_month_data("2024-01", vec![], Some(3), DateField::Created, &config);
+        data.set_review_turnaround(&[3600, 4 * 3600, 10 * 3600]);
+        assert_eq!(
+            data.review_turnaround,
+            Some(ReviewTurnaround {
+                median: Duration::hours(4),
+                mean: Duration::hours(5),
+                count: 3,
+            })
+        );
+
+        data.set_review_turnaround(&[]);
+        assert_eq!(data.review_turnaround, None);
+    }
+
+    #[test]
+    fn test_lead_time_histogram_uses_configured_edges() {
+        let mut config = test_config();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let lead_times = [
+            Duration::minutes(20),
+            Duration::hours(1),
+            Duration::hours(5),
+            Duration::hours(30),
+            Duration::days(3),
+            Duration::days(9),
+        ];
+        let prs: Vec<_> = lead_times
+            .iter()
+            .enumerate()
+            .map(|(i, &lead)| {
+                let number = i as u32 + 1;
+                let title = format!("PR {}", number);
+                create_test_pr(
+                    number,
+                    &title,
+                    "owner/repo",
+                    created,
+                    created + lead,
+                    5,
+                    1,
+                    1,
+                    vec![],
+                )
+            })
+            .collect();
+
+        let result = build_month_data("2024-01", prs.clone(), Some(0), DateField::Created, &config);
+        let bars: Vec<(&str, usize)> = result
+            .lead_time_histogram
+            .iter()
+            .map(|bucket| (bucket.label.as_str(), bucket.count))
+            .collect();
+        assert_eq!(
+            bars,
+            [
+                ("<1h", 1),
+                ("1h–4h", 1),
+                ("4h–1d", 1),
+                ("1d–3d", 1),
+                ("3d+", 2)
+            ]
+        );
+
+        config.analytics.lead_time_buckets = Some(vec![2, 48]);
+        let result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
+        let bars: Vec<(&str, usize)> = result
+            .lead_time_histogram
+            .iter()
+            .map(|bucket| (bucket.label.as_str(), bucket.count))
+            .collect();
+        assert_eq!(bars, [("<2h", 2), ("2h–2d", 2), ("2d+", 2)]);
+    }
+
+    #[test]
+    fn test_first_pr_counts_days_from_month_start() {
+        let mut config = test_config();
+        let pr = |number, title: &str, day| {
+            let created = Utc.with_ymd_and_hms(2024, 1, day, 9, 0, 0).unwrap();
+            create_test_pr(
+                number,
+                title,
+                "owner/repo",
+                created,
+                created + Duration::hours(2),
+                5,
+                1,
+                1,
+                vec![],
+            )
+        };
+        let prs = vec![
+            pr(1, "docs: notes", 2),
+            pr(2, "Second", 10),
+            pr(3, "First counted", 3),
+        ];
+        config.filter.ignore_patterns = vec!["^docs:".to_string()];
+
+        let result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
+        let first = result.first_pr.expect("non-empty month has a first PR");
+        assert_eq!((first.pr.number, first.days_in), (3, 2));
+        assert_eq!(first.describe(), "Jan 3 (2 days in)");
+
+        let empty = build_month_data("2024-01", vec![], Some(0), DateField::Created, &config);
+        assert!(empty.first_pr.is_none());
+    }
+
+    #[test]
+    fn test_per_repo_review_balance() {
+        let config = test_config();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let prs = vec![
+            create_test_pr(1, "A", "owner/repo-a", created, created, 5, 1, 1, vec![]),
+            create_test_pr(2, "B", "owner/repo-a", created, created, 5, 1, 1, vec![]),
+            create_test_pr(3, "C", "owner/repo-b", created, created, 5, 1, 1, vec![]),
+        ];
+        let mut result = build_month_data("2024-01", prs, Some(3), DateField::Created, &config);
+        assert!(
+            result
+                .repos
+                .iter()
+                .all(|repo| repo.review_balance().is_none())
+        );
+
+        let by_repo = BTreeMap::from([
+            ("owner/repo-a".to_string(), 1),
+            ("owner/elsewhere".to_string(), 2),
+        ]);
+        result.set_reviewed_by_repo(&by_repo);
+        let balances: Vec<(&str, Option<usize>, Option<f64>)> = result
+            .repos
+            .iter()
+            .map(|repo| {
+                (
+                    repo.name.as_str(),
+                    repo.reviewed_count,
+                    repo.review_balance(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            balances,
+            [
+                ("owner/repo-a", Some(1), Some(0.5)),
+                ("owner/repo-b", Some(0), Some(0.0))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_author_summary_review_balance() {
+        let config = test_config();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let merged = created + Duration::hours(4);
+        let prs = vec![
+            create_test_pr(1, "A", "owner/repo", created, merged, 5, 1, 1, vec![]),
+            create_test_pr(2, "B", "owner/repo", created, merged, 5, 1, 1, vec![]),
+        ];
+        let report = build_month_data("2024-01", prs, Some(3), DateField::Created, &config);
+        let summary = AuthorSummary::new("alice", &report, ReviewBalanceBase::Created);
+        assert_eq!(summary.login, "alice");
+        assert_eq!(summary.total_prs, 2);
+        assert_eq!(summary.avg_lead_time, Duration::hours(4));
+        assert_eq!(summary.review_balance, Some(1.5));
+
+        // Reviewing without authoring has no ratio to report.
+        let idle = build_month_data("2024-01", vec![], Some(2), DateField::Created, &config);
+        let summary = AuthorSummary::new("bob", &idle, ReviewBalanceBase::Created);
+        assert_eq!(
+            (summary.reviewed_count, summary.review_balance),
+            (Some(2), None)
+        );
+    }
+
+    #[test]
+    fn test_add_delete_ratio_handles_no_deletions() {
+        let config = test_config();
+        let base = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let prs = vec![
+            create_test_pr(1, "Refactor", "owner/repo", base, base, 100, 50, 2, vec![]),
+            create_test_pr(2, "Feature", "owner/repo", base, base, 200, 0, 2, vec![]),
+        ];
+
+        let result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
+        assert_eq!((result.additions, result.deletions), (300, 50));
+        assert_eq!(result.add_delete_ratio(), Some(6.0));
+
+        let feature = result
+            .prs_by_week
+            .iter()
+            .flatten()
+            .find(|pr| pr.number == 2)
+            .unwrap();
+        assert_eq!(feature.add_delete_ratio(), None);
+        assert_eq!(feature.churn(), "+200/-0");
+        assert_eq!(format_add_delete_ratio(200, 0), "+only");
+
+        let empty = build_month_data("2024-01", vec![], Some(0), DateField::Created, &config);
+        assert_eq!(empty.add_delete_ratio(), None);
+        assert_eq!(
+            format_add_delete_ratio(empty.additions, empty.deletions),
+            "n/a"
+        );
+    }
+
+    #[test]
+    fn test_truncated_reviews_are_flagged() {
+        let config = test_config();
+        let base = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let mut busy = create_test_pr(
+            1,
+            "Busy PR",
+            "owner/repo",
+            base,
+            base + Duration::hours(2),
+            20,
+            10,
+            2,
+            vec!["alice", "bob"],
+        );
+        busy.reviews.total_count = Some(12);
+        let mut complete = create_test_pr(
+            2,
+            "Quiet PR",
+            "owner/repo",
+            base,
+            base + Duration::hours(2),
+            20,
+            10,
+            2,
+            vec!["alice"],
+        );
+        complete.reviews.total_count = Some(1);
+        assert!(busy.reviews.is_truncated());
+        assert!(!complete.reviews.is_truncated());
+
+        let result = build_month_data(
+            "2024-01",
+            vec![busy, complete],
+            Some(0),
+            DateField::Created,
+            &config,
+        );
+
+        assert_eq!(result.truncated_reviews, 1);
+        let warnings = result.warnings(&config.targets);
+        assert!(warnings.iter().any(|w| w.contains("review_fetch_limit")));
+    }
+
+    #[test]
+    fn test_sort_repos_keeps_prs_aligned() {
+        let config = test_config();
+        let base = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let prs = vec![
+            create_test_pr(
+                1,
+                "Fast",
+                "owner/busy",
+                base,
+                base + Duration::hours(1),
+                20,
+                10,
+                2,
+                vec![],
+            ),
+            create_test_pr(
+                2,
+                "Fast",
+                "owner/busy",
+                base,
+                base + Duration::hours(1),
+                20,
+                10,
+                2,
+                vec![],
+            ),
+            create_test_pr(
+                3,
+                "Slow",
+                "owner/slow",
+                base,
+                base + Duration::hours(48),
+                20,
+                10,
+                2,
+                vec![],
+            ),
+            create_test_pr(
+                4,
+                "Mid",
+                "owner/alpha",
+                base,
+                base + Duration::hours(5),
+                20,
+                10,
+                2,
+                vec![],
+            ),
+        ];
+        let mut result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
+        let names = |data: &MonthData| -> Vec<String> {
+            data.repos.iter().map(|repo| repo.name.clone()).collect()
+        };
+        let assert_aligned = |data: &MonthData| {
+            for (repo, prs) in data.repos.iter().zip(&data.prs_by_repo) {
+                assert_eq!(prs.len(), repo.pr_count);
+                assert!(prs.iter().all(|pr| pr.repo == repo.name));
+            }
+        };
+
+        result.sort_repos(RepoOrder {
+            key: RepoSort::LeadTime,
+            order: None,
+        });
+        assert_eq!(names(&result), ["owner/slow", "owner/alpha", "owner/busy"]);
+        assert_aligned(&result);
+
+        result.sort_repos(RepoOrder {
+            key: RepoSort::Name,
+            order: Some(SortOrder::Desc),
+        });
+        assert_eq!(names(&result), ["owner/slow", "owner/busy", "owner/alpha"]);
+        assert_aligned(&result);
+
+        result.sort_repos(RepoOrder::default());
+        assert_eq!(names(&result), ["owner/busy", "owner/alpha", "owner/slow"]);
+        assert_aligned(&result);
+    }
+
+    #[test]
+    fn test_window_weeks_are_anchored_to_the_window_start() {
+        let config = test_config();
+        let today = NaiveDate::from_ymd_opt(2026, 1, 10).unwrap(); // Saturday
+        let window = trailing_weeks(today, 4);
+        let created = Utc.with_ymd_and_hms(2025, 12, 30, 9, 0, 0).unwrap();
+        let prs = vec![create_test_pr(
+            1,
+            "Year-end PR",
+            "owner/repo",
+            created,
+            created + Duration::hours(2),
+            20,
+            10,
+            2,
+            vec![],
+        )];
+
+        let result = build_window_data(&window, prs, Some(0), DateField::Created, &config);
+
+        assert_eq!(result.weeks.len(), 4);
+        assert_eq!(
+            result.weeks[0].week_start,
+            Utc.with_ymd_and_hms(2025, 12, 14, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            result.weeks[3].week_end,
+            Utc.with_ymd_and_hms(2026, 1, 10, 23, 59, 59).unwrap()
+        );
+        let counts: Vec<usize> = result.weeks.iter().map(|week| week.pr_count).collect();
+        assert_eq!(counts, vec![0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_year_report_totals_each_month() {
+        let config = test_config();
+        let pr = |number, month| {
+            let created = Utc.with_ymd_and_hms(2025, month, 15, 12, 0, 0).unwrap();
+            create_test_pr(
+                number,
+                "PR",
+                "owner/repo",
+                created,
+                created + Duration::hours(4),
+                20,
+                10,
+                2,
+                vec![],
+            )
+        };
+        let prs = vec![pr(1, 2), pr(2, 2), pr(3, 11)];
+
+        let result = build_year_data(&year_range(2025), prs, None, DateField::Created, &config);
+
+        assert_eq!(result.months.len(), 12);
+        assert_eq!(
+            result.months[1].week_start,
+            Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap()
+        );
+        let counts: Vec<usize> = result.months.iter().map(|month| month.pr_count).collect();
+        assert_eq!(counts, vec![0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0]);
+        assert_eq!(result.months[1].avg_lead_time, Duration::hours(4));
+        // The weekly buckets are still there, and plain windows have no month totals.
+        assert!(result.weeks.len() >= 52);
+        let window = build_window_data(
+            &quarter_range(2025, 1).unwrap(),
+            vec![pr(4, 2)],
+            None,
+            DateField::Created,
+            &config,
+        );
+        assert!(window.months.is_empty());
+    }
+
+    #[test]
+    fn test_lead_time_ends_at_merge_and_skips_open_prs() {
+        let config = test_config();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let pr = |number, state| {
+            let mut pr = create_test_pr(
+                number,
+                "PR",
+                "owner/repo",
+                created,
+                created + Duration::days(20),
+                20,
+                10,
+                2,
+                vec![],
+            );
+            pr.state = Some(state);
+            pr
+        };
+        let mut merged = pr(1, github::PrState::Merged);
+        merged.merged_at = Some(created + Duration::hours(3));
+        let closed = pr(2, github::PrState::Closed);
+        let open = pr(3, github::PrState::Open);
+
+        let result = build_month_data(
+            "2024-01",
+            vec![merged, closed, open],
+            Some(0),
+            DateField::Created,
+            &config,
+        );
+
+        let lead_times: Vec<(u32, Duration, bool)> = result.prs_by_week[2]
+            .iter()
+            .map(|pr| (pr.number, pr.lead_time, pr.open))
+            .collect();
+        assert_eq!(lead_times[0], (1, Duration::hours(3), false));
+        assert_eq!(lead_times[1], (2, Duration::days(20), false));
+        assert!(lead_times[2].2 && lead_times[2].1 > Duration::days(365));
+        assert_eq!(result.total_prs, 3);
+        assert_eq!(result.open_prs, 1);
+        assert_eq!(result.avg_lead_time, Duration::minutes(10 * 24 * 60 + 90));
+        assert_eq!(result.extremes.unwrap().slowest.number, 2);
+    }
+
+    #[test]
+    fn test_time_to_first_review_skips_unreviewed_prs() {
+        let config = test_config();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let review = |login: &str, hours| Review {
+            author: Author {
+                login: login.to_string(),
+                typename: None,
+            },
+            submitted_at: Some(created + Duration::hours(hours)),
+        };
+        let pr = |number, reviews: Vec<Review>| {
+            let mut pr = create_test_pr(
+                number,
+                "PR",
+                "owner/repo",
+                created,
+                created + Duration::days(1),
+                10,
+                0,
+                1,
+                vec![],
+            );
+            pr.author = Some(Author {
+                login: "me".to_string(),
+                typename: None,
+            });
+            pr.reviews.nodes = reviews;
+            pr
+        };
+        let prs = vec![
+            pr(1, vec![review("bob", 6), review("alice", 2)]),
+            // The author's own replies and bot reviews are not a first review.
+            pr(
+                2,
+                vec![review("me", 1), review("ci[bot]", 1), review("bob", 4)],
+            ),
+            pr(3, vec![]),
+        ];
+
+        let result = build_month_data("2024-01", prs, None, DateField::Created, &config);
+        assert_eq!(result.avg_time_to_first_review, Some(Duration::hours(3)));
+
+        let unreviewed = build_month_data(
+            "2024-01",
+            vec![pr(4, vec![])],
+            None,
+            DateField::Created,
+            &config,
+        );
+        assert_eq!(unreviewed.avg_time_to_first_review, None);
+    }
+
+    #[test]
+    fn test_compare_months_reports_change_and_percent() {
+        let config = test_config();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let pr = |number, hours, additions| {
+            create_test_pr(
+                number,
+                "PR",
+                "owner/repo",
+                created,
+                created + Duration::hours(hours),
+                additions,
+                0,
+                1,
+                vec![],
+            )
+        };
+        let january = build_month_data(
+            "2024-01",
+            vec![pr(1, 4, 10), pr(2, 8, 10)],
+            Some(2),
+            DateField::Created,
+            &config,
+        );
+        let february = build_month_data(
+            "2024-01",
+            vec![pr(3, 3, 10), pr(4, 3, 100), pr(5, 3, 10)],
+            None,
+            DateField::Created,
+            &config,
+        );
+
+        let comparison = compare_months(&january, &february);
+        let by_name = |name: &str| {
+            comparison
+                .metrics
+                .iter()
+                .find(|delta| delta.metric == name)
+                .unwrap()
+                .clone()
+        };
+        let total = by_name("Total PRs");
+        assert_eq!((total.a, total.b, total.change()), (2.0, 3.0, 1.0));
+        assert_eq!(total.percent_change(), Some(50.0));
+        let lead = by_name("Avg Lead Time");
+        assert_eq!((lead.unit, lead.change()), (MetricUnit::Hours, -3.0));
+        assert_eq!(lead.percent_change(), Some(-50.0));
+        let medium = by_name("Size M");
+        assert_eq!((medium.a, medium.percent_change()), (0.0, None));
+        // February skipped the review query, so there is nothing to compare.
+        assert!(comparison.metrics.iter().all(|d| d.metric != "Reviewed"));
+    }
+
+    #[test]
+    fn test_timezone_moves_prs_across_week_boundaries() {
+        let mut config = test_config();
+        // Monday 02:00 UTC is still Sunday evening in New York.
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 2, 0, 0).unwrap();
+        let prs = vec![create_test_pr(
+            1,
+            "PR",
+            "owner/repo",
+            created,
+            created + Duration::hours(5),
+            10,
+            0,
+            1,
+            vec![],
+        )];
+        let week_of = |config: &Config| {
+            let data = build_month_data("2024-01", prs.clone(), None, DateField::Created, config);
+            let week = data
+                .prs_by_week
+                .iter()
+                .position(|week| !week.is_empty())
+                .unwrap();
+            let pr = &data.prs_by_week[week][0];
+            (
+                week,
+                pr.created_at.format("%a %d").to_string(),
+                pr.lead_time,
+            )
+        };
+
+        config.display.timezone = Some(crate::config::Timezone::Utc);
+        assert_eq!(
+            week_of(&config),
+            (2, "Mon 15".to_string(), Duration::hours(5))
+        );
+        config.display.timezone = Some("-05:00".parse().unwrap());
+        assert_eq!(
+            week_of(&config),
+            (1, "Sun 14".to_string(), Duration::hours(5))
+        );
+    }
+
+    #[test]
+    fn test_skewed_timestamps_clamp_lead_time_to_zero() {
+        let config = test_config();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let mut skewed = create_test_pr(
+            1,
+            "PR",
+            "owner/repo",
+            created,
+            created - Duration::seconds(5),
+            20,
+            10,
+            2,
+            vec![],
+        );
+        skewed.state = Some(github::PrState::Closed);
+        let mut merged_early = skewed.clone();
+        merged_early.number = 2;
+        merged_early.state = Some(github::PrState::Merged);
+        merged_early.merged_at = Some(created - Duration::minutes(1));
+
+        let result = build_month_data(
+            "2024-01",
+            vec![skewed, merged_early],
+            Some(0),
+            DateField::Created,
+            &config,
+        );
+
+        let lead_times: Vec<Duration> = result.prs_by_week[2]
+            .iter()
+            .map(|pr| pr.lead_time)
+            .collect();
+        assert_eq!(lead_times, vec![Duration::zero(), Duration::zero()]);
+        assert_eq!(result.avg_lead_time, Duration::zero());
+        assert_eq!(result.skewed_prs, ["owner/repo#1", "owner/repo#2"]);
+        assert!(
+            result
+                .warnings(&config.targets)
+                .iter()
+                .any(|warning| warning.starts_with("PR owner/repo#1 ends before"))
+        );
+    }
+
+    #[test]
+    fn test_history_data_buckets_by_month() {
+        let config = test_config();
+        let pr = |number, created: DateTime<Utc>| {
+            create_test_pr(
+                number,
+                "PR",
+                "owner/repo",
+                created,
+                created + Duration::hours(2),
+                20,
+                10,
+                2,
+                vec![],
+            )
+        };
+        let prs = vec![
+            pr(1, Utc.with_ymd_and_hms(2025, 11, 3, 9, 0, 0).unwrap()),
+            pr(2, Utc.with_ymd_and_hms(2025, 11, 28, 9, 0, 0).unwrap()),
+            pr(3, Utc.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap()),
+        ];
+
+        let result = build_history_data(
+            "2025-11..2026-01",
+            prs,
+            Some(4),
+            DateField::Created,
+            &config,
+        );
+
+        assert!(result.monthly_buckets);
+        assert_eq!(result.total_prs, 3);
+        let counts: Vec<usize> = result.weeks.iter().map(|week| week.pr_count).collect();
+        assert_eq!(counts, vec![2, 0, 1]);
+        assert_eq!(
+            result.weeks[1].week_end,
+            Utc.with_ymd_and_hms(2025, 12, 31, 23, 59, 59).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_build_month_data_week_grouping() {
+        let config = test_config();
+        let base_date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap(); // Monday
+
+        let prs = vec![
+            create_test_pr(
+                1,
+                "Week 1 PR 1",
+                "owner/repo",
+                base_date,
+                base_date + Duration::hours(2),
+                20,
+                10,
+                2,
+                vec![],
+            ),
+            create_test_pr(
+                2,
+                "Week 1 PR 2",
+                "owner/repo",
+                base_date + Duration::days(2),
+                base_date + Duration::days(2) + Duration::hours(3),
+                30,
+                15,
+                3,
+                vec![],
+            ),
+            create_test_pr(
+                3,
+                "Week 2 PR",
+                "owner/repo",
+                base_date + Duration::days(8),
+                base_date + Duration::days(8) + Duration::hours(4),
+                40,
+                20,
+                4,
+                vec![],
+            ),
+        ];
+
+        let result = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
+
+        assert_eq!(result.total_prs, 3);
+        assert!(result.weeks.len() >= 4);
+        // January 2024 starts on a Monday, so the 15th opens week 3.
+        assert!(result.prs_by_week[0].is_empty());
+        assert!(result.prs_by_week[1].is_empty());
+        assert_eq!(result.prs_by_week[2].len(), 2);
+        assert_eq!(result.prs_by_week[3].len(), 1);
+    }
+
+    #[test]
+    fn test_week_numbering_starts_at_month_start() {
+        let config = test_config();
+        let first = Utc.with_ymd_and_hms(2025, 9, 10, 9, 0, 0).unwrap(); // Wednesday
+        let prs = vec![create_test_pr(
+            1,
+            "Mid-month PR",
+            "owner/repo",
+            first,
+            first + Duration::hours(2),
+            20,
+            10,
+            2,
+            vec![],
+        )];
+
+        let result = build_month_data("2025-09", prs, Some(0), DateField::Created, &config);
+
+        let week1 = &result.weeks[0];
+        assert_eq!(week1.week_num, 1);
+        assert_eq!(week1.pr_count, 0);
+        assert_eq!(
+            week1.week_start,
+            Utc.with_ymd_and_hms(2025, 9, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(result.weeks[1].week_num, 2);
+        assert_eq!(result.weeks[1].pr_count, 1);
+        assert_eq!(result.weeks.len(), 5);
+    }
+
+    #[test]
+    fn test_build_prs_by_repo() {
+        let mut by_repo = BTreeMap::new();
+
+        by_repo.insert(
+            "owner/repo-a".to_string(),
+            vec![PRData {
+                number: 1,
+                title: "PR 1".to_string(),
+                body: None,
+                created_at: Utc::now(),
+                merged_at: None,
+                bucket_at: Utc::now(),
+                lead_time: Duration::hours(1),
+                repo_name: "owner/repo-a".to_string(),
+                additions: 10,
+                deletions: 5,
+                changed_files: 2,
+                linked_issues: vec![],
+                labels: vec![],
+                matched_by: None,
+                ignored: false,
+                open: false,
+            }],
+        );
+
+        by_repo.insert(
+            "owner/repo-b".to_string(),
+            vec![PRData {
+                number: 2,
+                title: "PR 2".to_string(),
+                body: None,
                 created_at: Utc::now(),
+                merged_at: None,
+                bucket_at: Utc::now(),
                 lead_time: Duration::hours(2),
                 repo_name: "owner/repo-b".to_string(),
                 additions: 20,
                 deletions: 10,
                 changed_files: 3,
+                linked_issues: vec![],
+                labels: vec![],
+                matched_by: None,
+                ignored: false,
+                open: false,
             }],
         );
 
@@ -869,19 +3514,27 @@ mod tests {
                 name: "owner/repo-a".to_string(),
                 pr_count: 1,
                 avg_lead_time: Duration::hours(1),
+                p50_lead_time: Duration::hours(1),
+                p90_lead_time: Duration::hours(1),
                 size_s: 1,
                 size_m: 0,
                 size_l: 0,
                 size_xl: 0,
+                size_unknown: 0,
+                reviewed_count: None,
             },
             RepoData {
                 name: "owner/repo-b".to_string(),
                 pr_count: 1,
                 avg_lead_time: Duration::hours(2),
+                p50_lead_time: Duration::hours(2),
+                p90_lead_time: Duration::hours(2),
                 size_s: 1,
                 size_m: 0,
                 size_l: 0,
                 size_xl: 0,
+                size_unknown: 0,
+                reviewed_count: None,
             },
         ];
 
@@ -896,7 +3549,7 @@ mod tests {
 
     #[test]
     fn test_ignored_prs_visible_in_detail_but_not_metrics() {
-        let mut config = Config::default().unwrap();
+        let mut config = test_config();
         config.filter.exclude_patterns.clear();
         config.filter.exclude_repos.clear();
         config.filter.ignore_repos.clear();
@@ -929,7 +3582,7 @@ mod tests {
             ),
         ];
 
-        let month_data = build_month_data("2024-01", prs, 0, &config);
+        let month_data = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
 
         assert_eq!(month_data.total_prs, 1);
 
@@ -947,6 +3600,385 @@ mod tests {
             detail_titles.contains(&"docs: Update guide"),
             "expected ignored PR to remain visible in detail view"
         );
+        let ignored: Vec<(u32, bool)> = month_data
+            .prs_by_week
+            .iter()
+            .flatten()
+            .map(|pr| (pr.number, pr.ignored))
+            .collect();
+        assert_eq!(ignored, [(1, false), (2, true)]);
+    }
+
+    #[test]
+    fn test_reviewer_aliases_collapse_into_canonical_login() {
+        let mut config = test_config();
+        config.identity.aliases = vec![vec!["alice".to_string(), "alice-old".to_string()]];
+        let base_date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+
+        let prs = vec![
+            create_test_pr(
+                1,
+                "PR 1",
+                "owner/repo",
+                base_date,
+                base_date + Duration::hours(1),
+                10,
+                5,
+                1,
+                vec!["alice", "bob"],
+            ),
+            create_test_pr(
+                2,
+                "PR 2",
+                "owner/repo",
+                base_date,
+                base_date + Duration::hours(1),
+                10,
+                5,
+                1,
+                vec!["alice-old"],
+            ),
+        ];
+
+        let (reviewers, reviewer_prs) = extract_reviewers(&prs, &config);
+
+        assert_eq!(reviewers.len(), 2);
+        assert_eq!(reviewers[0].login, "alice");
+        assert_eq!(reviewers[0].pr_count, 2);
+        assert_eq!(reviewers[1].login, "bob");
+        assert_eq!(
+            reviewer_prs["alice"],
+            [("owner/repo".to_string(), 1), ("owner/repo".to_string(), 2)]
+        );
+        assert_eq!(reviewer_prs["bob"], [("owner/repo".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_review_balance_for_each_base() {
+        let mut config = test_config();
+        config.filter.exclude_patterns.clear();
+        config.filter.exclude_repos.clear();
+        config.filter.ignore_repos.clear();
+        config.filter.ignore_patterns = vec!["^docs:".to_string()];
+        let base_date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let pr = |number, title, merged: bool, reviewers| {
+            let mut pr = create_test_pr(
+                number,
+                title,
+                "owner/repo",
+                base_date,
+                base_date + Duration::hours(1),
+                10,
+                5,
+                1,
+                reviewers,
+            );
+            pr.merged_at = merged.then_some(pr.updated_at);
+            pr
+        };
+
+        let prs = vec![
+            pr(1, "Merged and reviewed", true, vec!["alice"]),
+            pr(2, "Open, no reviews", false, vec![]),
+            pr(3, "Merged, no reviews", true, vec![]),
+            pr(4, "docs: ignored", true, vec!["bob"]),
+        ];
+        let data = build_month_data("2024-01", prs, Some(6), DateField::Created, &config);
+
+        assert_eq!(data.review_balance(ReviewBalanceBase::Created), 2.0);
+        assert_eq!(data.review_balance(ReviewBalanceBase::Merged), 3.0);
+        assert_eq!(
+            data.review_balance(ReviewBalanceBase::ReviewedEligible),
+            6.0
+        );
+    }
+
+    #[test]
+    fn test_warnings_when_xl_ratio_exceeds_target() {
+        let mut data = MonthData::empty("2024-01", None);
+        data.total_prs = 10;
+        data.size_xl = 3;
+
+        let no_target = TargetsConfig::default();
+        assert!(data.warnings(&no_target).is_empty());
+
+        let strict = TargetsConfig {
+            max_xl_ratio: Some(0.2),
+        };
+        assert_eq!(
+            data.warnings(&strict),
+            vec!["30% of PRs were XL — consider smaller PRs"]
+        );
+
+        let lenient = TargetsConfig {
+            max_xl_ratio: Some(0.3),
+        };
+        assert!(data.warnings(&lenient).is_empty());
+    }
+
+    #[test]
+    fn test_line_totals_group_thousands() {
+        assert_eq!(group_thousands(0), "0");
+        assert_eq!(group_thousands(999), "999");
+        assert_eq!(group_thousands(1_000), "1,000");
+        assert_eq!(group_thousands(1_234_567), "1,234,567");
+    }
+
+    #[test]
+    fn test_review_balance_with_empty_denominator_is_zero() {
+        let data = MonthData::empty("2024-01", Some(3));
+        assert_eq!(data.review_balance(ReviewBalanceBase::Created), 0.0);
+        assert_eq!(data.review_balance(ReviewBalanceBase::Merged), 0.0);
+        assert_eq!(
+            data.review_balance(ReviewBalanceBase::ReviewedEligible),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_state_filter_keeps_matching_prs() {
+        let mut config = test_config();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let pr = |number, state, additions| {
+            let mut pr = create_test_pr(
+                number,
+                "PR",
+                "owner/repo",
+                created,
+                created + Duration::hours(number.into()),
+                additions,
+                0,
+                1,
+                vec![],
+            );
+            pr.state = state;
+            pr
+        };
+        let mut merged = pr(1, Some(github::PrState::Merged), 10);
+        merged.merged_at = Some(created + Duration::hours(1));
+        // Cached before `state` was fetched: inferred from `merged_at`.
+        let legacy_closed = pr(2, None, 1000);
+        let open = pr(3, Some(github::PrState::Open), 10);
+        let prs = vec![merged, legacy_closed, open];
+
+        let numbers = |config: &Config| -> Vec<u32> {
+            build_month_data("2024-01", prs.clone(), None, DateField::Created, config)
+                .prs_by_week
+                .iter()
+                .flatten()
+                .map(|pr| pr.number)
+                .collect()
+        };
+        assert_eq!(numbers(&config), [1, 2, 3]);
+        config.filter.state = github::StateFilter::Merged;
+        assert_eq!(numbers(&config), [1]);
+        config.filter.state = github::StateFilter::Closed;
+        assert_eq!(numbers(&config), [2]);
+
+        let closed = build_month_data("2024-01", prs.clone(), None, DateField::Created, &config);
+        assert_eq!((closed.size_s, closed.size_xl), (0, 1));
+        assert_eq!(closed.avg_lead_time, Duration::hours(2));
+    }
+
+    #[test]
+    fn test_include_lists_keep_only_matching_prs() {
+        let mut config = test_config();
+        config.filter = Default::default();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let pr = |number, title, repo| {
+            create_test_pr(
+                number,
+                title,
+                repo,
+                created,
+                created + Duration::hours(1),
+                10,
+                0,
+                1,
+                vec![],
+            )
+        };
+        let prs = vec![
+            pr(1, "feat: api", "me/api"),
+            pr(2, "fix: api", "me/api"),
+            pr(3, "feat: web", "me/web"),
+            pr(4, "feat: wip api", "me/api"),
+        ];
+
+        let numbers = |config: &Config| -> Vec<u32> {
+            build_month_data("2024-01", prs.clone(), None, DateField::Created, config)
+                .prs_by_week
+                .iter()
+                .flatten()
+                .map(|pr| pr.number)
+                .collect()
+        };
+        assert_eq!(numbers(&config), [1, 2, 3, 4]);
+        config.filter.include_repos = vec!["me/api".to_string()];
+        assert_eq!(numbers(&config), [1, 2, 4]);
+        config.filter.include_patterns = vec!["^feat:".to_string()];
+        assert_eq!(numbers(&config), [1, 4]);
+        // Excludes still apply to whatever the include lists keep.
+        config.filter.exclude_patterns = vec!["wip".to_string()];
+        assert_eq!(numbers(&config), [1]);
+    }
+
+    #[test]
+    fn test_include_labels_keep_labeled_prs() {
+        let mut config = test_config();
+        config.filter = Default::default();
+        let created = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+        let pr = |number, labels: &[&str]| PullRequest {
+            labels: labels.iter().map(|label| label.to_string()).collect(),
+            ..create_test_pr(
+                number,
+                "Change",
+                "me/api",
+                created,
+                created + Duration::hours(1),
+                10,
+                0,
+                1,
+                vec![],
+            )
+        };
+        let prs = vec![pr(1, &["Bug"]), pr(2, &["feature", "ui"]), pr(3, &[])];
+
+        let result = |config: &Config| {
+            build_month_data("2024-01", prs.clone(), None, DateField::Created, config)
+        };
+        let numbers = |data: MonthData| -> Vec<u32> {
+            data.prs_by_week
+                .iter()
+                .flatten()
+                .map(|pr| pr.number)
+                .collect()
+        };
+        assert_eq!(numbers(result(&config)), [1, 2, 3]);
+        let labeled = result(&config);
+        let second = labeled.prs_by_week.iter().flatten().nth(1).unwrap();
+        assert_eq!(second.labels, ["feature", "ui"]);
+
+        config.filter.include_labels = vec!["bug".to_string(), "ui".to_string()];
+        assert_eq!(numbers(result(&config)), [1, 2]);
+    }
+
+    #[test]
+    fn test_bots_are_excluded_unless_included() {
+        let mut config = test_config();
+        config.filter.exclude_patterns.clear();
+        config.filter.exclude_repos.clear();
+        config.filter.include_bots = false;
+        let base_date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
+
+        let human = create_test_pr(
+            1,
+            "Feature",
+            "owner/repo",
+            base_date,
+            base_date + Duration::hours(1),
+            10,
+            5,
+            1,
+            vec!["alice", "codeowners[bot]"],
+        );
+        let mut bot = create_test_pr(
+            2,
+            "Bump serde",
+            "owner/repo",
+            base_date,
+            base_date + Duration::hours(1),
+            2,
+            2,
+            1,
+            vec!["alice"],
+        );
+        bot.author = Some(Author {
+            login: "dependabot".to_string(),
+            typename: Some("Bot".to_string()),
+        });
+        let prs = vec![human, bot];
+
+        let filtered =
+            build_month_data("2024-01", prs.clone(), Some(0), DateField::Created, &config);
+        assert_eq!(filtered.prs_by_week.iter().flatten().count(), 1);
+        let logins: Vec<&str> = filtered
+            .reviewers
+            .iter()
+            .map(|r| r.login.as_str())
+            .collect();
+        assert_eq!(logins, vec!["alice"]);
+        assert_eq!(filtered.reviewers[0].pr_count, 1);
+
+        config.filter.include_bots = true;
+        let unfiltered = build_month_data("2024-01", prs, Some(0), DateField::Created, &config);
+        assert_eq!(unfiltered.prs_by_week.iter().flatten().count(), 2);
+        assert_eq!(unfiltered.reviewers.len(), 2);
+    }
+
+    #[test]
+    fn test_build_month_data_groups_by_merge_date() {
+        let config = test_config();
+        let created = Utc.with_ymd_and_hms(2024, 1, 2, 10, 0, 0).unwrap();
+        let merged = Utc.with_ymd_and_hms(2024, 1, 17, 10, 0, 0).unwrap();
+
+        let mut pr = create_test_pr(
+            1,
+            "Long-running change",
+            "owner/repo",
+            created,
+            merged,
+            20,
+            10,
+            2,
+            vec![],
+        );
+        pr.merged_at = Some(merged);
+
+        let by_created = build_month_data(
+            "2024-01",
+            vec![pr.clone()],
+            Some(0),
+            DateField::Created,
+            &config,
+        );
+        let by_merged = build_month_data("2024-01", vec![pr], Some(0), DateField::Merged, &config);
+
+        assert_eq!(by_created.prs_by_week[0].len(), 1);
+        assert!(by_merged.prs_by_week[0].is_empty());
+        assert_eq!(by_merged.weeks[2].week_start.day(), 15);
+        assert_eq!(by_merged.prs_by_week[2][0].created_at, created);
+        assert_eq!(by_merged.prs_by_week[2][0].merged_at, Some(merged));
+    }
+
+    #[test]
+    fn test_merge_date_grouping_reads_a_fixed_offset() {
+        let mut config = test_config();
+        config.display.timezone = Some("+14:00".parse().unwrap());
+        let created = Utc.with_ymd_and_hms(2024, 1, 2, 10, 0, 0).unwrap();
+        // Sunday evening in UTC, already Monday morning at +14:00.
+        let merged = Utc.with_ymd_and_hms(2024, 1, 14, 12, 0, 0).unwrap();
+        let mut pr = create_test_pr(
+            1,
+            "Late merge",
+            "owner/repo",
+            created,
+            merged,
+            20,
+            10,
+            2,
+            vec![],
+        );
+        pr.merged_at = Some(merged);
+
+        let by_merged = build_month_data("2024-01", vec![pr], Some(0), DateField::Merged, &config);
+
+        assert!(by_merged.prs_by_week[1].is_empty());
+        assert_eq!(by_merged.weeks[2].week_start.day(), 15);
+        assert_eq!(
+            by_merged.prs_by_week[2][0].merged_at,
+            Some(Utc.with_ymd_and_hms(2024, 1, 15, 2, 0, 0).unwrap())
+        );
     }
 
     use proptest::prelude::*;
@@ -978,11 +4010,18 @@ mod tests {
                 title: format!("PR {}", i),
                 body: None,
                 created_at: base_date,
+                merged_at: None,
+                bucket_at: base_date,
                 lead_time: Duration::hours(1),
                 repo_name: format!("owner/repo-{}", i % 5), // 5 different repos
                 additions: 10,
                 deletions: 5,
                 changed_files: 2,
+                linked_issues: vec![],
+                labels: vec![],
+                matched_by: None,
+                ignored: false,
+                open: false,
             }).collect();
 
             let by_repo = group_prs_by_repo(&prs);
@@ -996,7 +4035,7 @@ mod tests {
         fn test_compute_size_counts_sum_equals_input_count(
             pr_count in 1usize..100,
         ) {
-            let config = Config::default().unwrap();
+            let config = test_config();
             let base_date = Utc.with_ymd_and_hms(2024, 1, 15, 10, 0, 0).unwrap();
 
             let prs: Vec<PRData> = (0..pr_count).map(|i| {
@@ -1006,18 +4045,25 @@ mod tests {
                     title: format!("PR {}", i),
                     body: None,
                     created_at: base_date,
+                    merged_at: None,
+                    bucket_at: base_date,
                     lead_time: Duration::hours(1),
                     repo_name: "owner/repo".to_string(),
                     additions,
                     deletions: additions / 2,
                     changed_files: (additions / 50).min(30),
+                    linked_issues: vec![],
+                    labels: vec![],
+                    matched_by: None,
+                    ignored: false,
+                    open: false,
                 }
             }).collect();
 
-            let (s, m, l, xl) = compute_size_counts(&prs, &config);
+            let (s, m, l, xl, unknown) = compute_size_counts(&prs, &config);
 
             // Sum of all sizes should equal input count
-            prop_assert_eq!(s + m + l + xl, pr_count);
+            prop_assert_eq!(s + m + l + xl + unknown, pr_count);
         }
     }
 }
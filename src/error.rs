@@ -0,0 +1,179 @@
+//! gh-log error types.
+//!
+//! The `github`, `cache`, and `config` layers return [`Error`] so library callers can match on the
+//! failure kind instead of parsing message strings. The CLI converts these into `anyhow` errors at
+//! the edge, so the text users see stays the same.
+
+use std::path::PathBuf;
+
+/// Convenience alias used by the library modules.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Every failure the library layers can report.
+///
+/// Display strings are user-facing: `main` prints them verbatim, so keep them actionable.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The `gh` executable could not be found.
+    #[error(
+        "GitHub CLI (gh) is not installed.\nInstall it from: https://cli.github.com/\nIf it is installed outside PATH, set GH_LOG_GH_PATH or [gh] binary in the config."
+    )]
+    GhNotInstalled,
+    /// `gh` runs but reports a failure, which in practice means it is not logged in.
+    #[error(
+        "GitHub CLI (gh) is installed but not working correctly.\nRun 'gh auth login' to authenticate."
+    )]
+    GhNotAuthenticated,
+    /// A GraphQL request failed or returned a payload we could not understand.
+    #[error("GraphQL query failed: {0}")]
+    GraphQl(String),
+    /// A cache file exists but does not deserialize.
+    #[error("Failed to parse cache file: {}", .0.display())]
+    CacheCorrupt(PathBuf),
+    /// A snapshot is dated after the current time, so its age says nothing about freshness.
+    #[error(
+        "cached data for {key} is dated {}, in the future; check the system clock or use --force to refetch",
+        timestamp.format("%Y-%m-%d %H:%M UTC")
+    )]
+    CacheFromFuture {
+        key: String,
+        timestamp: chrono::DateTime<chrono::Utc>,
+    },
+    /// A snapshot exceeds the configured cache bound.
+    #[error("Too many PRs to cache: {count}. Max {max}")]
+    CacheTooLarge { count: usize, max: usize },
+    /// A `--month` argument is not a month, day, or day range within one month.
+    #[error(
+        "Month must be YYYY-MM, YYYY-MM-DD, or YYYY-MM-DD..YYYY-MM-DD within one month, e.g. 2025-11"
+    )]
+    InvalidMonth(String),
+    /// A `--range` argument is not two dates in order.
+    #[error(
+        "Range must be YYYY-MM-DD..YYYY-MM-DD with START on or before END, e.g. 2025-01-01..2025-03-31"
+    )]
+    InvalidRange(String),
+    /// `prefetch --from` is a later month than `--to`.
+    #[error("--from {from} is after --to {to}; the first month must be on or before the last")]
+    ReversedMonths { from: String, to: String },
+    /// A `--quarter` argument is not a year and quarter number.
+    #[error("Quarter must be YYYY-Qn with n from 1 to 4, e.g. 2025-Q1")]
+    InvalidQuarter(String),
+    /// A `--year` argument is not a four-digit year.
+    #[error("Year must be YYYY, e.g. 2025")]
+    InvalidYear(String),
+    /// An `--author` or `--org` argument is not a GitHub login.
+    #[error("Not a GitHub login: '{0}'. Use letters, digits and hyphens, e.g. octocat")]
+    InvalidLogin(String),
+    /// The config file could not be parsed as TOML.
+    #[error("Failed to parse config file: {}", path.display())]
+    ConfigParse {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+    /// A filter pattern in the config is not a valid regex.
+    #[error("Invalid {field}: '{pattern}'")]
+    InvalidPattern {
+        field: &'static str,
+        pattern: String,
+        #[source]
+        source: regex::Error,
+    },
+    /// A config value parsed but is out of range or inconsistent.
+    #[error("Invalid config value for {field}: {message}")]
+    InvalidConfigValue {
+        field: &'static str,
+        message: String,
+    },
+    /// `print --fail-on-empty` found no PRs once filters were applied.
+    #[error("No PRs found for {0} after filters (--fail-on-empty)")]
+    NoPrs(String),
+    /// The OS did not provide a cache or config directory for this platform.
+    #[error("Failed to determine {0} directory")]
+    NoProjectDirs(&'static str),
+    /// Filesystem or process I/O failed; `context` says what we were doing.
+    #[error("{context}")]
+    Io {
+        context: String,
+        #[source]
+        source: std::io::Error,
+    },
+    /// JSON or TOML serialization of our own data failed.
+    #[error("{context}")]
+    Serialize {
+        context: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+/// Process exit codes reported by the `gh-log` binary, so wrapping scripts can tell an empty month
+/// (success) from a setup problem or a transient failure.
+pub mod exit_code {
+    /// Command finished, including months with no PRs.
+    pub const SUCCESS: i32 = 0;
+    /// Any failure without a more specific code (I/O, cache, terminal).
+    pub const FAILURE: i32 = 1;
+    /// `gh` is missing or not authenticated.
+    pub const GH_UNAVAILABLE: i32 = 2;
+    /// The GitHub GraphQL request failed; usually transient, so retrying may help.
+    pub const GITHUB_API: i32 = 3;
+    /// The config file could not be parsed or contains invalid values.
+    pub const CONFIG: i32 = 4;
+    /// `print --fail-on-empty` found no PRs, e.g. to flag an inactive week in CI.
+    pub const EMPTY: i32 = 5;
+    /// Invalid command-line arguments (`EX_USAGE`).
+    pub const USAGE: i32 = 64;
+}
+
+impl Error {
+    /// Exit code the CLI reports for this error; see [`exit_code`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use gh_log::error::{Error, exit_code};
+    /// assert_eq!(Error::GhNotInstalled.exit_code(), exit_code::GH_UNAVAILABLE);
+    /// assert_eq!(Error::GraphQl("timeout".into()).exit_code(), exit_code::GITHUB_API);
+    /// ```
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::GhNotInstalled | Error::GhNotAuthenticated => exit_code::GH_UNAVAILABLE,
+            Error::GraphQl(_) => exit_code::GITHUB_API,
+            Error::ConfigParse { .. }
+            | Error::InvalidPattern { .. }
+            | Error::InvalidConfigValue { .. } => exit_code::CONFIG,
+            Error::InvalidMonth(_)
+            | Error::InvalidRange(_)
+            | Error::ReversedMonths { .. }
+            | Error::InvalidQuarter(_)
+            | Error::InvalidYear(_)
+            | Error::InvalidLogin(_) => exit_code::USAGE,
+            Error::NoPrs(_) => exit_code::EMPTY,
+            Error::CacheCorrupt(_)
+            | Error::CacheFromFuture { .. }
+            | Error::CacheTooLarge { .. }
+            | Error::NoProjectDirs(_)
+            | Error::Io { .. }
+            | Error::Serialize { .. } => exit_code::FAILURE,
+        }
+    }
+
+    /// Wrap an I/O error with a short description of the operation that failed.
+    pub(crate) fn io(context: impl Into<String>, source: std::io::Error) -> Self {
+        Error::Io {
+            context: context.into(),
+            source,
+        }
+    }
+
+    /// Wrap a serializer error with a short description of the data being written.
+    pub(crate) fn serialize(
+        context: impl Into<String>,
+        source: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        Error::Serialize {
+            context: context.into(),
+            source: Box::new(source),
+        }
+    }
+}
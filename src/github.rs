@@ -3,33 +3,120 @@
 //! Thin wrapper around the GitHub CLI that fetches authored and reviewed pull requests through the GraphQL API.
 //! Keeps cursor handling and JSON parsing in one place so higher layers stay test-friendly and free of shell details.
 
-use anyhow::bail;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Error, Result};
+
 /// Keep GraphQL page sizes near the top so batching stays consistent across queries.
 const PR_SEARCH_PAGE_SIZE: usize = 100;
 /// Reviews are sparse, so a smaller page keeps payloads light without extra round trips.
-const PR_REVIEW_PAGE_SIZE: usize = 10;
+/// `[gh] review_fetch_limit` raises it for PRs with long review threads.
+pub const DEFAULT_REVIEW_FETCH_LIMIT: usize = 10;
+/// Labels fetched per PR; PRs rarely carry more, and extras are simply not shown.
+pub const LABEL_FETCH_LIMIT: usize = 10;
+
+/// Environment variable naming the `gh` executable, for installs that are not on `PATH`.
+pub const GH_PATH_ENV: &str = "GH_LOG_GH_PATH";
+
+/// Pick the `gh` executable to run: `GH_LOG_GH_PATH` if set, then the `[gh] binary` config
+/// entry, then plain `gh` looked up on `PATH`.
+///
+/// # Examples
+/// ```rust
+/// # use gh_log::github::resolve_gh_binary;
+/// # use std::path::Path;
+/// if std::env::var_os("GH_LOG_GH_PATH").is_none() {
+///     assert_eq!(resolve_gh_binary(None), Path::new("gh"));
+///     assert_eq!(resolve_gh_binary(Some(Path::new("/opt/gh"))), Path::new("/opt/gh"));
+/// }
+/// ```
+pub fn resolve_gh_binary(configured: Option<&Path>) -> PathBuf {
+    std::env::var_os(GH_PATH_ENV)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| configured.map(Path::to_path_buf))
+        .unwrap_or_else(|| PathBuf::from("gh"))
+}
+
+/// Which timestamp places a pull request inside the requested month.
+///
+/// `Created` answers "what did I start", `Merged` answers "what did I ship"; the choice drives both
+/// the GitHub search qualifier and the week a PR is grouped into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DateField {
+    #[default]
+    Created,
+    Merged,
+}
+
+impl DateField {
+    /// GitHub search qualifier name for this field, e.g. `created` in `created:2025-01`.
+    pub fn qualifier(self) -> &'static str {
+        match self {
+            DateField::Created => "created",
+            DateField::Merged => "merged",
+        }
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 /// Lightweight representation of a GitHub user who authored a review or PR.
 pub struct Author {
     pub login: String,
+    /// GraphQL actor type (`User`, `Bot`, ...); absent in caches written by older versions.
+    #[serde(rename = "__typename", default)]
+    pub typename: Option<String>,
+}
+
+impl Author {
+    /// Returns `true` for GitHub App accounts such as Dependabot or Renovate.
+    ///
+    /// GraphQL reports bots as `__typename: Bot` with a bare login, while REST-style logins carry
+    /// a `[bot]` suffix; either marks the author as a bot.
+    pub fn is_bot(&self) -> bool {
+        self.typename.as_deref() == Some("Bot") || self.login.ends_with("[bot]")
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 /// Review metadata returned by the GitHub GraphQL API.
 pub struct Review {
     pub author: Author,
+    /// When the review was submitted; absent in caches written by older versions.
+    #[serde(rename = "submittedAt", default)]
+    pub submitted_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 /// Wrapper around the list of reviews attached to a pull request.
 pub struct Reviews {
     pub nodes: Vec<Review>,
+    /// Reviews on the PR overall, which can exceed `nodes` when the fetch limit cut the list
+    /// short; absent in caches written by older versions.
+    #[serde(rename = "totalCount", default)]
+    pub total_count: Option<usize>,
+}
+
+impl Reviews {
+    /// Returns `true` when GitHub reported more reviews than were fetched, so reviewer counts
+    /// built from `nodes` undercount this PR.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use gh_log::github::Reviews;
+    /// let reviews = Reviews { nodes: Vec::new(), total_count: Some(12) };
+    /// assert!(reviews.is_truncated());
+    /// ```
+    pub fn is_truncated(&self) -> bool {
+        self.total_count
+            .is_some_and(|total| total > self.nodes.len())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -39,22 +126,85 @@ pub struct Repository {
     pub name_with_owner: String,
 }
 
+/// Where a pull request stands, as GitHub reports it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PrState {
+    Open,
+    Closed,
+    Merged,
+}
+
+/// Which PRs a report keeps by [`PrState`] (`--state`, or `[filter] state`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateFilter {
+    #[default]
+    All,
+    Merged,
+    Open,
+    Closed,
+}
+
+impl StateFilter {
+    /// Returns `true` when `pr` is in the state this filter keeps. Closed means closed without
+    /// merging.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use gh_log::github::{PrState, StateFilter};
+    /// assert!(StateFilter::All.keeps(PrState::Open));
+    /// assert!(!StateFilter::Closed.keeps(PrState::Merged));
+    /// ```
+    pub fn keeps(self, state: PrState) -> bool {
+        match self {
+            StateFilter::All => true,
+            StateFilter::Merged => state == PrState::Merged,
+            StateFilter::Open => state == PrState::Open,
+            StateFilter::Closed => state == PrState::Closed,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 /// Subset of pull request fields needed for analytics and presentation.
 pub struct PullRequest {
     pub number: u32,
     pub title: String,
     pub body: Option<String>,
+    /// PR author; `None` for deleted accounts and caches written by older versions.
+    #[serde(default)]
+    pub author: Option<Author>,
     pub repository: Repository,
     #[serde(rename = "createdAt")]
     pub created_at: DateTime<Utc>,
     #[serde(rename = "updatedAt")]
     pub updated_at: DateTime<Utc>,
+    #[serde(rename = "mergedAt")]
+    pub merged_at: Option<DateTime<Utc>>,
+    /// `None` in caches written by older versions, which are treated as closed.
+    #[serde(default)]
+    pub state: Option<PrState>,
     pub additions: u32,
     pub deletions: u32,
     #[serde(rename = "changedFiles")]
     pub changed_files: u32,
     pub reviews: Reviews,
+    /// Label names, at most [`LABEL_FETCH_LIMIT`] of them.
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+impl PullRequest {
+    /// The PR's state, inferred from `merged_at` for caches that predate `state`: merged when it
+    /// has a merge time, closed otherwise.
+    pub fn state(&self) -> PrState {
+        self.state.unwrap_or(if self.merged_at.is_some() {
+            PrState::Merged
+        } else {
+            PrState::Closed
+        })
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -87,58 +237,265 @@ struct GraphQLPullRequest {
     number: u32,
     title: String,
     body: Option<String>,
+    author: Option<Author>,
     repository: Repository,
     #[serde(rename = "createdAt")]
     created_at: chrono::DateTime<chrono::Utc>,
     #[serde(rename = "updatedAt")]
     updated_at: chrono::DateTime<chrono::Utc>,
+    #[serde(rename = "mergedAt")]
+    merged_at: Option<chrono::DateTime<chrono::Utc>>,
+    state: PrState,
     additions: u32,
     deletions: u32,
     #[serde(rename = "changedFiles")]
     changed_files: u32,
     reviews: Reviews,
+    labels: GraphQLLabels,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQLLabels {
+    nodes: Vec<GraphQLLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQLLabel {
+    name: String,
+}
+
+/// Pull requests a search found, from [`CommandClient::fetch_prs`] and
+/// [`CommandClient::fetch_reviewed_pr_details`].
+#[derive(Debug, Clone, Default)]
+pub struct PrSearch {
+    pub prs: Vec<PullRequest>,
+    /// GitHub's `hasNextPage` where the search stopped: set only when a
+    /// [`CommandClient::with_pr_limit`] cut it short with more PRs left.
+    pub has_next_page: bool,
+}
+
+/// Pull requests the current user reviewed in a month, from
+/// [`CommandClient::fetch_reviewed_prs`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReviewedPrs {
+    /// Total across all repositories.
+    pub total: usize,
+    /// Reviewed PRs per `owner/repo`.
+    pub by_repo: BTreeMap<String, usize>,
+    /// Seconds from each reviewed PR's creation to the reviewer's first review on it. PRs whose
+    /// first review GitHub does not return are left out.
+    pub turnaround_secs: Vec<i64>,
+}
+
+/// The GitHub queries a month's report is built from.
+///
+/// [`CommandClient`] answers them by running `gh`; tests can substitute canned data so the
+/// fetch, cache and aggregation path runs without a GitHub CLI. `login` is a GitHub login, or
+/// `@me` for the authenticated user.
+pub trait GithubClient {
+    /// Pull requests authored by `login` within `month`; see [`CommandClient::fetch_prs_by`].
+    fn fetch_prs_by(&self, login: &str, month: &str, date_field: DateField) -> Result<PrSearch>;
+
+    /// Pull requests `login` reviewed within `month`; see
+    /// [`CommandClient::fetch_reviewed_prs_by`].
+    fn fetch_reviewed_prs_by(
+        &self,
+        login: &str,
+        month: &str,
+        date_field: DateField,
+    ) -> Result<ReviewedPrs>;
+
+    /// Full details of the pull requests `login` reviewed within `month`; see
+    /// [`CommandClient::fetch_reviewed_pr_details_by`].
+    fn fetch_reviewed_pr_details_by(
+        &self,
+        login: &str,
+        month: &str,
+        date_field: DateField,
+    ) -> Result<PrSearch>;
+
+    /// [`GithubClient::fetch_prs_by`] for the authenticated user.
+    fn fetch_prs(&self, month: &str, date_field: DateField) -> Result<PrSearch> {
+        self.fetch_prs_by("@me", month, date_field)
+    }
+
+    /// [`GithubClient::fetch_reviewed_prs_by`] for the authenticated user.
+    fn fetch_reviewed_prs(&self, month: &str, date_field: DateField) -> Result<ReviewedPrs> {
+        self.fetch_reviewed_prs_by("@me", month, date_field)
+    }
+
+    /// [`GithubClient::fetch_reviewed_pr_details_by`] for the authenticated user.
+    fn fetch_reviewed_pr_details(&self, month: &str, date_field: DateField) -> Result<PrSearch> {
+        self.fetch_reviewed_pr_details_by("@me", month, date_field)
+    }
 }
 
 /// GitHub CLI-backed client that hides shell execution details from callers.
 ///
 /// The client centralizes pagination and response parsing so higher layers can remain testable.
-pub struct CommandClient {}
+pub struct CommandClient {
+    gh: PathBuf,
+    review_limit: usize,
+    /// Organization every search is narrowed to, from [`CommandClient::with_org`].
+    org: Option<String>,
+    /// Most PRs a search collects, from [`CommandClient::with_pr_limit`].
+    pr_limit: Option<usize>,
+}
 
 impl CommandClient {
-    /// Instantiate a new client, asserting that the GitHub CLI is installed and reachable.
+    /// Instantiate a new client running `gh_binary` (see [`resolve_gh_binary`]), asserting that
+    /// the GitHub CLI is installed and reachable.
     ///
     /// # Examples
     /// ```rust,no_run
-    /// # use gh_log::github::CommandClient;
-    /// let client = CommandClient::new()?;
-    /// # anyhow::Ok::<_, anyhow::Error>(())
+    /// # use gh_log::github::{CommandClient, resolve_gh_binary};
+    /// let client = CommandClient::new(resolve_gh_binary(None))?;
+    /// # Ok::<_, gh_log::Error>(())
+    /// ```
+    pub fn new(gh_binary: impl Into<PathBuf>) -> Result<Self> {
+        let gh = gh_binary.into();
+        check_gh_installed(&gh)?;
+        Ok(CommandClient {
+            gh,
+            review_limit: DEFAULT_REVIEW_FETCH_LIMIT,
+            org: None,
+            pr_limit: None,
+        })
+    }
+
+    /// Fetch up to `limit` reviews per PR instead of [`DEFAULT_REVIEW_FETCH_LIMIT`], trading a
+    /// larger payload for complete reviewer counts.
+    pub fn with_review_limit(mut self, limit: usize) -> Self {
+        self.review_limit = limit;
+        self
+    }
+
+    /// Only find PRs in repositories owned by `org` (an organization or user), for authored and
+    /// reviewed searches alike.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::github::{CommandClient, DateField};
+    /// let client = CommandClient::new("gh")?.with_org("rust-lang");
+    /// let prs = client.fetch_prs("2025-01", DateField::Created)?.prs;
+    /// println!("{} PRs in rust-lang", prs.len());
+    /// # Ok::<_, gh_log::Error>(())
+    /// ```
+    pub fn with_org(mut self, org: impl Into<String>) -> Self {
+        self.org = Some(org.into());
+        self
+    }
+
+    /// Stop paging a search once `limit` PRs are collected, for a quick look at a busy month.
+    /// The result is then only the first `limit` PRs GitHub returns, not the whole month, and
+    /// [`PrSearch::has_next_page`] tells whether GitHub had more.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::github::{CommandClient, DateField};
+    /// let client = CommandClient::new("gh")?.with_pr_limit(20);
+    /// let search = client.fetch_prs("2025-01", DateField::Created)?;
+    /// assert!(search.prs.len() <= 20);
+    /// if search.has_next_page {
+    ///     println!("showing the first 20 PRs");
+    /// }
+    /// # Ok::<_, gh_log::Error>(())
     /// ```
-    pub fn new() -> anyhow::Result<Self> {
-        check_gh_installed()?;
-        Ok(CommandClient {})
+    pub fn with_pr_limit(mut self, limit: usize) -> Self {
+        self.pr_limit = Some(limit);
+        self
+    }
+
+    /// The ` org:NAME` search qualifier, or nothing when searches are not narrowed.
+    fn org_qualifier(&self) -> String {
+        self.org
+            .as_ref()
+            .map(|org| format!(" org:{}", org))
+            .unwrap_or_default()
     }
 
     /// Fetch pull requests authored by the current user within the provided month (YYYY-MM).
     ///
     /// Uses cursor-based pagination on the search API so high-volume months do not drop results and
-    /// keeps the paging contract identical to other GitHub queries in this crate.
+    /// keeps the paging contract identical to other GitHub queries in this crate. `date_field`
+    /// picks whether the month matches the creation or the merge date.
     ///
     /// # Examples
     /// ```rust,no_run
-    /// # use gh_log::github::CommandClient;
-    /// let client = CommandClient::new()?;
-    /// let prs = client.fetch_prs("2025-01")?;
+    /// # use gh_log::github::{CommandClient, DateField};
+    /// let client = CommandClient::new("gh")?;
+    /// let prs = client.fetch_prs("2025-01", DateField::Created)?.prs;
     /// println!("Fetched {} PRs", prs.len());
-    /// # anyhow::Ok::<_, anyhow::Error>(())
+    /// # Ok::<_, gh_log::Error>(())
     /// ```
-    pub fn fetch_prs(&self, month: &str) -> anyhow::Result<Vec<PullRequest>> {
-        let mut all_prs = Vec::new();
-        let mut has_next_page = true;
+    pub fn fetch_prs(&self, month: &str, date_field: DateField) -> Result<PrSearch> {
+        self.fetch_prs_by("@me", month, date_field)
+    }
+
+    /// [`CommandClient::fetch_prs`] for another GitHub user, such as one of your reports.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::github::{CommandClient, DateField};
+    /// let client = CommandClient::new("gh")?;
+    /// let prs = client.fetch_prs_by("octocat", "2025-01", DateField::Created)?.prs;
+    /// println!("octocat opened {} PRs", prs.len());
+    /// # Ok::<_, gh_log::Error>(())
+    /// ```
+    pub fn fetch_prs_by(
+        &self,
+        login: &str,
+        month: &str,
+        date_field: DateField,
+    ) -> Result<PrSearch> {
+        self.search_prs(&format!("author:{}", login), month, date_field)
+    }
+
+    /// Fetch full details of the pull requests the current user reviewed within the given month.
+    ///
+    /// Same shape as [`CommandClient::fetch_prs`], so the result can go through the usual
+    /// month aggregation to describe review load, not just count it.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::github::{CommandClient, DateField};
+    /// let client = CommandClient::new("gh")?;
+    /// let prs = client.fetch_reviewed_pr_details("2025-01", DateField::Created)?.prs;
+    /// println!("Reviewed {} PRs", prs.len());
+    /// # Ok::<_, gh_log::Error>(())
+    /// ```
+    pub fn fetch_reviewed_pr_details(
+        &self,
+        month: &str,
+        date_field: DateField,
+    ) -> Result<PrSearch> {
+        self.fetch_reviewed_pr_details_by("@me", month, date_field)
+    }
+
+    /// [`CommandClient::fetch_reviewed_pr_details`] for another GitHub user.
+    pub fn fetch_reviewed_pr_details_by(
+        &self,
+        login: &str,
+        month: &str,
+        date_field: DateField,
+    ) -> Result<PrSearch> {
+        self.search_prs(&format!("reviewed-by:{}", login), month, date_field)
+    }
+
+    /// Page through a PR search narrowed by `filter` (e.g. `author:@me`) and collect full nodes.
+    fn search_prs(&self, filter: &str, month: &str, date_field: DateField) -> Result<PrSearch> {
+        let mut pages = Vec::new();
         let mut cursor: Option<String> = None;
+        let mut collected = 0;
 
         // Cursor-based pagination keeps us from missing PRs in busy months that span multiple pages.
         // Reuse the same paging loop as fetch_prs so both commands honor GitHub's cursor protocol.
-        while has_next_page {
+        // Ends with GitHub's hasNextPage, which stays set when the limit stopped the search.
+        let has_next_page = loop {
+            // Under a limit, ask for no more than is still missing so the last page stays small.
+            let page_size = self.pr_limit.map_or(PR_SEARCH_PAGE_SIZE, |limit| {
+                (limit - collected).min(PR_SEARCH_PAGE_SIZE)
+            });
             let after_clause = cursor
                 .as_ref()
                 .map(|c| format!(r#", after: "{}""#, c))
@@ -146,7 +503,7 @@ impl CommandClient {
 
             let query = format!(
                 r#"{{
-  search(query: "is:pr author:@me created:{month}", type: ISSUE, first: {page_size}{after_clause}) {{
+  search(query: "is:pr {filter}{org} {qualifier}:{month}", type: ISSUE, first: {page_size}{after_clause}) {{
     pageInfo {{
       hasNextPage
       endCursor
@@ -156,82 +513,198 @@ impl CommandClient {
         number
         title
         body
+        author {{
+          __typename
+          login
+        }}
         repository {{
           nameWithOwner
         }}
         createdAt
         updatedAt
+        mergedAt
+        state
         additions
         deletions
         changedFiles
         reviews(first: {review_page_size}) {{
+          totalCount
           nodes {{
             author {{
+              __typename
               login
             }}
+            submittedAt
+          }}
+        }}
+        labels(first: {label_limit}) {{
+          nodes {{
+            name
           }}
         }}
       }}
     }}
   }}
 }}"#,
+                filter = filter,
+                org = self.org_qualifier(),
+                qualifier = date_field.qualifier(),
                 month = month,
-                page_size = PR_SEARCH_PAGE_SIZE,
+                page_size = page_size,
                 after_clause = after_clause,
-                review_page_size = PR_REVIEW_PAGE_SIZE,
+                review_page_size = self.review_limit,
+                label_limit = LABEL_FETCH_LIMIT,
             );
 
-            let output = Command::new("gh")
-                .arg("api")
-                .arg("graphql")
-                .arg("-f")
-                .arg(format!("query={}", query))
-                .output()?;
-            if !output.status.success() {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                bail!("GraphQL query failed: {}", stderr);
-            }
-
-            let json_str = String::from_utf8_lossy(&output.stdout);
-            let response: GraphQLResponse = serde_json::from_str(&json_str)?;
+            let response: GraphQLResponse = parse_response(&self.graphql(&query, None)?)?;
 
+            let mut page = Vec::with_capacity(response.data.search.nodes.len());
             for pr in response.data.search.nodes {
-                all_prs.push(PullRequest {
+                page.push(PullRequest {
                     number: pr.number,
                     title: pr.title,
                     body: pr.body,
+                    author: pr.author,
                     repository: pr.repository,
                     created_at: pr.created_at,
                     updated_at: pr.updated_at,
+                    merged_at: pr.merged_at,
+                    state: Some(pr.state),
                     additions: pr.additions,
                     deletions: pr.deletions,
                     changed_files: pr.changed_files,
                     reviews: pr.reviews,
+                    labels: pr
+                        .labels
+                        .nodes
+                        .into_iter()
+                        .map(|label| label.name)
+                        .collect(),
                 });
             }
+            collected += page.len();
+            pages.push(page);
 
-            has_next_page = response.data.search.page_info.has_next_page;
+            let has_next_page = response.data.search.page_info.has_next_page;
+            if !has_next_page || self.pr_limit.is_some_and(|limit| collected >= limit) {
+                break has_next_page;
+            }
             cursor = response.data.search.page_info.end_cursor;
+        };
+
+        let (all_prs, duplicates) = merge_pages(pages);
+        if duplicates > 0 {
+            eprintln!(
+                "Warning: dropped {} duplicate PR(s) returned across result pages",
+                duplicates
+            );
+        }
+        Ok(PrSearch {
+            prs: all_prs,
+            has_next_page,
+        })
+    }
+
+    /// Run an arbitrary GraphQL `query` through `gh api graphql` and return each page's response.
+    ///
+    /// Queries that declare a `$endCursor: String` variable are paged with the same cursor loop as
+    /// the built-in searches: the first `pageInfo { hasNextPage endCursor }` found in a response
+    /// feeds the next request until `hasNextPage` is false. Other queries run once.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use gh_log::github::CommandClient;
+    /// let client = CommandClient::new("gh")?;
+    /// let pages = client.raw_query("{ viewer { login } }")?;
+    /// println!("{}", pages[0]["data"]["viewer"]["login"]);
+    /// # Ok::<_, gh_log::Error>(())
+    /// ```
+    pub fn raw_query(&self, query: &str) -> Result<Vec<serde_json::Value>> {
+        let paginated = query.contains("$endCursor");
+        let mut pages = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let response: serde_json::Value =
+                parse_response(&self.graphql(query, cursor.as_deref())?)?;
+            let next = find_page_info(&response)
+                .filter(|_| paginated)
+                .and_then(|(has_next_page, end_cursor)| end_cursor.filter(|_| has_next_page));
+            pages.push(response);
+
+            match next {
+                Some(end_cursor) => cursor = Some(end_cursor),
+                None => return Ok(pages),
+            }
+        }
+    }
+
+    /// Login of the user `gh` is authenticated as.
+    fn viewer_login(&self) -> Result<String> {
+        let response: serde_json::Value =
+            parse_response(&self.graphql("{ viewer { login } }", None)?)?;
+        response["data"]["viewer"]["login"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| Error::GraphQl("viewer login missing from response".to_string()))
+    }
+
+    /// Run one `gh api graphql` request and return its raw stdout, binding `$endCursor` when given.
+    fn graphql(&self, query: &str, end_cursor: Option<&str>) -> Result<Vec<u8>> {
+        let mut command = Command::new(&self.gh);
+        command
+            .arg("api")
+            .arg("graphql")
+            .arg("-f")
+            .arg(format!("query={}", query));
+        if let Some(end_cursor) = end_cursor {
+            command.arg("-f").arg(format!("endCursor={}", end_cursor));
+        }
+
+        let output = command
+            .output()
+            .map_err(|e| Error::io("Failed to run gh api graphql", e))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(Error::GraphQl(stderr.to_string()));
         }
 
-        Ok(all_prs)
+        Ok(output.stdout)
     }
 
-    /// Count pull requests the current user reviewed within the given month (YYYY-MM).
+    /// Count pull requests the current user reviewed within the given month (YYYY-MM), in total
+    /// and per repository.
     ///
     /// Reuses the same cursor loop as `fetch_prs` while relying on `issueCount` for the aggregate so the
-    /// total remains accurate even when pagination schema changes.
+    /// total remains accurate even when pagination schema changes. Only the repository of each
+    /// node is requested, which keeps the pages small.
     ///
     /// # Examples
     /// ```rust,no_run
-    /// # use gh_log::github::CommandClient;
-    /// let client = CommandClient::new()?;
-    /// let reviewed = client.fetch_reviewed_prs("2025-01")?;
-    /// println!("Reviewed {} PRs", reviewed);
-    /// # anyhow::Ok::<_, anyhow::Error>(())
+    /// # use gh_log::github::{CommandClient, DateField};
+    /// let client = CommandClient::new("gh")?;
+    /// let reviewed = client.fetch_reviewed_prs("2025-01", DateField::Created)?;
+    /// println!("Reviewed {} PRs in {} repos", reviewed.total, reviewed.by_repo.len());
+    /// # Ok::<_, gh_log::Error>(())
     /// ```
-    pub fn fetch_reviewed_prs(&self, month: &str) -> anyhow::Result<usize> {
-        let mut total_count = 0;
+    pub fn fetch_reviewed_prs(&self, month: &str, date_field: DateField) -> Result<ReviewedPrs> {
+        self.fetch_reviewed_prs_by("@me", month, date_field)
+    }
+
+    /// [`CommandClient::fetch_reviewed_prs`] for another GitHub user.
+    pub fn fetch_reviewed_prs_by(
+        &self,
+        login: &str,
+        month: &str,
+        date_field: DateField,
+    ) -> Result<ReviewedPrs> {
+        // The reviews filter needs a real login, so resolve "@me" once up front.
+        let reviewer = if login == "@me" {
+            self.viewer_login()?
+        } else {
+            login.to_string()
+        };
+        let mut reviewed = ReviewedPrs::default();
         let mut has_next_page = true;
         let mut cursor: Option<String> = None;
 
@@ -243,36 +716,50 @@ impl CommandClient {
 
             let query = format!(
                 r#"{{
-  search(query: "is:pr reviewed-by:@me created:{month}", type: ISSUE, first: {page_size}{after_clause}) {{
+  search(query: "is:pr reviewed-by:{login}{org} {qualifier}:{month}", type: ISSUE, first: {page_size}{after_clause}) {{
     pageInfo {{
       hasNextPage
       endCursor
     }}
     issueCount
+    nodes {{
+      ... on PullRequest {{
+        createdAt
+        repository {{
+          nameWithOwner
+        }}
+        reviews(first: 1, author: "{reviewer}") {{
+          nodes {{
+            submittedAt
+          }}
+        }}
+      }}
+    }}
   }}
 }}"#,
+                login = login,
+                org = self.org_qualifier(),
+                reviewer = reviewer,
+                qualifier = date_field.qualifier(),
                 month = month,
                 page_size = PR_SEARCH_PAGE_SIZE,
                 after_clause = after_clause,
             );
 
-            let output = Command::new("gh")
-                .arg("api")
-                .arg("graphql")
-                .arg("-f")
-                .arg(format!("query={}", query))
-                .output()?;
-            if !output.status.success() {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                bail!("GraphQL query failed: {}", stderr);
-            }
-
-            let json_str = String::from_utf8_lossy(&output.stdout);
-            let response: serde_json::Value = serde_json::from_str(&json_str)?;
+            let response: serde_json::Value = parse_response(&self.graphql(&query, None)?)?;
 
             if let Some(issue_count) = response["data"]["search"]["issueCount"].as_u64() {
                 // issueCount is already the total across all pages, so overwriting here is idempotent.
-                total_count = issue_count as usize;
+                reviewed.total = issue_count as usize;
+            }
+            let nodes = response["data"]["search"]["nodes"].as_array();
+            for node in nodes.into_iter().flatten() {
+                if let Some(repo) = node["repository"]["nameWithOwner"].as_str() {
+                    *reviewed.by_repo.entry(repo.to_string()).or_default() += 1;
+                }
+                if let Some(secs) = review_turnaround_secs(node) {
+                    reviewed.turnaround_secs.push(secs);
+                }
             }
 
             has_next_page = response["data"]["search"]["pageInfo"]["hasNextPage"]
@@ -283,20 +770,255 @@ impl CommandClient {
                 .map(|s| s.to_string());
         }
 
-        Ok(total_count)
+        Ok(reviewed)
+    }
+}
+
+/// Bytes of `gh` output quoted in a parse error; enough to spot an HTML error page or a proxy
+/// banner without dumping a whole response.
+const RESPONSE_SNIPPET_LEN: usize = 200;
+
+impl GithubClient for CommandClient {
+    fn fetch_prs_by(&self, login: &str, month: &str, date_field: DateField) -> Result<PrSearch> {
+        CommandClient::fetch_prs_by(self, login, month, date_field)
+    }
+
+    fn fetch_reviewed_prs_by(
+        &self,
+        login: &str,
+        month: &str,
+        date_field: DateField,
+    ) -> Result<ReviewedPrs> {
+        CommandClient::fetch_reviewed_prs_by(self, login, month, date_field)
+    }
+
+    fn fetch_reviewed_pr_details_by(
+        &self,
+        login: &str,
+        month: &str,
+        date_field: DateField,
+    ) -> Result<PrSearch> {
+        CommandClient::fetch_reviewed_pr_details_by(self, login, month, date_field)
+    }
+}
+
+/// Deserialize `gh` output straight from its bytes, so invalid UTF-8 in a title fails loudly
+/// instead of being cached as U+FFFD.
+fn parse_response<T: serde::de::DeserializeOwned>(stdout: &[u8]) -> Result<T> {
+    serde_json::from_slice(stdout).map_err(|e| {
+        let snippet = String::from_utf8_lossy(&stdout[..stdout.len().min(RESPONSE_SNIPPET_LEN)]);
+        let ellipsis = if stdout.len() > RESPONSE_SNIPPET_LEN {
+            "…"
+        } else {
+            ""
+        };
+        Error::GraphQl(format!(
+            "unexpected response: {} (output starts: {:?}{})",
+            e,
+            snippet.trim_end(),
+            ellipsis
+        ))
+    })
+}
+
+/// Seconds between a reviewed PR's `createdAt` and the first review in its `reviews` nodes, or
+/// `None` when either timestamp is missing. Draft time counts too: GitHub's search nodes do not
+/// say when a PR was marked ready.
+fn review_turnaround_secs(node: &serde_json::Value) -> Option<i64> {
+    let created: DateTime<Utc> = node["createdAt"].as_str()?.parse().ok()?;
+    let reviewed: DateTime<Utc> = node["reviews"]["nodes"][0]["submittedAt"]
+        .as_str()?
+        .parse()
+        .ok()?;
+    Some((reviewed - created).num_seconds().max(0))
+}
+
+/// Concatenate search result pages, keeping the first copy of each PR by repository and number.
+///
+/// Search results can shift while we page through them (a PR updated mid-fetch may appear on two
+/// pages), so duplicates would otherwise inflate every count. Returns the PRs and how many copies
+/// were dropped.
+fn merge_pages(pages: Vec<Vec<PullRequest>>) -> (Vec<PullRequest>, usize) {
+    let mut seen = std::collections::HashSet::new();
+    let mut merged = Vec::new();
+    let mut duplicates = 0;
+    for pr in pages.into_iter().flatten() {
+        if seen.insert((pr.repository.name_with_owner.clone(), pr.number)) {
+            merged.push(pr);
+        } else {
+            duplicates += 1;
+        }
+    }
+    (merged, duplicates)
+}
+
+/// First `pageInfo` object in a response, depth first, as `(hasNextPage, endCursor)`.
+fn find_page_info(value: &serde_json::Value) -> Option<(bool, Option<String>)> {
+    match value {
+        serde_json::Value::Object(map) => {
+            if let Some(page_info) = map.get("pageInfo") {
+                let has_next_page = page_info["hasNextPage"].as_bool().unwrap_or(false);
+                let end_cursor = page_info["endCursor"].as_str().map(str::to_string);
+                return Some((has_next_page, end_cursor));
+            }
+            map.values().find_map(find_page_info)
+        }
+        serde_json::Value::Array(items) => items.iter().find_map(find_page_info),
+        _ => None,
     }
 }
 
-fn check_gh_installed() -> anyhow::Result<()> {
-    match Command::new("gh").arg("--version").output() {
+fn check_gh_installed(gh: &Path) -> Result<()> {
+    match Command::new(gh).arg("--version").output() {
         Ok(output) if output.status.success() => Ok(()),
-        Ok(_) => bail!(
-            "GitHub CLI (gh) is installed but not working correctly.\nRun 'gh auth login' to authenticate."
-        ),
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
-            bail!("GitHub CLI (gh) is not installed.\nInstall it from: https://cli.github.com/")
+        Ok(_) => Err(Error::GhNotAuthenticated),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(Error::GhNotInstalled),
+        Err(e) => Err(Error::io("Failed to check for GitHub CLI", e)),
+    }
+}
+
+/// Token scopes gh-log needs: `repo` lets the search see PRs in private repositories.
+pub const REQUIRED_TOKEN_SCOPES: &[&str] = &["repo"];
+
+/// The GraphQL rate limit as reported by `gh api rate_limit`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// Points available per hour.
+    pub limit: u64,
+    /// Points left until `reset`.
+    pub remaining: u64,
+    /// When the budget refills.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub reset: DateTime<Utc>,
+}
+
+impl RateLimit {
+    /// True when less than a tenth of the hourly budget is left, where a large month or a
+    /// `prefetch` can run out mid-way and fail with a GraphQL error.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use gh_log::github::RateLimit;
+    /// # use chrono::Utc;
+    /// let limit = RateLimit { limit: 5000, remaining: 120, reset: Utc::now() };
+    /// assert!(limit.is_low());
+    /// ```
+    pub fn is_low(&self) -> bool {
+        self.remaining < self.limit / 10
+    }
+}
+
+#[derive(Deserialize)]
+struct RateLimitResponse {
+    resources: RateLimitResources,
+}
+
+#[derive(Deserialize)]
+struct RateLimitResources {
+    graphql: RateLimit,
+}
+
+/// Current GraphQL rate limit via `gh api rate_limit`; `None` when gh fails or the reply has no
+/// GraphQL entry. Checking it does not count against the limit.
+pub fn graphql_rate_limit(gh: &Path) -> Option<RateLimit> {
+    let output = Command::new(gh).args(["api", "rate_limit"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_rate_limit(&output.stdout)
+}
+
+fn parse_rate_limit(json: &[u8]) -> Option<RateLimit> {
+    serde_json::from_slice::<RateLimitResponse>(json)
+        .ok()
+        .map(|response| response.resources.graphql)
+}
+
+/// OAuth scopes of the active token, read from `gh auth status`; `None` when gh prints no scope
+/// line, e.g. when logged out or for fine-grained tokens, which have no scopes.
+pub fn token_scopes(gh: &Path) -> Option<Vec<String>> {
+    let output = Command::new(gh).args(["auth", "status"]).output().ok()?;
+    // Older gh versions print the status on stderr.
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    parse_token_scopes(&text)
+}
+
+fn parse_token_scopes(status: &str) -> Option<Vec<String>> {
+    let (_, scopes) = status
+        .lines()
+        .find_map(|line| line.split_once("Token scopes:"))?;
+    let scopes = scopes.trim();
+    if scopes.is_empty() || scopes == "none" {
+        return Some(Vec::new());
+    }
+    Some(
+        scopes
+            .split(',')
+            .map(|scope| scope.trim().trim_matches('\'').to_string())
+            .collect(),
+    )
+}
+
+/// A [`GithubClient`] that answers every query with the same canned data.
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    pub struct MockClient {
+        pub prs: Vec<PullRequest>,
+        pub reviewed: ReviewedPrs,
+        /// Every query made, as `method login month`, in call order.
+        pub calls: RefCell<Vec<String>>,
+    }
+
+    impl GithubClient for MockClient {
+        fn fetch_prs_by(
+            &self,
+            login: &str,
+            month: &str,
+            _date_field: DateField,
+        ) -> Result<PrSearch> {
+            self.calls
+                .borrow_mut()
+                .push(format!("fetch_prs_by {} {}", login, month));
+            Ok(PrSearch {
+                prs: self.prs.clone(),
+                has_next_page: false,
+            })
+        }
+
+        fn fetch_reviewed_prs_by(
+            &self,
+            login: &str,
+            month: &str,
+            _date_field: DateField,
+        ) -> Result<ReviewedPrs> {
+            self.calls
+                .borrow_mut()
+                .push(format!("fetch_reviewed_prs_by {} {}", login, month));
+            Ok(self.reviewed.clone())
+        }
+
+        fn fetch_reviewed_pr_details_by(
+            &self,
+            login: &str,
+            month: &str,
+            _date_field: DateField,
+        ) -> Result<PrSearch> {
+            self.calls
+                .borrow_mut()
+                .push(format!("fetch_reviewed_pr_details_by {} {}", login, month));
+            Ok(PrSearch {
+                prs: self.prs.clone(),
+                has_next_page: false,
+            })
         }
-        Err(e) => bail!("Failed to check for GitHub CLI: {}", e),
     }
 }
 
@@ -365,13 +1087,20 @@ pub mod prop_strategies {
                         number,
                         title,
                         body: None,
+                        author: None,
                         repository,
                         created_at,
                         updated_at,
+                        merged_at: Some(updated_at),
+                        state: Some(PrState::Merged),
                         additions,
                         deletions,
                         changed_files,
-                        reviews: Reviews { nodes: Vec::new() },
+                        reviews: Reviews {
+                            nodes: Vec::new(),
+                            total_count: None,
+                        },
+                        labels: vec![],
                     }
                 },
             )
@@ -390,6 +1119,155 @@ mod tests {
     use super::*;
     use proptest::prelude::*;
 
+    #[test]
+    fn test_merge_pages_drops_overlapping_prs() {
+        let created = chrono::TimeZone::with_ymd_and_hms(&Utc, 2025, 1, 15, 10, 0, 0).unwrap();
+        let pr = |repo: &str, number: u32| PullRequest {
+            number,
+            title: format!("PR {}", number),
+            body: None,
+            author: None,
+            repository: Repository {
+                name_with_owner: repo.to_string(),
+            },
+            created_at: created,
+            updated_at: created,
+            merged_at: None,
+            state: None,
+            additions: 1,
+            deletions: 1,
+            changed_files: 1,
+            reviews: Reviews {
+                nodes: vec![],
+                total_count: None,
+            },
+            labels: vec![],
+        };
+        let first = vec![pr("owner/a", 1), pr("owner/a", 2)];
+        let second = vec![pr("owner/a", 2), pr("owner/b", 2), pr("owner/a", 3)];
+
+        let (merged, duplicates) = merge_pages(vec![first, second]);
+        let keys: Vec<(&str, u32)> = merged
+            .iter()
+            .map(|pr| (pr.repository.name_with_owner.as_str(), pr.number))
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                ("owner/a", 1),
+                ("owner/a", 2),
+                ("owner/b", 2),
+                ("owner/a", 3)
+            ]
+        );
+        assert_eq!(duplicates, 1);
+    }
+
+    #[test]
+    fn test_parse_rate_limit_reads_graphql_resource() {
+        let json = br#"{"resources":{"core":{"limit":5000,"remaining":4999,"reset":1767225600},
+            "graphql":{"limit":5000,"used":4900,"remaining":100,"reset":1767225600}}}"#;
+        let limit = parse_rate_limit(json).unwrap();
+        assert_eq!((limit.limit, limit.remaining), (5000, 100));
+        assert_eq!(limit.reset.to_rfc3339(), "2026-01-01T00:00:00+00:00");
+        assert!(limit.is_low());
+        assert_eq!(parse_rate_limit(b"{}"), None);
+    }
+
+    #[test]
+    fn test_parse_token_scopes_from_auth_status() {
+        let current = "github.com\n  ✓ Logged in to github.com account octocat (keyring)\n  \
+                       - Token: gho_****\n  - Token scopes: 'gist', 'read:org', 'repo'\n";
+        assert_eq!(
+            parse_token_scopes(current).unwrap(),
+            ["gist", "read:org", "repo"]
+        );
+        let legacy = "github.com\n  ✓ Token scopes: repo, workflow\n";
+        assert_eq!(parse_token_scopes(legacy).unwrap(), ["repo", "workflow"]);
+        assert_eq!(
+            parse_token_scopes("  - Token scopes: none\n"),
+            Some(Vec::new())
+        );
+        assert_eq!(
+            parse_token_scopes("You are not logged into any GitHub hosts."),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_page_info_searches_nested_connections() {
+        let response = serde_json::json!({
+            "data": {
+                "viewer": {
+                    "login": "octocat",
+                    "pullRequests": {
+                        "nodes": [],
+                        "pageInfo": { "hasNextPage": true, "endCursor": "Y3Vyc29y" }
+                    }
+                }
+            }
+        });
+        assert_eq!(
+            find_page_info(&response),
+            Some((true, Some("Y3Vyc29y".to_string())))
+        );
+        assert_eq!(find_page_info(&serde_json::json!({ "data": {} })), None);
+    }
+
+    #[test]
+    fn test_review_turnaround_from_first_review() {
+        let node = serde_json::json!({
+            "createdAt": "2025-01-10T09:00:00Z",
+            "repository": { "nameWithOwner": "owner/repo" },
+            "reviews": { "nodes": [{ "submittedAt": "2025-01-10T12:30:00Z" }] }
+        });
+        assert_eq!(review_turnaround_secs(&node), Some(3 * 3600 + 1800));
+
+        let unreviewed = serde_json::json!({
+            "createdAt": "2025-01-10T09:00:00Z",
+            "reviews": { "nodes": [] }
+        });
+        assert_eq!(review_turnaround_secs(&unreviewed), None);
+    }
+
+    #[test]
+    fn test_parse_response_quotes_bad_output() {
+        let value: serde_json::Value = parse_response(br#"{"title": "caf\u00e9"}"#).unwrap();
+        assert_eq!(value["title"], "café");
+
+        let err = parse_response::<serde_json::Value>(b"{\"title\": \"bad \xff byte\"}")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("unexpected response"), "{err}");
+        assert!(err.contains("bad \u{fffd} byte"), "{err}");
+
+        let html = format!("<html>{}</html>", "x".repeat(500));
+        let err = parse_response::<serde_json::Value>(html.as_bytes())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("output starts: \"<html>xxx"), "{err}");
+        assert!(err.ends_with("\"…)"), "{err}");
+    }
+
+    #[test]
+    fn test_client_shorthands_ask_for_current_user() {
+        let mock = mock::MockClient::default();
+        let client: &dyn GithubClient = &mock;
+
+        client.fetch_prs("2025-01", DateField::Created).unwrap();
+        client
+            .fetch_reviewed_prs("2025-01", DateField::Merged)
+            .unwrap();
+
+        assert_eq!(
+            *mock.calls.borrow(),
+            [
+                "fetch_prs_by @me 2025-01",
+                "fetch_reviewed_prs_by @me 2025-01"
+            ]
+        );
+    }
+
     proptest! {
         #[test]
         fn test_pull_request_dates_are_valid(pr in prop_strategies::pull_request_strategy()) {
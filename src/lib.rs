@@ -0,0 +1,14 @@
+//! gh-log library crate.
+//!
+//! Exposes the fetch, cache, configuration, aggregation, and rendering layers behind the `gh-log`
+//! binary so they can be reused and tested independently of the CLI. Fallible operations in
+//! `github`, `cache`, and `config` return [`Error`] so callers can match on the failure kind.
+
+pub mod cache;
+pub mod config;
+pub mod data;
+pub mod error;
+pub mod github;
+pub mod view;
+
+pub use error::{Error, Result};
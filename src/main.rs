@@ -15,6 +15,9 @@
 //! # Primary commands
 //! - `view`: Launch an interactive dashboard with weekly summaries, repo stats, and sortable PR lists.
 //! - `print`: Export data as text, JSON, or CSV so you can feed it to an LLM or drop it into a doc.
+//! - `digest`: Summarize the month in a few sentences for a status email.
+//! - `prefetch`: Warm the cache for a range of months before going offline.
+//! - `version`: Print the version; `--verbose` adds commit, build date, and rustc for bug reports.
 //! - `doctor`: Verify your GitHub CLI setup and reveal cache/config locations.
 //! - `config`: Open or scaffold the configuration file used to tune filters and size thresholds.
 //! - `completions`: Generate tab-completion scripts for popular shells.
@@ -30,30 +33,65 @@
 //! repeated queries fast; pass `--force` to refresh. For installation instructions and screenshots,
 //! see the project README.
 //!
-mod cache;
-mod config;
-mod data;
-mod github;
-mod view;
-
-use anyhow::bail;
-use clap::{CommandFactory, Parser, Subcommand};
+use anyhow::Context;
+use clap::{Args, CommandFactory, Parser, Subcommand};
 use clap_complete::{Shell, generate};
-use std::io;
-use std::process::Command;
+use gh_log::config::{PrintFormat, Timezone};
+use gh_log::data::{GroupBy, PrOrder, PrSort, RepoOrder, RepoSort, SortOrder};
+use gh_log::error::exit_code;
+use gh_log::github::{DateField, GithubClient, StateFilter};
+use gh_log::view::ColorChoice;
+use gh_log::{cache, config, data, github, view};
+use serde::Serialize;
+use std::cell::OnceCell;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
 
 fn view_help() -> &'static str {
     "Navigate PRs with an interactive terminal UI.
 
 Discussion:
-    Launch an interactive TUI to browse your PRs. The interface has three
+    Launch an interactive TUI to browse your PRs. The interface has five
     views that you can toggle between:
 
     - Summary (s): Weekly and repo statistics
     - Detail (d): Detailed list, cycle between grouped by week or by repo
     - Tail (t): All PRs sorted by lead time (longest first)
-
-    Use arrow keys or j/k to scroll, q or Esc to quit.
+    - Reviewers (r): Each reviewer; Enter on one shows the PRs they
+      reviewed
+    - Trend (T): Total PRs and average lead time of the last --months
+      cached months (6 by default) as bar charts
+
+    Use arrow keys or j/k to scroll, Ctrl-D/Ctrl-U for half a page,
+    PageDown/PageUp (or Ctrl-F/Ctrl-B) for a full page, and Home/End (or
+    g/G) for the top and bottom; the mouse wheel scrolls too. q or Esc
+    quits. Press w to wrap long PR titles onto extra lines instead of
+    truncating them. Press [ and ] (or < and >) to step to the previous or
+    next month; months past the current one are skipped.
+
+    In the Detail, Tail and Reviewers lists, arrow keys or j/k move a cursor
+    from PR to PR; press Enter to open the highlighted PR on GitHub in your
+    browser, or to expand or collapse the highlighted reviewer. PR numbers
+    are also links you can click in terminals that support them
+    (--no-hyperlinks shows plain numbers).
+    Press / and type to show only PRs whose title or repo contains the text
+    (any case); Enter keeps the search, Esc clears it.
+
+    On small screens, press z (or start with --compact) to fold the controls
+    and header onto one line each, leaving more rows for PRs.
+
+    By default a PR belongs to the month it was created in. Use --by merged
+    to count what you shipped instead: PRs merged that month, grouped into
+    weeks by merge date.
+
+    Pass --reviewed to browse the PRs you reviewed rather than the ones
+    you authored. --author LOGIN browses someone else's PRs instead, and
+    --org ORG keeps only PRs in that organization's repositories.
+
+    PRs authored by bots (Dependabot, Renovate, any login ending in [bot],
+    or matching [filter] bot_patterns) and reviews left by bots are
+    excluded by default. Pass --include-bots to keep them.
 
     Data is cached after the first fetch. Use --force to bypass cache and
     fetch fresh data from GitHub.
@@ -65,8 +103,20 @@ Examples:
     # View a specific month
     gh-log view --month 2025-12
 
+    # Just one week (a day or day range within one month)
+    gh-log view --month 2025-12-01..2025-12-07
+
+    # PRs merged this month instead of created
+    gh-log view --by merged
+
     # Force fresh data (bypass cache)
-    gh-log view --force"
+    gh-log view --force
+
+    # Dense layout for small terminals
+    gh-log view --compact
+
+    # Chart the last year of cached months in the trend view
+    gh-log view --months 12"
 }
 
 fn print_help() -> &'static str {
@@ -78,10 +128,46 @@ Discussion:
     - Default: Human-readable text with PR descriptions
     - --json: Structured data for LLMs, scripts, or further processing
     - --csv: Spreadsheet-compatible format
+    - --markdown: GitHub-flavored Markdown tables for review docs
+    - --html: A self-contained web page to share, styled inline
 
     This is particularly useful for performance reviews - pipe the output
     to your clipboard, feed it to an LLM, or export to a spreadsheet.
 
+    PRs are placed in the month by their creation date, so a PR opened
+    last month and merged this month is not in this month's report. Use
+    --by merged to report PRs merged in the month rather than created, or
+    --also-merged to list both: each PR is then marked [created], [merged]
+    or [created+merged] (\"matched_by\" in JSON) to show why it is there.
+    Use --no-reviewed to skip the second GitHub query (PRs you reviewed)
+    when you only need your own PRs; the review section is then hidden.
+    Use --reviewed to run the same report over the PRs you reviewed
+    instead: their repos, sizes and lead times.
+
+    Bot-authored PRs and bot reviews are excluded by default; pass
+    --include-bots to keep them.
+
+    --exclude-repo, --ignore-repo, --exclude-pattern, --ignore-pattern and
+    --label add to the config's [filter] lists for one run, without editing
+    it. --label keeps only PRs carrying one of the GitHub labels named.
+
+    Use --author LOGIN to report on someone else, such as a direct report,
+    and --org ORG to keep only PRs in one organization's repositories.
+    Both work with --reviewed, and each combination is cached separately.
+
+    Use --author-file to report on a team: list one GitHub login per line
+    (blank lines and # comments are skipped). Each person's month is fetched
+    and cached separately, then combined into one report that opens with an
+    Authors table of PRs, average lead time and review balance per person.
+
+    Use --all-cached to roll up every month already in the cache (say,
+    after gh-log prefetch) into one report, with a section per month
+    instead of per week. It never calls GitHub.
+
+    Use --quarter 2025-Q1 or --year 2025 to report a longer period in 7-day
+    buckets; --year adds a Months section (\"months\" in JSON) with each
+    month's totals. Each period is fetched and cached on its own.
+
     Data is cached after the first fetch. Use --force to bypass cache.
 
 Examples:
@@ -95,8 +181,29 @@ Examples:
     # Export to spreadsheet
     gh-log print --csv > prs-2025-01.csv
 
+    # Paste-ready Markdown for a review doc
+    gh-log print --markdown > review-2025-01.md
+
+    # Shareable page that opens in any browser
+    gh-log print --html > report-2025-01.html
+
     # Specific month with fresh data
-    gh-log print --month 2024-12 --force --json"
+    gh-log print --month 2024-12 --force --json
+
+    # Quantify your review load
+    gh-log print --reviewed --json
+
+    # A year in review, month by month
+    gh-log print --year 2025
+
+    # Full details of specific PRs
+    gh-log print --pr 123,456
+
+    # One report for everyone in team.txt
+    gh-log print --author-file team.txt --json
+
+    # A direct report's month, limited to your organization
+    gh-log print --author octocat --org acme"
 }
 
 fn config_help() -> &'static str {
@@ -107,9 +214,15 @@ Discussion:
     If the file doesn't exist, a template will be created.
 
     Configuration allows you to:
+    - Include only some repos/PRs (everything else is hidden)
     - Exclude repos/PRs completely (won't be shown)
     - Ignore repos/PRs (shown but not counted in metrics)
+    - Recognize extra bot logins (bots are excluded unless --include-bots)
+    - Keep only merged, open, or closed PRs (same as --state)
     - Customize PR size thresholds (S/M/L/XL)
+    - Merge alias logins into one reviewer (first login in a group wins)
+    - Choose what Review Balance divides by (created, merged, or reviewed PRs)
+    - Set targets, e.g. a maximum share of XL PRs, that trigger warnings
 
     Patterns use regex syntax and are applied to PR titles.
 
@@ -122,15 +235,73 @@ Config location:
 
 Example configuration:
     [filter]
+    include_repos = [\"username/api\", \"username/web\"]
+    include_patterns = [\"^feat:\", \"^fix:\"]
+    include_labels = [\"bug\", \"feature\"]
     exclude_repos = [\"username/spam-repo\"]
     exclude_patterns = [\"^test:\", \"^tmp:\"]
     ignore_repos = [\"username/personal-notes\"]
     ignore_patterns = [\"^docs:\", \"^meeting:\"]
+    bot_patterns = [\"^ci-\"]
+    include_bots = false
+    state = \"merged\"
 
     [size]
     small = 50
     medium = 200
     large = 500
+    files_large = 15
+    files_xl = 25
+
+    # Per-repo line thresholds; unset ones use [size]
+    [size.overrides.\"username/generated\"]
+    large = 5000
+
+    [identity]
+    aliases = [[\"alice\", \"alice-old\"]]
+
+    [analytics]
+    # Review Balance denominator: created, merged, or reviewed_eligible
+    review_balance_base = \"created\"
+    # Lead-time histogram edges in hours: <1h, 1h-4h, 4h-1d, 1d-3d, 3d+
+    lead_time_buckets = [1, 4, 24, 72]
+
+    [targets]
+    # Warn when more than 20% of the month's PRs are XL
+    max_xl_ratio = 0.2
+
+    [review]
+    # Review Balance shows red below this ratio, green at or above it
+    target_ratio = 1.0
+
+    [gh]
+    # Full path to gh when it is not on PATH (GH_LOG_GH_PATH overrides)
+    binary = \"/opt/homebrew/bin/gh\"
+
+    [display]
+    # Link issue keys in titles; the first capture group is the id
+    issue_pattern = '\\[([A-Z]+-\\d+)\\]'
+    issue_url_template = \"https://jira.example.com/browse/{id}\"
+    # Tag on ignored PRs in the view lists (they are dimmed either way)
+    ignored_marker = \"(ignored)\"
+    # Dates and weeks in this zone: local (default), utc, or an offset (--tz wins)
+    timezone = \"+02:00\"
+
+    [theme]
+    # view colors by role, e.g. for a light terminal: a name (lightblue,
+    # darkgray), an index 0-255, or \"#rrggbb\". Roles: size_s, size_m, size_l,
+    # size_xl, size_unknown, accent, highlight, count, header, muted
+    header = \"black\"
+    highlight = \"#b58900\"
+
+    [defaults]
+    # Written by view/print --save-config; a flag given on the command line still wins
+    by = \"merged\"
+    group_by = \"repo\"
+    sort_repos = \"lead-time\"
+    sort_order = \"asc\"
+    format = \"json\"   # print output: text, json, csv, table, markdown, html
+    month_offset = 1   # Set by hand: report last month when --month is not given
 
 Common regex patterns:
     ^prefix:     Match titles starting with \"prefix:\"
@@ -142,7 +313,9 @@ fn completions_help() -> &'static str {
     "Generate tab-completion scripts for your shell.
 
 The script is output on `stdout`, allowing you to redirect the output to
-the file of your choosing. Where you place the file will depend on which
+the file of your choosing. Pass --install to write it to the usual per-user
+location for bash, zsh or fish instead (use --dir to pick another
+directory); other shells fall back to printing the script. Where you place the file will depend on which
 shell, and which operating system you are using. Your particular
 configuration may also determine where these scripts need to be placed.
 
@@ -239,6 +412,102 @@ to add the proper directives, such as `source`ing inside your login
 script. Consult your shell's documentation for how to add such directives."
 }
 
+fn digest_help() -> &'static str {
+    "Summarize the month in a few sentences of plain prose.
+
+Discussion:
+    Prints how many PRs you opened and across how many repos, the average
+    lead time, your biggest PR by lines changed, and how many PRs you
+    reviewed - the shape of a weekly or monthly status update. Config
+    filters apply as in `print`.
+
+Examples:
+    # Paste into this week's status email
+    gh-log digest | pbcopy
+
+    # Last month, from the cache only
+    gh-log digest --month 2025-12 --offline"
+}
+
+fn compare_help() -> &'static str {
+    "Compare two months side by side.
+
+Discussion:
+    Builds the report for each month, from the cache when it is there, and
+    prints total PRs, average lead time, frequency, the size distribution
+    and the reviewed count for both, with the change from the first month
+    to the second in absolute terms and in percent. Config filters apply as
+    in `print`. The percentage is n/a when the first month's value is 0.
+
+Examples:
+    # Did this month go better than last month?
+    gh-log compare 2025-01 2025-02
+
+    # Same comparison for a script or an LLM
+    gh-log compare 2025-01 2025-02 --json --offline"
+}
+
+fn prefetch_help() -> &'static str {
+    "Download several months into the cache ahead of time.
+
+Discussion:
+    Fetches each month in the range and writes it to the cache, so `view`
+    and `print` load instantly later, even without a network connection.
+    Months that are already cached and fresh are skipped. A month that
+    fails to fetch is reported and the rest of the range still runs; the
+    command exits non-zero if any month failed.
+
+    Pick the range with --from/--to, or --months N for the N months ending
+    at --to (the current month by default). Without either, the last 3
+    months are fetched.
+
+    Months are fetched two at a time; --jobs sets how many run at once
+    (1 to 8). Higher values finish long ranges sooner but are more likely
+    to hit GitHub's rate limits.
+
+Examples:
+    # Last 3 months
+    gh-log prefetch
+
+    # The whole of 2025
+    gh-log prefetch --from 2025-01 --to 2025-12
+
+    # Last 6 months, grouped by merge date
+    gh-log prefetch --months 6 --by merged
+
+    # A long range, four months at a time
+    gh-log prefetch --from 2024-01 --to 2025-12 --jobs 4"
+}
+
+fn raw_help() -> &'static str {
+    "Run your own GraphQL query through gh and print the JSON response.
+
+Discussion:
+    An escape hatch for fields gh-log does not expose, such as mergeable or
+    reviewDecision. The query runs through `gh api graphql` with the same
+    GitHub CLI that `view` and `print` use, and nothing is cached.
+
+    Declare a `$endCursor: String` variable and pass it as `after:` to page
+    through a connection: gh-log follows the first pageInfo in each
+    response until hasNextPage is false. Each page is printed as one line
+    of JSON, so the output can be piped straight into jq.
+
+Examples:
+    # Who am I?
+    gh-log raw '{ viewer { login } }'
+
+    # Review decisions for all your open PRs
+    gh-log raw --query-file open-prs.graphql | jq '.data.search.nodes[]'
+
+    # open-prs.graphql:
+    query($endCursor: String) {
+      search(query: \"is:pr is:open author:@me\", type: ISSUE, first: 100, after: $endCursor) {
+        pageInfo { hasNextPage endCursor }
+        nodes { ... on PullRequest { url reviewDecision mergeable } }
+      }
+    }"
+}
+
 fn doctor_help() -> &'static str {
     "Verify system setup and show diagnostic information.
 
@@ -246,14 +515,27 @@ Discussion:
     Runs a series of checks to verify that gh-log is properly configured
     and can communicate with GitHub.
 
+    Starts with the gh-log build (version, commit, build date and rustc),
+    the same details `gh-log version --verbose` prints for bug reports.
+
     Checks performed:
-    - GitHub CLI (gh) installation and version
+    - GitHub CLI (gh) installation, version and resolved path
+      (GH_LOG_GH_PATH, then [gh] binary in the config, then gh on PATH)
     - GitHub authentication status
+    - Token scopes (from gh auth status), warning when `repo` is missing
+    - Remaining GraphQL rate limit (from gh api rate_limit), warning when
+      less than a tenth is left
+    - Configuration file validity: regex patterns, ascending size
+      thresholds and other values, with the specific error if one fails
 
     Also displays the locations of:
-    - Cache directory (where PR data is stored)
+    - Cache directory (where PR data is stored), with each cached
+      month's last update and size on disk
     - Configuration file (if it exists)
 
+    Use --by-size to list the largest cache files first, or --json to get
+    the same report as structured data.
+
     Use this command to troubleshoot issues or find where your data is stored.
 
 Common issues:
@@ -263,10 +545,23 @@ Common issues:
     'not authenticated'
     → Run: gh auth login
 
+    'GraphQL query failed'
+    → Check the rate limit and token scopes above; a missing scope is
+      added with: gh auth refresh -s repo
+
     Stale data showing
     → Use --force flag with view/print commands to refresh"
 }
 
+const EXIT_CODES_HELP: &str = "Exit codes:
+  0   Success (including months with no PRs)
+  1   Other failure (I/O, cache, terminal)
+  2   GitHub CLI (gh) missing or not authenticated
+  3   GitHub API (GraphQL) request failed
+  4   Invalid config file
+  5   No PRs found (print --fail-on-empty)
+  64  Invalid command-line arguments";
+
 #[derive(Parser)]
 #[command(name = "gh-log")]
 #[command(about = "GitHub PR analytics for your terminal")]
@@ -274,16 +569,99 @@ Common issues:
     long_about = "Pull your GitHub PR data in seconds. View interactively or export to JSON/CSV.\n\nRequires: GitHub CLI (gh) installed and authenticated\nCaching: Speeds up repeated queries. Current month cached 6h, last month 24h, older months permanent.\n         Use --force flag to refresh cached data.\n\nExamples:\n  gh-log view                    # Interactive TUI for current month\n  gh-log print --json | claude   # Feed to LLM for performance review\n  gh-log doctor                  # Check setup"
 )]
 #[command(version)]
+#[command(after_long_help = EXIT_CODES_HELP)]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// When to color the print output
+    #[arg(long, global = true, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+
+    /// Zone for PR dates and week boundaries: local, utc, or an offset like +02:00; --save-config stores it as display.timezone [default: display.timezone, else local]
+    #[arg(long, global = true, value_name = "ZONE")]
+    tz: Option<Timezone>,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 enum OutputFormat {
-    Raw,
+    /// Plain text, with the PR listing sectioned by `group_by`, PR bodies cut to
+    /// `--max-body-lines`, and PR numbers linked unless `--no-hyperlinks`.
+    Raw {
+        group_by: GroupBy,
+        max_body_lines: Option<usize>,
+        hyperlinks: bool,
+    },
     Json,
     Csv,
+    /// GitHub-flavored Markdown for review docs.
+    Markdown,
+    /// Self-contained HTML page with inline CSS.
+    Html,
+    /// Bordered PR table, optionally at a fixed width (`--format table --width N`).
+    Table(Option<usize>),
+    /// JSON piped through a user command (`--postprocess`).
+    Postprocess(String),
+}
+
+/// Whose PRs `view` and `print` report on, when not just your own.
+#[derive(Args, Debug, Default)]
+struct ScopeArgs {
+    #[arg(
+        long,
+        value_name = "LOGIN",
+        value_parser = parser_login,
+        help = "Report on another GitHub user's PRs (with --reviewed, the PRs they reviewed)"
+    )]
+    author: Option<String>,
+    #[arg(
+        long,
+        value_name = "ORG",
+        value_parser = parser_login,
+        help = "Only PRs in repositories owned by this organization (or user)"
+    )]
+    org: Option<String>,
+}
+
+/// One-off additions to the config's `[filter]` lists for `view` and `print`.
+#[derive(Args, Debug, Default)]
+struct FilterArgs {
+    #[arg(
+        long,
+        value_name = "OWNER/NAME",
+        help = "Hide a repository for this run, on top of filter.exclude_repos (repeatable)"
+    )]
+    exclude_repo: Vec<String>,
+    #[arg(
+        long,
+        value_name = "OWNER/NAME",
+        help = "Show but don't count a repository for this run, on top of filter.ignore_repos (repeatable)"
+    )]
+    ignore_repo: Vec<String>,
+    #[arg(
+        long,
+        value_name = "REGEX",
+        help = "Hide PRs whose title matches, on top of filter.exclude_patterns (repeatable)"
+    )]
+    exclude_pattern: Vec<String>,
+    #[arg(
+        long,
+        value_name = "REGEX",
+        help = "Show but don't count PRs whose title matches, on top of filter.ignore_patterns (repeatable)"
+    )]
+    ignore_pattern: Vec<String>,
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Only PRs with this GitHub label, on top of filter.include_labels (repeatable)"
+    )]
+    label: Vec<String>,
+    #[arg(
+        long,
+        value_enum,
+        help = "Only report PRs that are merged, open, or closed without merging [default: all]"
+    )]
+    state: Option<StateFilter>,
 }
 
 #[derive(Subcommand)]
@@ -295,12 +673,93 @@ enum Commands {
         #[arg(
             long,
             value_name = "YYYY-MM",
-            help = "Month in format YYYY-MM, e.g. 2025-11 (defaults to current month)",
+            help = "Month as YYYY-MM (defaults to current month, or defaults.month_offset months back), or a day or day range within one month: 2025-11-03, 2025-11-03..2025-11-09",
             value_parser = parser_month
         )]
         month: Option<String>,
+        #[arg(
+            long,
+            value_name = "START..END",
+            conflicts_with_all = ["month"],
+            value_parser = parser_range,
+            help = "Report any date range, e.g. a sprint, in 7-day buckets: 2025-01-06..2025-03-30"
+        )]
+        range: Option<String>,
         #[arg(long, help = "Force refresh data from GitHub API, bypassing cache")]
         force: bool,
+        #[arg(
+            long,
+            conflicts_with = "force",
+            help = "Use cached data even when stale and never call GitHub"
+        )]
+        offline: bool,
+        #[arg(
+            long,
+            value_enum,
+            help = "Place PRs in the month by creation or merge date [default: created]"
+        )]
+        by: Option<DateField>,
+        #[arg(
+            long,
+            help = "Keep PRs and reviews from bot accounts (excluded by default)"
+        )]
+        include_bots: bool,
+        #[command(flatten)]
+        filters: FilterArgs,
+        #[command(flatten)]
+        scope: ScopeArgs,
+        #[arg(
+            long,
+            help = "Skip the reviewed-PR query for a faster run (hides Review Balance)"
+        )]
+        no_reviewed: bool,
+        #[arg(
+            long,
+            help = "Analyze the PRs you reviewed instead of the ones you authored"
+        )]
+        reviewed: bool,
+        #[arg(
+            long,
+            value_enum,
+            help = "Order the repository table by PR count, name, or average lead time [default: count]"
+        )]
+        sort_repos: Option<RepoSort>,
+        #[arg(
+            long,
+            value_enum,
+            help = "Override the sort direction (count and lead-time default to desc, name to asc)"
+        )]
+        sort_order: Option<SortOrder>,
+        #[arg(
+            long,
+            help = "Start with single-line controls and header for small screens (toggle with z)"
+        )]
+        compact: bool,
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::value_parser!(u32).range(1..),
+            help = "Stop fetching after N PRs for a quick look; the report is marked partial and not cached"
+        )]
+        limit: Option<u32>,
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = 6,
+            value_parser = clap::value_parser!(u32).range(1..=36),
+            help = "How many of the newest cached months the trend view (T) charts"
+        )]
+        months: u32,
+        #[arg(
+            long,
+            help = "Show PR numbers as plain text instead of links that open the PR on click"
+        )]
+        no_hyperlinks: bool,
+        #[arg(
+            long,
+            help = "Save this run's --by, --sort-repos, --sort-order, --state and --tz as defaults in config.toml"
+        )]
+        save_config: bool,
     },
     /// Print PRs as text/json/csv - pipe to LLMs, clipboard, or files
     #[command(long_about = print_help())]
@@ -309,16 +768,310 @@ enum Commands {
         #[arg(
             long,
             value_name = "YYYY-MM",
-            help = "Month in format YYYY-MM, e.g. 2025-11 (defaults to current month)",
+            help = "Month as YYYY-MM (defaults to current month, or defaults.month_offset months back), or a day or day range within one month: 2025-11-03, 2025-11-03..2025-11-09",
             value_parser = parser_month
         )]
         month: Option<String>,
+        #[arg(
+            long,
+            value_name = "N",
+            conflicts_with = "month",
+            value_parser = clap::value_parser!(u32).range(1..=52),
+            help = "Report the last N weeks ending today, in 7-day buckets, instead of a month"
+        )]
+        last_weeks: Option<u32>,
+        #[arg(
+            long,
+            value_name = "START..END",
+            conflicts_with_all = ["month", "last_weeks"],
+            value_parser = parser_range,
+            help = "Report any date range, e.g. a sprint, in 7-day buckets: 2025-01-06..2025-03-30"
+        )]
+        range: Option<String>,
+        #[arg(
+            long,
+            value_name = "YYYY-Qn",
+            conflicts_with_all = ["month", "last_weeks", "range"],
+            value_parser = parser_quarter,
+            help = "Report a calendar quarter in 7-day buckets, e.g. 2025-Q1"
+        )]
+        quarter: Option<String>,
+        #[arg(
+            long,
+            value_name = "YYYY",
+            conflicts_with_all = ["month", "last_weeks", "range", "quarter"],
+            value_parser = parser_year,
+            help = "Report a whole year in 7-day buckets, with a per-month breakdown, e.g. 2025"
+        )]
+        year: Option<String>,
         #[arg(long, help = "Force refresh data from GitHub API, bypassing cache")]
         force: bool,
+        #[arg(
+            long,
+            conflicts_with = "force",
+            help = "Use cached data even when stale and never call GitHub"
+        )]
+        offline: bool,
+        #[arg(
+            long,
+            value_enum,
+            help = "Place PRs in the month by creation or merge date [default: created]"
+        )]
+        by: Option<DateField>,
+        #[arg(
+            long,
+            help = "Keep PRs and reviews from bot accounts (excluded by default)"
+        )]
+        include_bots: bool,
+        #[command(flatten)]
+        filters: FilterArgs,
+        #[command(flatten)]
+        scope: ScopeArgs,
+        #[arg(
+            long,
+            help = "Skip the reviewed-PR query for a faster run (hides Review Balance)"
+        )]
+        no_reviewed: bool,
+        #[arg(
+            long,
+            help = "Analyze the PRs you reviewed instead of the ones you authored"
+        )]
+        reviewed: bool,
+        #[arg(
+            long,
+            value_enum,
+            help = "Order the repository table by PR count, name, or average lead time [default: count]"
+        )]
+        sort_repos: Option<RepoSort>,
+        #[arg(
+            long,
+            value_enum,
+            help = "Override the sort direction (count and lead-time default to desc, name to asc)"
+        )]
+        sort_order: Option<SortOrder>,
+        #[arg(
+            long,
+            value_enum,
+            conflicts_with_all = ["pr", "group_by"],
+            help = "List every PR in one flat list ordered by lead time, size, creation date, or repo instead of by week"
+        )]
+        sort: Option<PrSort>,
+        #[arg(
+            long,
+            requires = "sort",
+            help = "Reverse --sort: longest, biggest, newest or Z-A first"
+        )]
+        desc: bool,
+        #[arg(
+            long,
+            value_name = "NUMBER",
+            value_delimiter = ',',
+            conflicts_with_all = ["json", "csv", "markdown", "html", "format"],
+            help = "Show only these PRs in full, ignoring config filters, e.g. --pr 123,456"
+        )]
+        pr: Vec<u32>,
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with_all = ["author", "reviewed", "pr", "csv", "format", "postprocess"],
+            help = "Combined report for the GitHub logins listed in a file (one per line, # comments)"
+        )]
+        author_file: Option<std::path::PathBuf>,
+        #[arg(
+            long,
+            conflicts_with_all = ["by", "reviewed", "last_weeks", "range", "quarter", "year", "author_file", "pr"],
+            help = "Also include PRs opened earlier but merged in the month, marking why each PR is listed"
+        )]
+        also_merged: bool,
+        #[arg(
+            long,
+            conflicts_with_all = ["month", "last_weeks", "range", "quarter", "year", "force", "reviewed", "pr", "author", "org", "author_file", "also_merged"],
+            help = "Roll up every month already in the cache into one report, by month, without calling GitHub"
+        )]
+        all_cached: bool,
+        #[arg(
+            long,
+            help = "Exit with code 5 when no PRs remain after filters, e.g. to flag inactivity in CI"
+        )]
+        fail_on_empty: bool,
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::value_parser!(u32).range(1..),
+            conflicts_with_all = ["pr", "author_file", "also_merged", "all_cached"],
+            help = "Stop fetching after N PRs for a quick look; the report is marked partial and not cached"
+        )]
+        limit: Option<u32>,
+        #[arg(
+            long,
+            value_enum,
+            conflicts_with_all = ["json", "csv", "markdown", "html", "postprocess"],
+            help = "Section the PR listing by week, repo, size, label (title prefix like 'docs:'), or day [default: week]"
+        )]
+        group_by: Option<GroupBy>,
+        #[arg(
+            long,
+            value_name = "N",
+            conflicts_with_all = ["json", "csv", "markdown", "html", "format", "postprocess"],
+            help = "Show at most N lines of each PR description (0 hides them)"
+        )]
+        max_body_lines: Option<u32>,
         #[arg(long, help = "Output data in JSON format")]
         json: bool,
         #[arg(long, help = "Output data in CSV format")]
         csv: bool,
+        #[arg(
+            long,
+            conflicts_with_all = ["json", "csv"],
+            help = "Output a Markdown report with a PR table per week"
+        )]
+        markdown: bool,
+        #[arg(
+            long,
+            conflicts_with_all = ["json", "csv", "markdown"],
+            help = "Output a self-contained HTML page with summary cards and a PR table per week"
+        )]
+        html: bool,
+        #[arg(
+            long,
+            value_enum,
+            conflicts_with_all = ["json", "csv", "markdown", "html", "postprocess"],
+            help = "Output format; table prints a bordered PR table sized to the terminal"
+        )]
+        format: Option<PrintFormat>,
+        #[arg(
+            long,
+            value_name = "COLUMNS",
+            value_parser = clap::value_parser!(u16).range(40..),
+            help = "Width of --format table [default: terminal width, or 100 when piped]"
+        )]
+        width: Option<u16>,
+        #[arg(
+            long,
+            value_name = "COMMAND",
+            conflicts_with_all = ["csv", "markdown", "html", "pr"],
+            help = "Pipe the JSON output through a shell command and print what it writes, e.g. --postprocess 'jq .total_prs'"
+        )]
+        postprocess: Option<String>,
+        #[arg(
+            long,
+            help = "Print PR numbers as plain text instead of clickable links (links only appear with color)"
+        )]
+        no_hyperlinks: bool,
+        #[arg(
+            long,
+            help = "Save this run's --by, --group-by, --sort-repos, --sort-order, --state, --tz and output format as defaults in config.toml"
+        )]
+        save_config: bool,
+    },
+    /// One-paragraph summary of the month, ready to paste into a status email
+    #[command(long_about = digest_help())]
+    #[command(override_usage = "gh-log digest [OPTIONS]")]
+    Digest {
+        #[arg(
+            long,
+            value_name = "YYYY-MM",
+            help = "Month as YYYY-MM (defaults to current month, or defaults.month_offset months back), or a day or day range within one month: 2025-11-03, 2025-11-03..2025-11-09",
+            value_parser = parser_month
+        )]
+        month: Option<String>,
+        #[arg(long, help = "Force refresh data from GitHub API, bypassing cache")]
+        force: bool,
+        #[arg(
+            long,
+            conflicts_with = "force",
+            help = "Use cached data even when stale and never call GitHub"
+        )]
+        offline: bool,
+        #[arg(
+            long,
+            value_enum,
+            help = "Place PRs in the month by creation or merge date [default: created]"
+        )]
+        by: Option<DateField>,
+    },
+    /// Compare two months side by side: totals, lead time, frequency, sizes, reviews
+    #[command(long_about = compare_help())]
+    #[command(override_usage = "gh-log compare [OPTIONS] <A> <B>")]
+    Compare {
+        #[arg(value_name = "A", help = "Baseline month as YYYY-MM", value_parser = parser_calendar_month)]
+        a: String,
+        #[arg(value_name = "B", help = "Month to compare against A, as YYYY-MM", value_parser = parser_calendar_month)]
+        b: String,
+        #[arg(long, help = "Output as JSON")]
+        json: bool,
+        #[arg(long, help = "Force refresh data from GitHub API, bypassing cache")]
+        force: bool,
+        #[arg(
+            long,
+            conflicts_with = "force",
+            help = "Use cached data even when stale and never call GitHub"
+        )]
+        offline: bool,
+        #[arg(
+            long,
+            value_enum,
+            help = "Place PRs in the month by creation or merge date [default: created]"
+        )]
+        by: Option<DateField>,
+    },
+    /// Download several months into the cache ahead of time, e.g. before going offline
+    #[command(long_about = prefetch_help())]
+    Prefetch {
+        #[arg(
+            long,
+            value_name = "YYYY-MM",
+            conflicts_with = "months",
+            help = "First month to fetch",
+            value_parser = parser_calendar_month
+        )]
+        from: Option<String>,
+        #[arg(
+            long,
+            value_name = "YYYY-MM",
+            help = "Last month to fetch (defaults to current month)",
+            value_parser = parser_calendar_month
+        )]
+        to: Option<String>,
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::value_parser!(u32).range(1..=120),
+            help = "Fetch the N months ending at --to, up to 120 [default: 3]"
+        )]
+        months: Option<u32>,
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = 2,
+            value_parser = clap::value_parser!(u32).range(1..=8),
+            help = "Months to fetch at once; keep it low to stay under GitHub's rate limits"
+        )]
+        jobs: u32,
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = DateField::Created,
+            help = "Place PRs in the month by creation or merge date"
+        )]
+        by: DateField,
+    },
+    /// Run a raw GraphQL query through gh, following $endCursor pagination
+    #[command(long_about = raw_help())]
+    Raw {
+        #[arg(
+            value_name = "QUERY",
+            required_unless_present = "query_file",
+            help = "Inline GraphQL query"
+        )]
+        query: Option<String>,
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with = "query",
+            help = "Read the GraphQL query from a file"
+        )]
+        query_file: Option<std::path::PathBuf>,
     },
     /// Create/edit config - exclude/ignore repos, customize PR size thresholds
     #[command(long_about = config_help())]
@@ -327,141 +1080,1196 @@ enum Commands {
     /// Verify GitHub CLI (gh) is installed and show cache/config paths
     #[command(long_about = doctor_help())]
     #[command(name = "doctor")]
-    Doctor,
+    Doctor {
+        #[arg(long, help = "Output diagnostics in JSON format")]
+        json: bool,
+        #[arg(long, help = "List cache files largest first")]
+        by_size: bool,
+    },
+    /// Print the version, with --verbose adding build details for bug reports
+    Version {
+        #[arg(long, help = "Include git commit, build date and rustc version")]
+        verbose: bool,
+    },
     /// Generate shell completion scripts for your shell
     #[command(long_about = completions_help())]
     Completions {
         /// Shell to generate completions for
         #[arg(value_enum)]
         shell: Shell,
+        #[arg(
+            long,
+            help = "Write the script to the shell's usual completions directory instead of stdout"
+        )]
+        install: bool,
+        #[arg(
+            long,
+            value_name = "DIR",
+            requires = "install",
+            help = "Directory to install into (overrides the per-shell default)"
+        )]
+        dir: Option<std::path::PathBuf>,
     },
 }
 
-fn parser_month(s: &str) -> anyhow::Result<String> {
-    let re = regex::Regex::new(r"^\d{4}-\d{2}$").unwrap();
+/// Accept a whole month only (`YYYY-MM`).
+fn parser_calendar_month(s: &str) -> gh_log::Result<String> {
+    let re = regex::Regex::new(r"^\d{4}-(0[1-9]|1[0-2])$").unwrap();
     if re.is_match(s) {
         Ok(s.to_string())
     } else {
-        bail!("Month must be in format YYYY-MM, e.g. 2025-11")
+        Err(gh_log::Error::InvalidMonth(s.to_string()))
     }
 }
 
-fn get_data_with_cache(
-    month: &str,
-    use_cache: bool,
-) -> anyhow::Result<(Vec<github::PullRequest>, usize)> {
-    let cache = cache::Cache::default()?;
-    // Reuse cached data when allowed to avoid redundant API calls.
-    if use_cache && let Some(cached) = cache.load(month)? {
-        eprintln!("Loading from cache...");
-        return Ok((cached.prs, cached.reviewed_count));
+/// Accept a month (`YYYY-MM`), a single day (`YYYY-MM-DD`), or a day range
+/// (`YYYY-MM-DD..YYYY-MM-DD`) inside one month. All three are valid GitHub search date
+/// qualifiers, so the string is passed through unchanged and doubles as the cache key.
+fn parser_month(s: &str) -> gh_log::Result<String> {
+    let invalid = || gh_log::Error::InvalidMonth(s.to_string());
+    if let Ok(month) = parser_calendar_month(s) {
+        return Ok(month);
     }
 
-    // Fetch live data when the cache misses or a refresh is forced.
-    eprintln!("Fetching data from GitHub...");
-    let client = github::CommandClient::new()?;
-    let prs = client.fetch_prs(month)?;
-    let reviewed_count = client.fetch_reviewed_prs(month)?;
-
-    // Persist the fresh snapshot so the next call can reuse it.
-    let cached_data = cache::CachedData {
-        month: month.to_string(),
-        timestamp: chrono::Utc::now(),
-        prs: prs.clone(),
-        reviewed_count,
+    let parse_day = |day: &str| {
+        chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d")
+            .ok()
+            .filter(|_| day.len() == 10)
     };
-
-    cache.save(&cached_data)?;
-    Ok((prs, reviewed_count))
+    let (start, end) = match s.split_once("..") {
+        Some((start, end)) => (parse_day(start), parse_day(end)),
+        None => (parse_day(s), parse_day(s)),
+    };
+    let (Some(start), Some(end)) = (start, end) else {
+        return Err(invalid());
+    };
+    // Reports are built per month, so a range may not leave the month it starts in.
+    if start > end || start.format("%Y-%m").to_string() != end.format("%Y-%m").to_string() {
+        return Err(invalid());
+    }
+    Ok(s.to_string())
 }
 
-fn run_view_mode(month: &str, force: bool) -> anyhow::Result<()> {
-    let use_cache = !force;
-    let (prs, reviewed_count) = get_data_with_cache(month, use_cache)?;
-    // We reload config on every run so edits from `gh-log config` take effect immediately.
-    let cfg = config::Config::default()?;
-    let month_data = data::build_month_data(month, prs, reviewed_count, &cfg);
+/// Accept a GitHub user or organization login (`--author`, `--org`). Logins go straight into
+/// search queries and cache file names, so anything else is refused.
+fn parser_login(s: &str) -> gh_log::Result<String> {
+    let re = regex::Regex::new(r"^[A-Za-z0-9][A-Za-z0-9-]*$").unwrap();
+    if re.is_match(s) {
+        Ok(s.to_string())
+    } else {
+        Err(gh_log::Error::InvalidLogin(s.to_string()))
+    }
+}
+
+/// Parse `--range START..END`: two `YYYY-MM-DD` dates, the start on or before the end. Unlike a
+/// `--month` day range, it may span several months.
+fn parser_range(s: &str) -> gh_log::Result<String> {
+    let invalid = || gh_log::Error::InvalidRange(s.to_string());
+    let parse_day = |day: &str| {
+        chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d")
+            .ok()
+            .filter(|_| day.len() == 10)
+    };
+    let (start, end) = s.split_once("..").ok_or_else(invalid)?;
+    match (parse_day(start), parse_day(end)) {
+        (Some(start), Some(end)) if start <= end => Ok(s.to_string()),
+        _ => Err(invalid()),
+    }
+}
+
+/// Parse `--quarter YYYY-Qn` into the quarter's `START..END` range, which doubles as its cache
+/// key.
+fn parser_quarter(s: &str) -> gh_log::Result<String> {
+    let invalid = || gh_log::Error::InvalidQuarter(s.to_string());
+    let (year, quarter) = s.split_once("-Q").ok_or_else(invalid)?;
+    let year = parse_year(year).ok_or_else(invalid)?;
+    let quarter = quarter.parse::<u32>().ok().filter(|_| quarter.len() == 1);
+    quarter
+        .and_then(|quarter| data::quarter_range(year, quarter))
+        .ok_or_else(invalid)
+}
+
+/// Parse `--year YYYY` into the year's `START..END` range.
+fn parser_year(s: &str) -> gh_log::Result<String> {
+    parse_year(s)
+        .map(data::year_range)
+        .ok_or_else(|| gh_log::Error::InvalidYear(s.to_string()))
+}
 
-    view::run(month_data, cfg)
+fn parse_year(s: &str) -> Option<i32> {
+    s.parse()
+        .ok()
+        .filter(|_| s.len() == 4 && s.bytes().all(|b| b.is_ascii_digit()))
 }
 
-fn run_print_mode(month: &str, force: bool, format: OutputFormat) -> anyhow::Result<()> {
-    let use_cache = !force;
-    let (prs, reviewed_count) = get_data_with_cache(month, use_cache)?;
+/// How a report splits its PRs into buckets, from the period flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Period {
+    /// Calendar weeks of one month (`--month`).
+    Month,
+    /// 7-day buckets from the start of a date range (`--range`, `--last-weeks`, `--quarter`).
+    Window,
+    /// 7-day buckets over a year plus a total for each of its months (`--year`).
+    Year,
+}
+
+/// How `view`/`print` should fetch a month's data.
+#[derive(Debug, Clone, Copy)]
+struct FetchOptions<'a> {
+    date_field: DateField,
+    use_cache: bool,
+    /// Run the second query for the reviewed-PR count (`--no-reviewed` turns it off).
+    fetch_reviewed: bool,
+    /// Print progress notes to stderr. Off inside the TUI, where they would corrupt the screen.
+    progress: bool,
+    /// Analyze the PRs you reviewed instead of the ones you authored (`--reviewed`).
+    reviewed: bool,
+    /// `gh` executable to run, from `resolve_gh_binary`.
+    gh: &'a Path,
+    /// Reviews fetched per PR, from `[gh] review_fetch_limit`.
+    review_limit: usize,
+    /// Serve cached data whatever its age and never run `gh` (`--offline`).
+    offline: bool,
+    /// Someone else's login to report on (`--author`, `--author-file`); `None` means you.
+    author: Option<&'a str>,
+    /// Only PRs in this organization's repositories (`--org`).
+    org: Option<&'a str>,
+    /// Stop the PR search after this many PRs (`--limit`); such partial snapshots are never
+    /// cached.
+    pr_limit: Option<usize>,
+}
+
+impl FetchOptions<'_> {
+    fn client(&self) -> gh_log::Result<github::CommandClient> {
+        let mut client = github::CommandClient::new(self.gh)?.with_review_limit(self.review_limit);
+        if let Some(org) = self.org {
+            client = client.with_org(org);
+        }
+        if let Some(limit) = self.pr_limit {
+            client = client.with_pr_limit(limit);
+        }
+        Ok(client)
+    }
+
+    /// Cache key for `month` under these options: whose PRs, authored or reviewed, and the
+    /// organization they are narrowed to.
+    fn cache_key(&self, month: &str) -> String {
+        let key = match (self.reviewed, self.author) {
+            (false, None) => cache::cache_key(month, self.date_field),
+            (false, Some(login)) => cache::author_cache_key(month, self.date_field, login),
+            (true, None) => cache::reviewed_cache_key(month, self.date_field),
+            (true, Some(login)) => format!(
+                "{}.reviewed",
+                cache::author_cache_key(month, self.date_field, login)
+            ),
+        };
+        match self.org {
+            Some(org) => cache::org_cache_key(&key, org),
+            None => key,
+        }
+    }
+}
+
+/// The `gh` client for a fetch, started on first use so cache hits and `--offline` runs never
+/// need the GitHub CLI.
+struct LazyClient<'a> {
+    opts: FetchOptions<'a>,
+    client: OnceCell<github::CommandClient>,
+}
+
+impl<'a> LazyClient<'a> {
+    fn new(opts: FetchOptions<'a>) -> Self {
+        Self {
+            opts,
+            client: OnceCell::new(),
+        }
+    }
+
+    fn get(&self) -> gh_log::Result<&github::CommandClient> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+        let client = self.opts.client()?;
+        Ok(self.client.get_or_init(|| client))
+    }
+}
+
+impl GithubClient for LazyClient<'_> {
+    fn fetch_prs_by(
+        &self,
+        login: &str,
+        month: &str,
+        date_field: DateField,
+    ) -> gh_log::Result<github::PrSearch> {
+        self.get()?.fetch_prs_by(login, month, date_field)
+    }
+
+    fn fetch_reviewed_prs_by(
+        &self,
+        login: &str,
+        month: &str,
+        date_field: DateField,
+    ) -> gh_log::Result<github::ReviewedPrs> {
+        self.get()?.fetch_reviewed_prs_by(login, month, date_field)
+    }
+
+    fn fetch_reviewed_pr_details_by(
+        &self,
+        login: &str,
+        month: &str,
+        date_field: DateField,
+    ) -> gh_log::Result<github::PrSearch> {
+        self.get()?
+            .fetch_reviewed_pr_details_by(login, month, date_field)
+    }
+}
+
+/// What [`get_data_with_cache`] found for a month.
+struct Fetched {
+    snapshot: cache::CachedData,
+    source: cache::DataSource,
+    /// The `--limit` the snapshot was cut to while the month holds more PRs: GitHub's
+    /// `hasNextPage` for a fetch, or the PRs left over in a cached month.
+    pr_limit: Option<usize>,
+}
+
+impl Fetched {
+    /// A snapshot served from the cache as-is.
+    fn cached(snapshot: cache::CachedData) -> Self {
+        Fetched {
+            source: cache::DataSource::Cache(snapshot.timestamp),
+            snapshot,
+            pr_limit: None,
+        }
+    }
+}
+
+/// A month's snapshot (PRs plus reviewed counts), from the cache or freshly fetched through `gh`
+/// and saved.
+fn get_data_with_cache(month: &str, opts: FetchOptions<'_>) -> anyhow::Result<Fetched> {
+    let cache = cache::Cache::default()?;
+    get_data_with_client(month, opts, &LazyClient::new(opts), &cache)
+}
+
+/// [`get_data_with_cache`] against `cache`, with cache misses answered by `client`. Under
+/// `--limit` a cached snapshot is cut to the same number of PRs a fresh search would return.
+fn get_data_with_client(
+    month: &str,
+    opts: FetchOptions<'_>,
+    client: &dyn GithubClient,
+    cache: &cache::Cache,
+) -> anyhow::Result<Fetched> {
+    let mut fetched = if opts.reviewed {
+        get_reviewed_data_with_cache(month, opts, client, cache)?
+    } else {
+        get_authored_data_with_cache(month, opts, client, cache)?
+    };
+    if let Some(limit) = opts.pr_limit
+        && fetched.snapshot.prs.len() > limit
+    {
+        fetched.snapshot.prs.truncate(limit);
+        fetched.pr_limit = Some(limit);
+    }
+    Ok(fetched)
+}
+
+/// `get_data_with_cache` for the PRs you (or `--author`) opened, plus the reviewed counts.
+fn get_authored_data_with_cache(
+    month: &str,
+    opts: FetchOptions<'_>,
+    client: &dyn GithubClient,
+    cache: &cache::Cache,
+) -> anyhow::Result<Fetched> {
+    let note = |msg: &str| {
+        if opts.progress {
+            eprintln!("{}", msg);
+        }
+    };
+    let key = opts.cache_key(month);
+    let login = opts.author.unwrap_or("@me");
+    if opts.offline {
+        let cached = load_offline(cache, &key, month)?;
+        note("Loading from cache (offline)...");
+        return Ok(Fetched::cached(cached));
+    }
+    // Reuse cached data when allowed to avoid redundant API calls.
+    if opts.use_cache
+        && let Some(mut cached) = load_fresh(cache, &key)?
+    {
+        let missing_reviewed =
+            cached.reviewed_by_repo.is_none() || cached.review_turnaround_secs.is_none();
+        if missing_reviewed && opts.fetch_reviewed {
+            // The snapshot came from a --no-reviewed run or predates per-repo counts and review
+            // turnaround; fill in just the missing review query.
+            note("Fetching reviewed count from GitHub...");
+            let reviewed = client.fetch_reviewed_prs_by(login, month, opts.date_field)?;
+            cached.reviewed_count = Some(reviewed.total);
+            cached.reviewed_by_repo = Some(reviewed.by_repo);
+            cached.review_turnaround_secs = Some(reviewed.turnaround_secs);
+            cache.save(&key, &cached)?;
+        } else {
+            note("Loading from cache...");
+        }
+        return Ok(Fetched::cached(cached));
+    }
+
+    // Fetch live data when the cache misses or a refresh is forced.
+    note("Fetching data from GitHub...");
+    let search = client.fetch_prs_by(login, month, opts.date_field)?;
+    let reviewed = if opts.fetch_reviewed {
+        Some(client.fetch_reviewed_prs_by(login, month, opts.date_field)?)
+    } else {
+        None
+    };
+    let snapshot = cache::CachedData {
+        version: cache::CACHE_VERSION,
+        month: month.to_string(),
+        timestamp: chrono::Utc::now(),
+        prs: search.prs,
+        reviewed_count: reviewed.as_ref().map(|reviewed| reviewed.total),
+        reviewed_by_repo: reviewed.as_ref().map(|reviewed| reviewed.by_repo.clone()),
+        review_turnaround_secs: reviewed.map(|reviewed| reviewed.turnaround_secs),
+    };
+
+    // Persist the fresh snapshot so the next call can reuse it, unless it stopped at --limit or
+    // would replace a snapshot that still carries a reviewed count with one that does not.
+    if let Some(limit) = opts.pr_limit {
+        return Ok(Fetched {
+            snapshot,
+            source: cache::DataSource::Fresh,
+            pr_limit: Some(limit).filter(|_| search.has_next_page),
+        });
+    }
+    let keeps_existing_count = snapshot.reviewed_count.is_none()
+        && match cache.load(&key) {
+            Ok(existing) => existing.is_some_and(|existing| existing.reviewed_count.is_some()),
+            // Already warned about when the cache was first checked; overwrite it.
+            Err(gh_log::Error::CacheFromFuture { .. }) => false,
+            Err(err) => return Err(err.into()),
+        };
+    if !keeps_existing_count {
+        cache.save(&key, &snapshot)?;
+    }
+    Ok(Fetched {
+        snapshot,
+        source: cache::DataSource::Fresh,
+        pr_limit: None,
+    })
+}
+
+/// [`cache::Cache::load`], warning about a snapshot dated in the future and refetching it.
+fn load_fresh(cache: &cache::Cache, key: &str) -> anyhow::Result<Option<cache::CachedData>> {
+    match cache.load(key) {
+        Err(err @ gh_log::Error::CacheFromFuture { .. }) => {
+            eprintln!("Warning: {}", err);
+            Ok(None)
+        }
+        result => Ok(result?),
+    }
+}
+
+fn review_limit(cfg: &config::Config) -> usize {
+    cfg.gh
+        .review_fetch_limit
+        .unwrap_or(github::DEFAULT_REVIEW_FETCH_LIMIT)
+}
+
+/// Months from `from` to `to` inclusive, oldest first. Empty when `from` is after `to`.
+fn month_range(from: &str, to: &str) -> Vec<String> {
+    let parse =
+        |month: &str| chrono::NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d");
+    let (Ok(mut current), Ok(end)) = (parse(from), parse(to)) else {
+        return Vec::new();
+    };
+    let mut months = Vec::new();
+    while current <= end {
+        months.push(current.format("%Y-%m").to_string());
+        current = current + chrono::Months::new(1);
+    }
+    months
+}
+
+fn run_prefetch(
+    months: &[String],
+    date_field: DateField,
+    gh: &Path,
+    review_limit: usize,
+    jobs: usize,
+) -> anyhow::Result<()> {
+    let opts = FetchOptions {
+        date_field,
+        use_cache: true,
+        fetch_reviewed: true,
+        progress: false,
+        reviewed: false,
+        gh,
+        review_limit,
+        offline: false,
+        author: None,
+        org: None,
+        pr_limit: None,
+    };
+
+    // Workers take the next month off a shared counter; results are reported in month order once
+    // every month is done.
+    let next = std::sync::atomic::AtomicUsize::new(0);
+    let results: std::sync::Mutex<Vec<_>> =
+        std::sync::Mutex::new((0..months.len()).map(|_| None).collect());
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, months.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let idx = next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    let Some(month) = months.get(idx) else {
+                        break;
+                    };
+                    // Fresh cache entries are served as-is, so only stale or missing months hit
+                    // GitHub.
+                    let result = get_data_with_cache(month, opts);
+                    results.lock().expect("prefetch results lock")[idx] = Some(result);
+                }
+            });
+        }
+    });
+
+    let mut failed = 0;
+    let results = results.into_inner().expect("prefetch results lock");
+    for (month, result) in months.iter().zip(results) {
+        match result.expect("every month is fetched") {
+            Ok(Fetched {
+                snapshot,
+                source: cache::DataSource::Cache(_),
+                ..
+            }) => {
+                println!("{}: {} PRs (already cached)", month, snapshot.prs.len())
+            }
+            Ok(Fetched {
+                snapshot,
+                source: cache::DataSource::Fresh,
+                ..
+            }) => {
+                println!("{}: {} PRs fetched", month, snapshot.prs.len())
+            }
+            Err(err) => {
+                failed += 1;
+                println!("{}: failed: {}", month, err);
+            }
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} of {} months failed to prefetch", failed, months.len());
+    }
+    Ok(())
+}
+
+/// `--offline`: the snapshot under `key` regardless of freshness, or an error when there is none.
+fn load_offline(cache: &cache::Cache, key: &str, month: &str) -> anyhow::Result<cache::CachedData> {
+    match cache.load_any(key)? {
+        Some(cached) => Ok(cached),
+        None => anyhow::bail!("No cached data for {} and --offline is set", month),
+    }
+}
+
+/// `get_data_with_cache` for `--reviewed`: full details of the PRs you reviewed, under their own
+/// cache key. The reviewed count is left out since it would just be the number of PRs.
+fn get_reviewed_data_with_cache(
+    month: &str,
+    opts: FetchOptions<'_>,
+    client: &dyn GithubClient,
+    cache: &cache::Cache,
+) -> anyhow::Result<Fetched> {
+    let note = |msg: &str| {
+        if opts.progress {
+            eprintln!("{}", msg);
+        }
+    };
+    let key = opts.cache_key(month);
+    if opts.offline {
+        let cached = load_offline(cache, &key, month)?;
+        note("Loading from cache (offline)...");
+        return Ok(Fetched::cached(cached));
+    }
+    if opts.use_cache
+        && let Some(cached) = load_fresh(cache, &key)?
+    {
+        note("Loading from cache...");
+        return Ok(Fetched::cached(cached));
+    }
+
+    note("Fetching reviewed PRs from GitHub...");
+    let login = opts.author.unwrap_or("@me");
+    let search = client.fetch_reviewed_pr_details_by(login, month, opts.date_field)?;
+    let snapshot = cache::CachedData {
+        version: cache::CACHE_VERSION,
+        month: month.to_string(),
+        timestamp: chrono::Utc::now(),
+        prs: search.prs,
+        reviewed_count: None,
+        reviewed_by_repo: None,
+        review_turnaround_secs: None,
+    };
+    // A search cut short by --limit would pass for the whole month on the next run.
+    if opts.pr_limit.is_none() {
+        cache.save(&key, &snapshot)?;
+    }
+    Ok(Fetched {
+        snapshot,
+        source: cache::DataSource::Fresh,
+        pr_limit: opts.pr_limit.filter(|_| search.has_next_page),
+    })
+}
+
+/// Layer the flags given on this run over the saved `[defaults]`, first writing them (and
+/// `settings`) to the config when `save` is set (`--save-config`). Explicit flag > saved default >
+/// built-in default.
+fn resolve_defaults(
+    cfg: &mut config::Config,
+    flags: config::DefaultsConfig,
+    settings: config::SavedSettings,
+    save: bool,
+) -> anyhow::Result<config::DefaultsConfig> {
+    if save {
+        cfg.save_defaults(flags, settings)?;
+        eprintln!("Saved defaults to {}", cfg.path().display());
+    }
+    Ok(flags.or(cfg.defaults))
+}
+
+/// Load the config for a reporting command, applying CLI overrides on top of the file.
+fn load_config(
+    include_bots: bool,
+    filters: &FilterArgs,
+    tz: Option<Timezone>,
+) -> anyhow::Result<config::Config> {
     // We reload config on every run so edits from `gh-log config` take effect immediately.
-    let cfg = config::Config::default()?;
-    let data = data::build_month_data(month, prs, reviewed_count, &cfg);
+    let mut cfg = config::Config::default()?;
+    if tz.is_some() {
+        cfg.display.timezone = tz;
+    }
+    cfg.filter.include_bots |= include_bots;
+    cfg.filter
+        .exclude_repos
+        .extend(filters.exclude_repo.iter().cloned());
+    cfg.filter
+        .ignore_repos
+        .extend(filters.ignore_repo.iter().cloned());
+    cfg.filter
+        .exclude_patterns
+        .extend(filters.exclude_pattern.iter().cloned());
+    cfg.filter
+        .ignore_patterns
+        .extend(filters.ignore_pattern.iter().cloned());
+    cfg.filter
+        .include_labels
+        .extend(filters.label.iter().cloned());
+    if let Some(state) = filters.state {
+        cfg.filter.state = state;
+    }
+    // Patterns from the command line get the same check as the file's, before any fetching.
+    cfg.filter.validate()?;
+    Ok(cfg)
+}
+
+/// Aggregate a snapshot into the report, bucketed as `period` asks.
+fn build_report(
+    month: &str,
+    snapshot: cache::CachedData,
+    date_field: DateField,
+    cfg: &config::Config,
+    period: Period,
+) -> data::MonthData {
+    let build = match period {
+        Period::Month => data::build_month_data,
+        Period::Window => data::build_window_data,
+        Period::Year => data::build_year_data,
+    };
+    let mut data = build(
+        month,
+        snapshot.prs,
+        snapshot.reviewed_count,
+        date_field,
+        cfg,
+    );
+    if let Some(by_repo) = &snapshot.reviewed_by_repo {
+        data.set_reviewed_by_repo(by_repo);
+    }
+    if let Some(secs) = &snapshot.review_turnaround_secs {
+        data.set_review_turnaround(secs);
+    }
+    data
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_view_mode(
+    month: &str,
+    opts: FetchOptions<'_>,
+    cfg: config::Config,
+    compact: bool,
+    hyperlinks: bool,
+    repo_order: RepoOrder,
+    period: Period,
+    trend_months: u32,
+) -> anyhow::Result<()> {
+    let Fetched {
+        snapshot,
+        source,
+        pr_limit,
+    } = get_data_with_cache(month, opts)?;
+    let mut month_data = build_report(month, snapshot, opts.date_field, &cfg, period);
+    month_data.pr_limit = pr_limit;
+    month_data.sort_repos(repo_order);
+
+    // Months reached with [ / ] come from the cache when possible, even under --force.
+    let load_opts = FetchOptions {
+        use_cache: true,
+        progress: false,
+        ..opts
+    };
+    let load_cfg = cfg.clone();
+    let load = move |month: &str| {
+        let Fetched {
+            snapshot,
+            source,
+            pr_limit,
+        } = get_data_with_cache(month, load_opts)?;
+        let mut month_data = build_report(
+            month,
+            snapshot,
+            load_opts.date_field,
+            &load_cfg,
+            Period::Month,
+        );
+        month_data.pr_limit = pr_limit;
+        month_data.sort_repos(repo_order);
+        Ok((month_data, source))
+    };
+
+    let trend = load_trend(trend_months, opts, &cfg, &cache::Cache::default()?)?;
+    view::run(
+        month, month_data, cfg, source, compact, hyperlinks, trend, load,
+    )
+}
+
+/// Reports for the newest `months` cached months under the same author, organization and
+/// reviewed scope as `opts`, oldest first, for the view's Trend chart. Only `cache` is read, so
+/// months never fetched are simply missing.
+fn load_trend(
+    months: u32,
+    opts: FetchOptions<'_>,
+    cfg: &config::Config,
+    cache: &cache::Cache,
+) -> anyhow::Result<Vec<data::MonthData>> {
+    let snapshots = cache.load_recent_months(months as usize, |month| opts.cache_key(month))?;
+    Ok(snapshots
+        .into_iter()
+        .map(|snapshot| {
+            let month = snapshot.month.clone();
+            build_report(&month, snapshot, opts.date_field, cfg, Period::Month)
+        })
+        .collect())
+}
+
+/// Logins listed in an `--author-file`: one per line, with blank lines and `#` comments skipped
+/// and repeats dropped.
+fn parse_author_file(contents: &str) -> anyhow::Result<Vec<String>> {
+    let mut logins: Vec<String> = Vec::new();
+    for (idx, line) in contents.lines().enumerate() {
+        let login = line.split('#').next().unwrap_or_default().trim();
+        if login.is_empty() {
+            continue;
+        }
+        if parser_login(login).is_err() {
+            anyhow::bail!("line {}: {:?} is not a GitHub login", idx + 1, login);
+        }
+        if !logins.iter().any(|seen| seen.eq_ignore_ascii_case(login)) {
+            logins.push(login.to_string());
+        }
+    }
+    if logins.is_empty() {
+        anyhow::bail!("no logins found");
+    }
+    Ok(logins)
+}
+
+/// `print --author-file`: each login's month fetched and cached like your own, merged into one
+/// report whose `authors` keeps everyone's own totals. The source is the oldest cached snapshot,
+/// or fresh when every login was fetched.
+fn build_team_report(
+    month: &str,
+    opts: FetchOptions<'_>,
+    logins: &[String],
+    cfg: &config::Config,
+    period: Period,
+) -> anyhow::Result<(data::MonthData, cache::DataSource)> {
+    let mut combined = cache::CachedData {
+        version: cache::CACHE_VERSION,
+        month: month.to_string(),
+        timestamp: chrono::Utc::now(),
+        prs: Vec::new(),
+        reviewed_count: None,
+        reviewed_by_repo: None,
+        review_turnaround_secs: None,
+    };
+    let mut authors = Vec::new();
+    let mut source = cache::DataSource::Fresh;
+    for login in logins {
+        if opts.progress {
+            eprintln!("{}:", login);
+        }
+        let author_opts = FetchOptions {
+            author: Some(login),
+            ..opts
+        };
+        let Fetched {
+            snapshot,
+            source: author_source,
+            ..
+        } = get_data_with_cache(month, author_opts)?;
+        if let cache::DataSource::Cache(timestamp) = author_source
+            && !matches!(source, cache::DataSource::Cache(oldest) if oldest <= timestamp)
+        {
+            source = author_source;
+        }
+
+        let report = build_report(month, snapshot.clone(), opts.date_field, cfg, period);
+        authors.push(data::AuthorSummary::new(
+            login,
+            &report,
+            cfg.analytics.review_balance_base,
+        ));
 
+        merge_snapshot(&mut combined, snapshot);
+    }
+
+    let mut data = build_report(month, combined, opts.date_field, cfg, period);
+    data.authors = authors;
+    Ok((data, source))
+}
+
+/// Add `snapshot`'s PRs to `combined` and sum its review counts into the running totals.
+fn merge_snapshot(combined: &mut cache::CachedData, snapshot: cache::CachedData) {
+    combined.prs.extend(snapshot.prs);
+    if let Some(reviewed) = snapshot.reviewed_count {
+        *combined.reviewed_count.get_or_insert(0) += reviewed;
+    }
+    if let Some(by_repo) = snapshot.reviewed_by_repo {
+        let merged = combined
+            .reviewed_by_repo
+            .get_or_insert_with(Default::default);
+        for (repo, reviewed) in by_repo {
+            *merged.entry(repo).or_default() += reviewed;
+        }
+    }
+    if let Some(secs) = snapshot.review_turnaround_secs {
+        combined
+            .review_turnaround_secs
+            .get_or_insert_with(Vec::new)
+            .extend(secs);
+    }
+}
+
+/// `print --all-cached`: every whole-month snapshot already in the cache, however old, rolled up
+/// into one report bucketed by month. Never calls GitHub; the source is the oldest snapshot.
+fn build_all_cached_report(
+    label: &str,
+    months: &[String],
+    date_field: DateField,
+    cfg: &config::Config,
+) -> anyhow::Result<(data::MonthData, cache::DataSource)> {
+    let cache = cache::Cache::default()?;
+    let mut combined = cache::CachedData {
+        version: cache::CACHE_VERSION,
+        month: label.to_string(),
+        timestamp: chrono::Utc::now(),
+        prs: Vec::new(),
+        reviewed_count: None,
+        reviewed_by_repo: None,
+        review_turnaround_secs: None,
+    };
+    for month in months {
+        let key = cache::cache_key(month, date_field);
+        let Some(snapshot) = cache.load_any(&key)? else {
+            continue;
+        };
+        combined.timestamp = combined.timestamp.min(snapshot.timestamp);
+        merge_snapshot(&mut combined, snapshot);
+    }
+
+    let source = cache::DataSource::Cache(combined.timestamp);
+    let mut data = data::build_history_data(
+        label,
+        combined.prs,
+        combined.reviewed_count,
+        date_field,
+        cfg,
+    );
+    if let Some(by_repo) = &combined.reviewed_by_repo {
+        data.set_reviewed_by_repo(by_repo);
+    }
+    if let Some(secs) = &combined.review_turnaround_secs {
+        data.set_review_turnaround(secs);
+    }
+    Ok((data, source))
+}
+
+/// Whose PRs `print` reports on, and which searches find them.
+enum ReportScope {
+    /// Your PRs, placed in the month by `--by`.
+    Own,
+    /// Your PRs created in the month plus those merged in it (`--also-merged`).
+    AlsoMerged,
+    /// The logins from `--author-file`.
+    Team(Vec<String>),
+    /// Every month in the cache (`--all-cached`), oldest first.
+    AllCached(Vec<String>),
+}
+
+/// `print --also-merged`: the created-date and merged-date snapshots, each cached under its own
+/// key, combined into one report. The review count comes from the created-date search.
+fn build_also_merged_report(
+    month: &str,
+    opts: FetchOptions<'_>,
+    cfg: &config::Config,
+) -> anyhow::Result<(data::MonthData, cache::DataSource)> {
+    let Fetched {
+        snapshot: created,
+        source: created_source,
+        ..
+    } = get_data_with_cache(month, opts)?;
+    let merged_opts = FetchOptions {
+        date_field: DateField::Merged,
+        fetch_reviewed: false,
+        ..opts
+    };
+    let Fetched {
+        snapshot: merged,
+        source: merged_source,
+        ..
+    } = get_data_with_cache(month, merged_opts)?;
+    let source = match (created_source, merged_source) {
+        (cache::DataSource::Cache(a), cache::DataSource::Cache(b)) => {
+            cache::DataSource::Cache(a.min(b))
+        }
+        (cache::DataSource::Cache(_), _) => created_source,
+        _ => merged_source,
+    };
+    let mut data =
+        data::build_also_merged_data(month, created.prs, merged.prs, created.reviewed_count, cfg);
+    if let Some(by_repo) = &created.reviewed_by_repo {
+        data.set_reviewed_by_repo(by_repo);
+    }
+    if let Some(secs) = &created.review_turnaround_secs {
+        data.set_review_turnaround(secs);
+    }
+    Ok((data, source))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_print_mode(
+    month: &str,
+    opts: FetchOptions<'_>,
+    format: OutputFormat,
+    cfg: config::Config,
+    fail_on_empty: bool,
+    period: Period,
+    repo_order: RepoOrder,
+    pr_order: Option<PrOrder>,
+    scope: &ReportScope,
+    color: bool,
+) -> anyhow::Result<()> {
+    let (mut data, source) = match scope {
+        ReportScope::Own => {
+            let Fetched {
+                snapshot,
+                source,
+                pr_limit,
+            } = get_data_with_cache(month, opts)?;
+            let mut data = build_report(month, snapshot, opts.date_field, &cfg, period);
+            data.pr_limit = pr_limit;
+            (data, source)
+        }
+        ReportScope::AlsoMerged => build_also_merged_report(month, opts, &cfg)?,
+        ReportScope::Team(logins) => build_team_report(month, opts, logins, &cfg, period)?,
+        ReportScope::AllCached(months) => {
+            build_all_cached_report(month, months, opts.date_field, &cfg)?
+        }
+    };
+    data.sort_repos(repo_order);
+    if let Some(order) = pr_order {
+        data.sort_prs(order);
+    }
+    if let cache::DataSource::Cache(_) = source {
+        eprintln!(
+            "{} (use --force to refresh)",
+            source.describe(chrono::Utc::now())
+        );
+    }
+
+    let warnings = data.warnings(&cfg.targets);
     match format {
-        OutputFormat::Raw => view::print_data(&data, month, &cfg.size),
-        OutputFormat::Json => view::print_json(&data, &cfg.size)?,
-        OutputFormat::Csv => view::print_csv(&data, &cfg.size)?,
+        OutputFormat::Raw {
+            group_by,
+            max_body_lines,
+            hyperlinks,
+        } => view::print_data(
+            &data,
+            month,
+            &cfg,
+            &warnings,
+            group_by,
+            opts.date_field,
+            max_body_lines,
+            color,
+            hyperlinks,
+        ),
+        OutputFormat::Json => view::print_json(&data, &cfg, &warnings)?,
+        OutputFormat::Csv => view::print_csv(&data, &cfg)?,
+        OutputFormat::Markdown => view::print_markdown(&data, &cfg),
+        OutputFormat::Html => view::print_html(&data, &cfg),
+        OutputFormat::Table(width) => view::print_table(&data, &cfg, width, color)?,
+        OutputFormat::Postprocess(command) => {
+            run_postprocess(&command, &view::json_string(&data, &cfg, &warnings)?)?
+        }
+    }
+
+    // The report is still printed so the CI log shows the empty month alongside the failure.
+    if fail_on_empty && data.total_prs == 0 {
+        return Err(gh_log::Error::NoPrs(month.to_string()).into());
     }
 
     Ok(())
 }
 
-fn run_doctor() -> anyhow::Result<()> {
-    println!("gh-log diagnostics\n");
-    match Command::new("gh").arg("--version").output() {
+/// `raw`: run `query` and print each response page as a line of JSON.
+fn run_raw(query: &str, gh: &Path) -> anyhow::Result<()> {
+    let client = github::CommandClient::new(gh)?;
+    let mut stdout = io::stdout().lock();
+    for page in client.raw_query(query)? {
+        writeln!(stdout, "{}", page)?;
+    }
+    Ok(())
+}
+
+/// `print --postprocess`: feed the JSON report to `command` (run by the shell) on stdin and
+/// stream its stdout back as the output.
+fn run_postprocess(command: &str, json: &str) -> anyhow::Result<()> {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    let mut child = Command::new(shell)
+        .arg(flag)
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run --postprocess command: {}", command))?;
+
+    // Write from another thread so a command that answers before reading all of its input
+    // cannot deadlock against us.
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let input = json.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let mut output = child.stdout.take().expect("stdout is piped");
+    io::copy(&mut output, &mut io::stdout().lock())?;
+    let status = child.wait()?;
+    match writer.join().expect("stdin writer panicked") {
+        // The command may legitimately stop reading early, e.g. `head`.
+        Err(err) if err.kind() != io::ErrorKind::BrokenPipe => return Err(err.into()),
+        _ => {}
+    }
+
+    if !status.success() {
+        anyhow::bail!("--postprocess command exited with {}: {}", status, command);
+    }
+    Ok(())
+}
+
+/// `print --pr`: the requested PRs in full, with a warning for each number the month lacks.
+fn run_print_prs(
+    month: &str,
+    opts: FetchOptions<'_>,
+    numbers: &[u32],
+    cfg: &config::Config,
+) -> anyhow::Result<()> {
+    let snapshot = get_data_with_cache(month, opts)?.snapshot;
+    let selected = data::select_prs(&snapshot.prs, numbers, opts.date_field);
+    for number in numbers {
+        if !selected.iter().any(|pr| pr.number == *number) {
+            eprintln!("Warning: PR #{} not found in {}", number, month);
+        }
+    }
+    view::print_pr_details(&selected, &cfg.size);
+    Ok(())
+}
+
+/// Build metadata captured by `build.rs`, shown by `version --verbose` and `doctor`.
+#[derive(Serialize)]
+struct BuildInfo {
+    version: &'static str,
+    git_sha: &'static str,
+    build_date: &'static str,
+    rustc: &'static str,
+}
+
+const BUILD_INFO: BuildInfo = BuildInfo {
+    version: env!("CARGO_PKG_VERSION"),
+    git_sha: env!("GH_LOG_GIT_SHA"),
+    build_date: env!("GH_LOG_BUILD_DATE"),
+    rustc: env!("GH_LOG_RUSTC_VERSION"),
+};
+
+fn run_version(verbose: bool) {
+    println!("gh-log {}", BUILD_INFO.version);
+    if verbose {
+        println!("commit: {}", BUILD_INFO.git_sha);
+        println!("built: {}", BUILD_INFO.build_date);
+        println!("rustc: {}", BUILD_INFO.rustc);
+    }
+}
+
+/// Outcome of probing `gh --version`.
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum GhStatus {
+    Ok,
+    NotAuthenticated,
+    NotInstalled,
+}
+
+/// Machine-readable `doctor` output for `--json`.
+#[derive(Serialize)]
+struct DoctorReport {
+    build: &'static BuildInfo,
+    gh_status: GhStatus,
+    gh_path: String,
+    gh_version: Option<String>,
+    cache_dir: Option<String>,
+    cache_files: Vec<cache::CacheFileInfo>,
+    config_path: Option<String>,
+    config_exists: bool,
+    /// Why the config file failed to load; `null` when it is valid or does not exist.
+    config_error: Option<String>,
+    /// Scopes of the gh token; `null` when gh is unavailable or reports none (fine-grained tokens).
+    token_scopes: Option<Vec<String>>,
+    /// GraphQL rate limit; `null` when gh is unavailable or the check failed.
+    rate_limit: Option<github::RateLimit>,
+}
+
+fn run_doctor(json: bool, by_size: bool) -> anyhow::Result<()> {
+    let dirs = directories::ProjectDirs::from("", "", "gh-log");
+    let cache_dir = dirs.as_ref().map(|d| d.cache_dir().to_path_buf());
+    let config_path = dirs.as_ref().map(|d| d.config_dir().join("config.toml"));
+    let config_exists = config_path.as_ref().is_some_and(|p| p.exists());
+
+    // Only load a config that exists, so doctor does not write the template. A broken config is
+    // reported below and otherwise falls back to plain `gh`.
+    let loaded = config_exists.then(config::Config::default);
+    let config_error = match &loaded {
+        Some(Err(err)) => Some(err.to_string()),
+        _ => None,
+    };
+    let configured_gh = match loaded {
+        Some(Ok(cfg)) => cfg.gh.binary,
+        _ => None,
+    };
+    let gh = github::resolve_gh_binary(configured_gh.as_deref());
+    let (gh_status, gh_version) = match Command::new(&gh).arg("--version").output() {
         Ok(output) if output.status.success() => {
             let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            println!("✓ GitHub CLI: {}", version);
+            (GhStatus::Ok, Some(version))
+        }
+        Ok(_) => (GhStatus::NotAuthenticated, None),
+        Err(_) => (GhStatus::NotInstalled, None),
+    };
+    let (token_scopes, rate_limit) = match gh_status {
+        GhStatus::Ok => (github::token_scopes(&gh), github::graphql_rate_limit(&gh)),
+        _ => (None, None),
+    };
+
+    let mut cache_files = match &cache_dir {
+        Some(dir) if dir.exists() => cache::list_cache_files(dir).unwrap_or_default(),
+        _ => Vec::new(),
+    };
+    if by_size {
+        cache_files.sort_by_key(|f| std::cmp::Reverse(f.size_bytes));
+    }
+
+    if json {
+        let report = DoctorReport {
+            build: &BUILD_INFO,
+            gh_status,
+            gh_path: gh.display().to_string(),
+            gh_version,
+            cache_dir: cache_dir.as_ref().map(|d| d.display().to_string()),
+            cache_files,
+            config_exists,
+            config_error,
+            config_path: config_path.as_ref().map(|p| p.display().to_string()),
+            token_scopes,
+            rate_limit,
+        };
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!("gh-log diagnostics\n");
+    println!(
+        "gh-log {} ({}, built {}, {})\n",
+        BUILD_INFO.version, BUILD_INFO.git_sha, BUILD_INFO.build_date, BUILD_INFO.rustc
+    );
+    match gh_status {
+        GhStatus::Ok => {
+            println!("✓ GitHub CLI: {}", gh_version.unwrap_or_default());
+            println!("  Path: {}", gh.display());
+            print_token_scopes(token_scopes.as_deref());
+            print_rate_limit(rate_limit.as_ref());
         }
-        Ok(_) => {
+        GhStatus::NotAuthenticated => {
             println!("✗ GitHub CLI: installed but not authenticated");
             println!("  Run: gh auth login");
         }
-        Err(_) => {
-            println!("✗ GitHub CLI: not installed");
+        GhStatus::NotInstalled => {
+            println!("✗ GitHub CLI: not found at {}", gh.display());
             println!("  Install from: https://cli.github.com/");
+            println!(
+                "  Already installed elsewhere? Set {} or [gh] binary in the config",
+                github::GH_PATH_ENV
+            );
         }
     }
 
-    match directories::ProjectDirs::from("", "", "gh-log") {
-        Some(dirs) => {
-            let cache_dir = dirs.cache_dir();
-            let config_dir = dirs.config_dir();
-            let config_path = config_dir.join("config.toml");
+    match (cache_dir, config_path) {
+        (Some(cache_dir), Some(config_path)) => {
             println!("\nCache directory: {}", cache_dir.display());
 
-            if cache_dir.exists() {
-                if let Ok(entries) = std::fs::read_dir(cache_dir) {
-                    let mut cache_files: Vec<_> = entries
-                        .filter_map(|e| e.ok())
-                        .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
-                        .collect();
-
-                    if cache_files.is_empty() {
-                        println!("  (no cache files)");
-                    } else {
-                        cache_files.sort_by_key(|e| e.path());
-                        for entry in cache_files {
-                            if let Ok(metadata) = entry.metadata()
-                                && let Ok(modified) = metadata.modified()
-                            {
-                                let datetime: chrono::DateTime<chrono::Utc> = modified.into();
-                                println!(
-                                    "  {} ({})",
-                                    entry.file_name().to_string_lossy(),
-                                    datetime.format("%Y-%m-%d %H:%M:%S UTC")
-                                );
-                            }
-                        }
-                    }
-                }
-            } else {
+            if !cache_dir.exists() {
                 println!("  (directory does not exist yet)");
+            } else if cache_files.is_empty() {
+                println!("  (no cache files)");
+            } else {
+                for file in &cache_files {
+                    println!(
+                        "  {} ({}, {})",
+                        file.name,
+                        file.modified.format("%Y-%m-%d %H:%M:%S UTC"),
+                        cache::format_size(file.size_bytes)
+                    );
+                }
             }
 
             println!("\nConfiguration file: {}", config_path.display());
-            if config_path.exists() {
-                println!("  (exists)");
+            if let Some(err) = &config_error {
+                println!("  ✗ Config invalid: {}", err);
+            } else if config_exists {
+                println!("  ✓ Config valid");
             } else {
                 println!("  (not created yet, using defaults)");
             }
         }
-        None => {
+        _ => {
             println!("\n✗ Could not determine cache/config directories");
         }
     }
@@ -469,6 +2277,106 @@ fn run_doctor() -> anyhow::Result<()> {
     Ok(())
 }
 
+fn print_token_scopes(scopes: Option<&[String]>) {
+    let Some(scopes) = scopes else {
+        println!("  Token scopes: unknown (none listed by gh auth status)");
+        return;
+    };
+    if scopes.is_empty() {
+        println!("  Token scopes: none");
+    } else {
+        println!("  Token scopes: {}", scopes.join(", "));
+    }
+    let missing: Vec<&str> = github::REQUIRED_TOKEN_SCOPES
+        .iter()
+        .copied()
+        .filter(|required| !scopes.iter().any(|scope| scope == required))
+        .collect();
+    if !missing.is_empty() {
+        println!(
+            "  ⚠ Missing scope {}: PRs in private repos will not be found",
+            missing.join(", ")
+        );
+        println!("  Run: gh auth refresh -s {}", missing.join(","));
+    }
+}
+
+fn print_rate_limit(rate_limit: Option<&github::RateLimit>) {
+    let Some(rate_limit) = rate_limit else {
+        println!("  GraphQL rate limit: unknown (gh api rate_limit failed)");
+        return;
+    };
+    println!(
+        "  GraphQL rate limit: {}/{} remaining, resets {}",
+        rate_limit.remaining,
+        rate_limit.limit,
+        rate_limit.reset.format("%Y-%m-%d %H:%M UTC")
+    );
+    if rate_limit.is_low() {
+        println!(
+            "  ⚠ Rate limit nearly used up; queries may fail with \"GraphQL query failed\" until it resets"
+        );
+    }
+}
+
+/// File name each shell expects for gh-log's completion script.
+fn completion_file_name(shell: Shell) -> &'static str {
+    match shell {
+        Shell::Zsh => "_gh-log",
+        Shell::Fish => "gh-log.fish",
+        Shell::PowerShell => "_gh-log.ps1",
+        Shell::Elvish => "gh-log.elv",
+        _ => "gh-log",
+    }
+}
+
+/// Per-user completions directory for `shell`, matching the setups in `completions --help`.
+/// PowerShell and Elvish load completions from a profile script, so there is no directory to
+/// drop a file into.
+fn default_completion_dir(shell: Shell) -> Option<std::path::PathBuf> {
+    let home = directories::BaseDirs::new()?.home_dir().to_path_buf();
+    match shell {
+        Shell::Bash => Some(home.join(".local/share/bash-completion/completions")),
+        Shell::Zsh => Some(home.join(".zsh/completions")),
+        Shell::Fish => Some(home.join(".config/fish/completions")),
+        _ => None,
+    }
+}
+
+fn run_completions_install(shell: Shell, dir: Option<std::path::PathBuf>) -> anyhow::Result<()> {
+    let mut cmd = Cli::command();
+    let Some(dir) = dir.or_else(|| default_completion_dir(shell)) else {
+        eprintln!(
+            "No standard completions directory for {}; printing the script instead (see `gh-log completions --help`).",
+            shell
+        );
+        generate(shell, &mut cmd, "gh-log", &mut io::stdout());
+        return Ok(());
+    };
+
+    let path = dir.join(completion_file_name(shell));
+    std::fs::create_dir_all(&dir).map_err(|source| gh_log::Error::Io {
+        context: format!("Failed to create {}", dir.display()),
+        source,
+    })?;
+    let mut file = std::fs::File::create(&path).map_err(|source| gh_log::Error::Io {
+        context: format!("Failed to write {}", path.display()),
+        source,
+    })?;
+    generate(shell, &mut cmd, "gh-log", &mut file);
+
+    println!("Installed {} completions to {}", shell, path.display());
+    if shell == Shell::Zsh {
+        println!(
+            "Make sure {} is in your $fpath before compinit, then restart your shell.",
+            dir.display()
+        );
+    } else {
+        println!("Restart your shell for the completions to take effect.");
+    }
+    Ok(())
+}
+
 fn run_config() -> anyhow::Result<()> {
     match directories::ProjectDirs::from("", "", "gh-log") {
         Some(dirs) => {
@@ -489,36 +2397,549 @@ fn run_config() -> anyhow::Result<()> {
     Ok(())
 }
 
-fn main() -> anyhow::Result<()> {
-    let cli = Cli::parse();
+fn main() {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        // --help and --version also arrive here; let clap print them and exit 0.
+        Err(err) if !err.use_stderr() => err.exit(),
+        Err(err) => {
+            let _ = err.print();
+            std::process::exit(exit_code::USAGE);
+        }
+    };
+
+    if let Err(err) = run(cli) {
+        eprintln!("Error: {:?}", err);
+        let code = err
+            .downcast_ref::<gh_log::Error>()
+            .map_or(exit_code::FAILURE, gh_log::Error::exit_code);
+        std::process::exit(code);
+    }
+}
 
+fn run(cli: Cli) -> anyhow::Result<()> {
+    let color = cli.color.enabled();
     match cli.command {
-        Commands::View { month, force } => {
-            let month = month.unwrap_or_else(|| chrono::Utc::now().format("%Y-%m").to_string());
-            run_view_mode(&month, force)
+        Commands::View {
+            month,
+            range,
+            force,
+            offline,
+            by,
+            include_bots,
+            filters,
+            scope,
+            no_reviewed,
+            reviewed,
+            sort_repos,
+            sort_order,
+            compact,
+            limit,
+            months,
+            no_hyperlinks,
+            save_config,
+        } => {
+            let period = if range.is_some() {
+                Period::Window
+            } else {
+                Period::Month
+            };
+            let mut cfg = load_config(include_bots, &filters, cli.tz)?;
+            let month = range
+                .or(month)
+                .unwrap_or_else(|| cfg.defaults.month(chrono::Utc::now().date_naive()));
+            let flags = config::DefaultsConfig {
+                by,
+                sort_repos,
+                sort_order,
+                ..Default::default()
+            };
+            let settings = config::SavedSettings {
+                state: filters.state,
+                timezone: cli.tz,
+            };
+            let defaults = resolve_defaults(&mut cfg, flags, settings, save_config)?;
+            let gh = github::resolve_gh_binary(cfg.gh.binary.as_deref());
+            let opts = FetchOptions {
+                date_field: defaults.by.unwrap_or_default(),
+                use_cache: !force,
+                fetch_reviewed: !no_reviewed,
+                progress: true,
+                reviewed,
+                gh: &gh,
+                review_limit: review_limit(&cfg),
+                offline,
+                author: scope.author.as_deref(),
+                org: scope.org.as_deref(),
+                pr_limit: limit.map(|n| n as usize),
+            };
+            let repo_order = RepoOrder {
+                key: defaults.sort_repos.unwrap_or_default(),
+                order: defaults.sort_order,
+            };
+            run_view_mode(
+                &month,
+                opts,
+                cfg,
+                compact,
+                !no_hyperlinks,
+                repo_order,
+                period,
+                months,
+            )
         }
         Commands::Print {
             month,
+            last_weeks,
+            range,
+            quarter,
+            year,
             force,
+            offline,
+            by,
+            include_bots,
+            filters,
+            scope,
+            no_reviewed,
+            reviewed,
+            sort_repos,
+            sort_order,
+            sort,
+            desc,
+            pr,
+            author_file,
+            also_merged,
+            all_cached,
+            fail_on_empty,
+            limit,
+            group_by,
+            max_body_lines,
             json,
             csv,
+            markdown,
+            html,
+            format,
+            width,
+            postprocess,
+            no_hyperlinks,
+            save_config,
+        } => {
+            let now = chrono::Utc::now();
+            let mut cfg = load_config(include_bots, &filters, cli.tz)?;
+            // These only shape the text listing, so they pick text over a `[defaults] format`.
+            let text_only = group_by.is_some() || max_body_lines.is_some() || no_hyperlinks;
+            // The shorthands are mutually exclusive with --format, so at most one of these is set.
+            let format = [
+                (json, PrintFormat::Json),
+                (csv, PrintFormat::Csv),
+                (markdown, PrintFormat::Markdown),
+                (html, PrintFormat::Html),
+            ]
+            .into_iter()
+            .find_map(|(set, format)| set.then_some(format))
+            .or(format);
+            let explicit_format = format.is_some();
+            let flags = config::DefaultsConfig {
+                by,
+                group_by,
+                sort_repos,
+                sort_order,
+                format,
+                ..Default::default()
+            };
+            let settings = config::SavedSettings {
+                state: filters.state,
+                timezone: cli.tz,
+            };
+            let defaults = resolve_defaults(&mut cfg, flags, settings, save_config)?;
+            // The window string doubles as the search range and the cache key, so each end date
+            // and N, quarter and year get their own cache entry.
+            let (period, month) = match (last_weeks, range.or(quarter), year) {
+                (Some(weeks), _, _) => (
+                    Period::Window,
+                    data::trailing_weeks(now.date_naive(), weeks),
+                ),
+                (None, Some(range), _) => (Period::Window, range),
+                (None, None, Some(year)) => (Period::Year, year),
+                (None, None, None) => (
+                    Period::Month,
+                    month.unwrap_or_else(|| defaults.month(now.date_naive())),
+                ),
+            };
+            let gh = github::resolve_gh_binary(cfg.gh.binary.as_deref());
+            let opts = FetchOptions {
+                date_field: defaults.by.unwrap_or_default(),
+                use_cache: !force,
+                fetch_reviewed: !no_reviewed,
+                progress: true,
+                reviewed,
+                gh: &gh,
+                review_limit: review_limit(&cfg),
+                offline,
+                author: scope.author.as_deref(),
+                org: scope.org.as_deref(),
+                pr_limit: limit.map(|n| n as usize),
+            };
+            let print_format = if text_only && !explicit_format {
+                Some(PrintFormat::Text)
+            } else {
+                defaults.format
+            };
+            let format = match (postprocess, print_format) {
+                (Some(command), _) => OutputFormat::Postprocess(command),
+                (None, Some(PrintFormat::Json)) => OutputFormat::Json,
+                (None, Some(PrintFormat::Csv)) => OutputFormat::Csv,
+                (None, Some(PrintFormat::Markdown)) => OutputFormat::Markdown,
+                (None, Some(PrintFormat::Html)) => OutputFormat::Html,
+                (None, Some(PrintFormat::Table)) => OutputFormat::Table(width.map(usize::from)),
+                (None, Some(PrintFormat::Text) | None) => OutputFormat::Raw {
+                    group_by: defaults.group_by.unwrap_or_default(),
+                    max_body_lines: max_body_lines.map(|n| n as usize),
+                    hyperlinks: !no_hyperlinks,
+                },
+            };
+            if !pr.is_empty() {
+                return run_print_prs(&month, opts, &pr, &cfg);
+            }
+            let scope = match author_file {
+                Some(path) => {
+                    let contents = std::fs::read_to_string(&path).with_context(|| {
+                        format!("Failed to read author file {}", path.display())
+                    })?;
+                    ReportScope::Team(
+                        parse_author_file(&contents)
+                            .with_context(|| format!("Invalid author file {}", path.display()))?,
+                    )
+                }
+                None if also_merged => ReportScope::AlsoMerged,
+                None if all_cached => {
+                    let months =
+                        cache::Cache::default()?.cached_months(|month| opts.cache_key(month))?;
+                    if months.is_empty() {
+                        anyhow::bail!("No cached months to roll up; run gh-log prefetch first");
+                    }
+                    ReportScope::AllCached(months)
+                }
+                None => ReportScope::Own,
+            };
+            // Name the roll-up after the months it covers.
+            let month = match &scope {
+                ReportScope::AllCached(months) if months.len() > 1 => {
+                    format!("{}..{}", months[0], months[months.len() - 1])
+                }
+                ReportScope::AllCached(months) => months[0].clone(),
+                _ => month,
+            };
+            let repo_order = RepoOrder {
+                key: defaults.sort_repos.unwrap_or_default(),
+                order: defaults.sort_order,
+            };
+            let pr_order = sort.map(|key| PrOrder {
+                key,
+                descending: desc,
+            });
+            run_print_mode(
+                &month,
+                opts,
+                format,
+                cfg,
+                fail_on_empty,
+                period,
+                repo_order,
+                pr_order,
+                &scope,
+                color,
+            )
+        }
+        Commands::Digest {
+            month,
+            force,
+            offline,
+            by,
         } => {
-            let month = month.unwrap_or_else(|| chrono::Utc::now().format("%Y-%m").to_string());
-            let format = if json {
-                OutputFormat::Json
-            } else if csv {
-                OutputFormat::Csv
+            let cfg = load_config(false, &FilterArgs::default(), cli.tz)?;
+            let month =
+                month.unwrap_or_else(|| cfg.defaults.month(chrono::Utc::now().date_naive()));
+            let by = by.or(cfg.defaults.by).unwrap_or_default();
+            let gh = github::resolve_gh_binary(cfg.gh.binary.as_deref());
+            let opts = FetchOptions {
+                date_field: by,
+                use_cache: !force,
+                fetch_reviewed: true,
+                progress: true,
+                reviewed: false,
+                gh: &gh,
+                review_limit: review_limit(&cfg),
+                offline,
+                author: None,
+                org: None,
+                pr_limit: None,
+            };
+            let snapshot = get_data_with_cache(&month, opts)?.snapshot;
+            let data = build_report(&month, snapshot, by, &cfg, Period::Month);
+            println!("{}", view::digest(&data, &month));
+            Ok(())
+        }
+        Commands::Compare {
+            a,
+            b,
+            json,
+            force,
+            offline,
+            by,
+        } => {
+            let cfg = load_config(false, &FilterArgs::default(), cli.tz)?;
+            let by = by.or(cfg.defaults.by).unwrap_or_default();
+            let gh = github::resolve_gh_binary(cfg.gh.binary.as_deref());
+            let opts = FetchOptions {
+                date_field: by,
+                use_cache: !force,
+                fetch_reviewed: true,
+                progress: true,
+                reviewed: false,
+                gh: &gh,
+                review_limit: review_limit(&cfg),
+                offline,
+                author: None,
+                org: None,
+                pr_limit: None,
+            };
+            let a_snapshot = get_data_with_cache(&a, opts)?.snapshot;
+            let b_snapshot = get_data_with_cache(&b, opts)?.snapshot;
+            let a_data = build_report(&a, a_snapshot, by, &cfg, Period::Month);
+            let b_data = build_report(&b, b_snapshot, by, &cfg, Period::Month);
+            let comparison = data::compare_months(&a_data, &b_data);
+            if json {
+                println!("{}", view::comparison_json(&comparison, &a, &b)?);
             } else {
-                OutputFormat::Raw
+                print!("{}", view::comparison_string(&comparison, &a, &b));
+            }
+            Ok(())
+        }
+        Commands::Prefetch {
+            from,
+            to,
+            months,
+            by,
+            jobs,
+        } => {
+            let to = to.unwrap_or_else(|| chrono::Utc::now().format("%Y-%m").to_string());
+            let from = match from {
+                Some(from) => from,
+                None => {
+                    let count = months.unwrap_or(3);
+                    let end = chrono::NaiveDate::parse_from_str(&format!("{}-01", to), "%Y-%m-%d")
+                        .expect("--to is validated as YYYY-MM");
+                    end.checked_sub_months(chrono::Months::new(count - 1))
+                        .with_context(|| format!("--months {} reaches too far back", count))?
+                        .format("%Y-%m")
+                        .to_string()
+                }
+            };
+            let range = month_range(&from, &to);
+            if range.is_empty() {
+                return Err(gh_log::Error::ReversedMonths { from, to }.into());
+            }
+            let cfg = load_config(false, &FilterArgs::default(), cli.tz)?;
+            let gh = github::resolve_gh_binary(cfg.gh.binary.as_deref());
+            run_prefetch(&range, by, &gh, review_limit(&cfg), jobs as usize)
+        }
+        Commands::Raw { query, query_file } => {
+            let query = match query_file {
+                Some(path) => std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read query file {}", path.display()))?,
+                None => query.expect("clap requires QUERY without --query-file"),
             };
-            run_print_mode(&month, force, format)
+            let cfg = load_config(false, &FilterArgs::default(), cli.tz)?;
+            let gh = github::resolve_gh_binary(cfg.gh.binary.as_deref());
+            run_raw(&query, &gh)
+        }
+        Commands::Doctor { json, by_size } => run_doctor(json, by_size),
+        Commands::Version { verbose } => {
+            run_version(verbose);
+            Ok(())
         }
-        Commands::Doctor => run_doctor(),
         Commands::Config => run_config(),
-        Commands::Completions { shell } => {
+        Commands::Completions {
+            shell,
+            install: false,
+            ..
+        } => {
             let mut cmd = Cli::command();
             generate(shell, &mut cmd, "gh-log", &mut io::stdout());
             Ok(())
         }
+        Commands::Completions {
+            shell,
+            install: true,
+            dir,
+        } => run_completions_install(shell, dir),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use tempfile::TempDir;
+
+    /// Answers every query with `reviewed` and no PRs, recording each call the way the library's
+    /// `github::mock::MockClient` does; that one is only built for the library's own tests.
+    #[derive(Default)]
+    struct MockClient {
+        reviewed: github::ReviewedPrs,
+        /// Reported for every search, as if a `--limit` stopped it with PRs left.
+        has_next_page: bool,
+        calls: RefCell<Vec<String>>,
+    }
+
+    impl GithubClient for MockClient {
+        fn fetch_prs_by(
+            &self,
+            login: &str,
+            month: &str,
+            _date_field: DateField,
+        ) -> gh_log::Result<github::PrSearch> {
+            self.calls
+                .borrow_mut()
+                .push(format!("fetch_prs_by {} {}", login, month));
+            Ok(github::PrSearch {
+                prs: Vec::new(),
+                has_next_page: self.has_next_page,
+            })
+        }
+
+        fn fetch_reviewed_prs_by(
+            &self,
+            login: &str,
+            month: &str,
+            _date_field: DateField,
+        ) -> gh_log::Result<github::ReviewedPrs> {
+            self.calls
+                .borrow_mut()
+                .push(format!("fetch_reviewed_prs_by {} {}", login, month));
+            Ok(self.reviewed.clone())
+        }
+
+        fn fetch_reviewed_pr_details_by(
+            &self,
+            login: &str,
+            month: &str,
+            _date_field: DateField,
+        ) -> gh_log::Result<github::PrSearch> {
+            self.calls
+                .borrow_mut()
+                .push(format!("fetch_reviewed_pr_details_by {} {}", login, month));
+            Ok(github::PrSearch {
+                prs: Vec::new(),
+                has_next_page: self.has_next_page,
+            })
+        }
+    }
+
+    fn fetch_options() -> FetchOptions<'static> {
+        FetchOptions {
+            date_field: DateField::Created,
+            use_cache: true,
+            fetch_reviewed: true,
+            progress: false,
+            reviewed: false,
+            gh: Path::new("/nonexistent/gh"),
+            review_limit: github::DEFAULT_REVIEW_FETCH_LIMIT,
+            offline: false,
+            author: None,
+            org: None,
+            pr_limit: None,
+        }
+    }
+
+    #[test]
+    fn test_get_data_with_client_caches_a_fetch_and_reuses_it() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = cache::Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        let opts = fetch_options();
+        let client = MockClient {
+            reviewed: github::ReviewedPrs {
+                total: 3,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let Fetched {
+            snapshot, source, ..
+        } = get_data_with_client("2025-03", opts, &client, &cache).unwrap();
+        assert_eq!(source, cache::DataSource::Fresh);
+        assert_eq!(snapshot.reviewed_count, Some(3));
+        assert_eq!(
+            *client.calls.borrow(),
+            [
+                "fetch_prs_by @me 2025-03",
+                "fetch_reviewed_prs_by @me 2025-03"
+            ]
+        );
+        let saved = cache.load_any(&opts.cache_key("2025-03")).unwrap().unwrap();
+        assert_eq!(saved.reviewed_count, Some(3));
+
+        // The second run is served from the cache, and the lazy `gh` client is never started.
+        let lazy = LazyClient::new(opts);
+        let Fetched {
+            snapshot, source, ..
+        } = get_data_with_client("2025-03", opts, &lazy, &cache).unwrap();
+        assert!(matches!(source, cache::DataSource::Cache(_)));
+        assert_eq!(snapshot.reviewed_count, Some(3));
+        assert!(lazy.client.get().is_none());
+        assert_eq!(client.calls.borrow().len(), 2);
+    }
+
+    #[test]
+    fn test_limit_is_reached_only_when_github_has_more() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = cache::Cache::new(temp_dir.path().to_path_buf(), 100).unwrap();
+        let opts = FetchOptions {
+            pr_limit: Some(20),
+            ..fetch_options()
+        };
+        for has_next_page in [false, true] {
+            let client = MockClient {
+                has_next_page,
+                ..Default::default()
+            };
+            let fetched = get_data_with_client("2025-03", opts, &client, &cache).unwrap();
+            assert_eq!(fetched.pr_limit, has_next_page.then_some(20));
+        }
+        // A partial search is never cached.
+        assert!(
+            cache
+                .load_any(&opts.cache_key("2025-03"))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_trend_reads_the_authors_own_months() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = cache::Cache::new(temp_dir.path().join("cache"), 100).unwrap();
+        let cfg = config::Config::new(temp_dir.path().join("config")).unwrap();
+        let snapshot = |reviewed| cache::CachedData {
+            version: cache::CACHE_VERSION,
+            month: "2025-03".to_string(),
+            timestamp: chrono::Utc::now(),
+            prs: Vec::new(),
+            reviewed_count: Some(reviewed),
+            reviewed_by_repo: None,
+            review_turnaround_secs: None,
+        };
+        cache.save("2025-03", &snapshot(1)).unwrap();
+        cache.save("2025-03.author-octocat", &snapshot(7)).unwrap();
+
+        let opts = FetchOptions {
+            author: Some("octocat"),
+            ..fetch_options()
+        };
+        let trend = load_trend(6, opts, &cfg, &cache).unwrap();
+        assert_eq!(trend.len(), 1);
+        assert_eq!(trend[0].reviewed_count, Some(7));
     }
 }
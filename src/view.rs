@@ -7,31 +7,57 @@ use crate::data;
 use chrono::{DateTime, Datelike, Duration, Utc};
 use ratatui::{
     Frame, Terminal,
-    backend::CrosstermBackend,
+    backend::{Backend, CrosstermBackend},
+    buffer::{Buffer, Cell},
     crossterm::{
-        event::{self, Event, KeyCode, KeyEventKind},
+        event::{
+            self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind,
+            MouseEvent, MouseEventKind,
+        },
         execute,
         terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
     },
-    layout::{Constraint, Layout, Margin, Rect},
-    style::{Color, Style},
+    layout::{Constraint, Flex, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+    widgets::{
+        Bar, BarChart, BarGroup, Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation,
+        ScrollbarState,
+    },
 };
+use std::collections::HashMap;
 use std::io::{Result, stdout};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use crate::config::{Config, SizeConfig};
-use crate::data::{MonthData, PRDetail, PRSize};
+use crate::cache::DataSource;
+use crate::config::{Config, SizeConfig, Theme};
+use crate::data::{
+    GroupBy, MonthData, PRDetail, PRSize, format_add_delete_ratio, format_line_totals, size_label,
+};
+use crate::github::DateField;
 
 const HORIZONTAL_MARGIN: u16 = 2;
 const SCROLLBAR_SPACE: u16 = 1;
 const SECTION_SPACING: usize = 1;
+/// Room for a PR's `+added/-deleted` column; longer values just run past it.
+const CHURN_WIDTH: usize = 11;
+/// Room for the optional changed-files column, e.g. ` 12f`.
+const FILES_WIDTH: usize = 4;
+/// Below this size the header and tables overlap, so a resize prompt is shown instead.
+const MIN_TERMINAL_WIDTH: u16 = 60;
+const MIN_TERMINAL_HEIGHT: u16 = 15;
+/// Wide enough for a `YYYY-MM` label under each Trend bar.
+const TREND_BAR_WIDTH: u16 = 7;
 
 #[derive(Clone, Copy)]
 enum View {
     Summary,
     Detail(DetailMode),
     Tail,
+    /// Totals and lead time of recent cached months, side by side.
+    Trend,
+    /// Each reviewer with the PRs they reviewed.
+    Reviewers,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -49,10 +75,44 @@ impl DetailMode {
     }
 }
 
+/// What Enter does on a selectable row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RowTarget {
+    /// Open this PR URL in the browser.
+    Pr(String),
+    /// Show or hide the PRs this reviewer reviewed.
+    Reviewer(String),
+}
+
+/// Where one PR (or Reviewers header) sits in a list view's lines, so it can be highlighted and
+/// acted on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SelectableRow {
+    line: usize,
+    height: usize,
+    target: RowTarget,
+}
+
+impl SelectableRow {
+    /// The PR this row opens, if it is a PR row.
+    fn url(&self) -> Option<&str> {
+        match &self.target {
+            RowTarget::Pr(url) => Some(url),
+            RowTarget::Reviewer(_) => None,
+        }
+    }
+}
+
 struct ScrollState {
     position: usize,
     content_height: usize,
     viewport_height: usize,
+    /// PR rows of the last rendered list view; empty in views without a cursor.
+    rows: Vec<SelectableRow>,
+    /// Index into `rows` of the highlighted PR.
+    selected: usize,
+    /// Screen area the list was last drawn in, to place PR links over it.
+    area: Rect,
 }
 
 impl ScrollState {
@@ -61,50 +121,116 @@ impl ScrollState {
             position: 0,
             content_height: 0,
             viewport_height: 0,
+            rows: Vec::new(),
+            selected: 0,
+            area: Rect::default(),
         }
     }
 
     fn reset(&mut self) {
         self.position = 0;
+        self.selected = 0;
     }
 
+    /// In a list view, move the cursor up one PR; otherwise scroll up one line.
     fn scroll_up(&mut self) {
-        self.position = self.position.saturating_sub(1);
+        if self.rows.is_empty() {
+            self.position = self.position.saturating_sub(1);
+            return;
+        }
+        self.selected = self.selected.saturating_sub(1);
+        self.reveal_selected();
     }
 
+    /// In a list view, move the cursor down one PR; otherwise scroll down one line.
     fn scroll_down(&mut self) {
-        let max = self.max_scroll();
-        if self.position < max {
-            self.position += 1;
+        if self.rows.is_empty() {
+            let max = self.max_scroll();
+            if self.position < max {
+                self.position += 1;
+            }
+            return;
         }
+        self.selected = (self.selected + 1).min(self.rows.len() - 1);
+        self.reveal_selected();
     }
 
     fn scroll_page_down(&mut self) {
         let half_page = self.viewport_height / 2;
         let max = self.max_scroll();
         self.position = self.position.saturating_add(half_page).min(max);
+        self.follow_position();
     }
 
     fn scroll_page_up(&mut self) {
         let half_page = self.viewport_height / 2;
         self.position = self.position.saturating_sub(half_page);
+        self.follow_position();
     }
 
     fn scroll_full_page_down(&mut self) {
         let max = self.max_scroll();
         self.position = self.position.saturating_add(self.viewport_height).min(max);
+        self.follow_position();
     }
 
     fn scroll_full_page_up(&mut self) {
         self.position = self.position.saturating_sub(self.viewport_height);
+        self.follow_position();
     }
 
     fn scroll_to_top(&mut self) {
         self.position = 0;
+        self.selected = 0;
     }
 
     fn scroll_to_bottom(&mut self) {
         self.position = self.max_scroll();
+        self.selected = self.rows.len().saturating_sub(1);
+    }
+
+    /// Replace the list rows after a render and keep the cursor on an existing row.
+    fn set_rows(&mut self, rows: Vec<SelectableRow>) {
+        self.rows = rows;
+        self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+    }
+
+    /// The highlighted PR, if the current view has a cursor.
+    fn selected_row(&self) -> Option<&SelectableRow> {
+        self.rows.get(self.selected)
+    }
+
+    /// Scroll just far enough that every line of the selected row is on screen.
+    fn reveal_selected(&mut self) {
+        let Some(row) = self.selected_row() else {
+            return;
+        };
+        let (line, end) = (row.line, row.line + row.height);
+        if line < self.position {
+            self.position = line;
+        } else if end > self.position + self.viewport_height {
+            self.position = end.saturating_sub(self.viewport_height).min(line);
+        }
+    }
+
+    /// After a page jump, move the cursor onto the screen if the jump left it behind.
+    fn follow_position(&mut self) {
+        let Some(row) = self.selected_row() else {
+            return;
+        };
+        let bottom = self.position + self.viewport_height;
+        if row.line < self.position {
+            if let Some(first) = self.rows.iter().position(|row| row.line >= self.position) {
+                self.selected = first;
+            }
+        } else if row.line + row.height > bottom
+            && let Some(last) = self
+                .rows
+                .iter()
+                .rposition(|row| row.line + row.height <= bottom)
+        {
+            self.selected = last;
+        }
     }
 
     fn max_scroll(&self) -> usize {
@@ -119,6 +245,40 @@ impl ScrollState {
         self.viewport_height = height;
     }
 
+    /// Keep the position valid after the content shrinks, e.g. when title wrapping is turned off.
+    fn clamp_position(&mut self) {
+        self.position = self.position.min(self.max_scroll());
+    }
+
+    /// 1-based first and last line on screen, or `(0, 0)` when there is no content.
+    fn visible_range(&self) -> (usize, usize) {
+        if self.content_height == 0 {
+            return (0, 0);
+        }
+        let last = (self.position + self.viewport_height).min(self.content_height);
+        (self.position + 1, last)
+    }
+
+    /// How far through the scrollable range the view is; 100 when everything fits on screen.
+    fn percent_scrolled(&self) -> usize {
+        match self.max_scroll() {
+            0 => 100,
+            max => self.position.min(max) * 100 / max,
+        }
+    }
+
+    /// Footer text such as "Line 21–40 of 120 (20%)".
+    fn position_label(&self) -> String {
+        let (first, last) = self.visible_range();
+        format!(
+            "Line {}–{} of {} ({}%)",
+            first,
+            last,
+            self.content_height,
+            self.percent_scrolled()
+        )
+    }
+
     fn as_scrollbar_state(&self) -> ScrollbarState {
         let scrollable_content = self.max_scroll().max(1);
         ScrollbarState::new(scrollable_content).position(self.position)
@@ -132,6 +292,8 @@ enum Msg {
     ShowSummary,
     ToggleDetail,
     ShowTail,
+    ShowTrend,
+    ShowReviewers,
     ScrollUp,
     ScrollDown,
     ScrollPageDown,
@@ -140,19 +302,103 @@ enum Msg {
     ScrollFullPageUp,
     ScrollToTop,
     ScrollToBottom,
+    ToggleWrap,
+    ToggleCompact,
+    ToggleFiles,
+    PrevMonth,
+    NextMonth,
+    OpenSelected,
+    StartSearch,
+    SearchInput(char),
+    SearchBackspace,
+    EndSearch,
+    ClearSearch,
+}
+
+/// Display toggles the render functions need, copied out of [`AppState`] each frame.
+#[derive(Debug, Clone, Copy, Default)]
+struct RenderOptions<'a> {
+    wrap: bool,
+    compact: bool,
+    files: bool,
+    /// Narrows the PR lists to matching PRs; see [`matching_prs`].
+    search: &'a str,
+    /// Reviewer whose PRs the Reviewers view shows.
+    expanded_reviewer: Option<&'a str>,
+    /// Colors from `[theme]`.
+    theme: Theme,
+    /// Make PR numbers in the lists OSC 8 links to GitHub (off with `--no-hyperlinks`).
+    hyperlinks: bool,
+}
+
+/// Column widths for PR list rows, shared by the Detail and Tail views.
+#[derive(Debug, Clone, Copy)]
+struct RowLayout {
+    repo_width: usize,
+    title_width: usize,
+    wrap: bool,
+    files: bool,
+}
+
+impl RowLayout {
+    /// Split what the fixed columns leave of `usable_width` between repo and title.
+    fn new(usable_width: usize, opts: RenderOptions<'_>) -> Self {
+        let mut fixed_width = 6 + 3 + 3 + 5 + 3 + 3 + 8 + 3 + 2 + 3 + CHURN_WIDTH;
+        if opts.files {
+            fixed_width += 3 + FILES_WIDTH;
+        }
+        let remaining = usable_width.saturating_sub(fixed_width).max(30);
+        let repo_width = (remaining / 3).max(10);
+        Self {
+            repo_width,
+            title_width: remaining.saturating_sub(repo_width).max(15),
+            wrap: opts.wrap,
+            files: opts.files,
+        }
+    }
 }
 
 /// Application state - consolidates all mutable state in one place
 struct AppState {
     current_view: View,
     scroll: ScrollState,
+    /// Wrap long PR titles onto continuation lines instead of truncating them.
+    wrap_titles: bool,
+    /// Single-line controls and header, leaving more rows for content on small screens.
+    compact: bool,
+    /// Show each PR's changed-file count as an extra list column.
+    show_files: bool,
+    /// Month being shown, `YYYY-MM` (or a day window from `--month`). The run loop reloads data
+    /// whenever this changes.
+    month: String,
+    /// Newest month `NextMonth` may move to; there is nothing to show past the current month.
+    latest_month: String,
+    /// Error from the last failed month load, shown in the controls bar.
+    status: Option<String>,
+    /// Case-insensitive text the Detail and Tail lists are narrowed to, matched against each
+    /// PR's title and repo; empty shows every PR.
+    search: String,
+    /// Keys are going into `search` rather than driving the view, after `/`.
+    searching: bool,
+    /// Reviewer expanded in the Reviewers view with Enter; the others show just their header.
+    expanded_reviewer: Option<String>,
 }
 
 impl AppState {
     fn new() -> Self {
+        let current_month = Utc::now().format("%Y-%m").to_string();
         Self {
             current_view: View::Summary,
             scroll: ScrollState::new(),
+            wrap_titles: false,
+            compact: false,
+            show_files: false,
+            month: current_month.clone(),
+            latest_month: current_month,
+            status: None,
+            search: String::new(),
+            searching: false,
+            expanded_reviewer: None,
         }
     }
 
@@ -160,6 +406,19 @@ impl AppState {
         self.current_view
     }
 
+    /// Display toggles without the search, which the run loop borrows separately.
+    fn render_options(&self) -> RenderOptions<'static> {
+        RenderOptions {
+            wrap: self.wrap_titles,
+            compact: self.compact,
+            files: self.show_files,
+            search: "",
+            expanded_reviewer: None,
+            theme: Theme::default(),
+            hyperlinks: false,
+        }
+    }
+
     fn scroll_mut(&mut self) -> &mut ScrollState {
         &mut self.scroll
     }
@@ -207,6 +466,17 @@ impl AppState {
 fn update(msg: Msg, mut state: AppState) -> AppState {
     match msg {
         Msg::Quit => state, // Should not be called, handled in run loop
+        Msg::OpenSelected => {
+            // Opening a PR in the browser is a side effect, handled in the run loop; a reviewer
+            // header expands or collapses in place.
+            if let Some(RowTarget::Reviewer(login)) =
+                state.scroll.selected_row().map(|row| row.target.clone())
+            {
+                state.expanded_reviewer =
+                    (state.expanded_reviewer.as_ref() != Some(&login)).then_some(login);
+            }
+            state
+        }
         Msg::ShowSummary => {
             state.set_view(View::Summary);
             state
@@ -223,6 +493,42 @@ fn update(msg: Msg, mut state: AppState) -> AppState {
             state.set_view(View::Tail);
             state
         }
+        Msg::ShowTrend => {
+            state.set_view(View::Trend);
+            state
+        }
+        Msg::ShowReviewers => {
+            state.set_view(View::Reviewers);
+            state
+        }
+        Msg::StartSearch => {
+            // Only the PR lists are filtered, so search from elsewhere lands in Tail.
+            if matches!(state.current_view(), View::Summary | View::Trend) {
+                state.set_view(View::Tail);
+            }
+            state.searching = true;
+            state
+        }
+        Msg::SearchInput(c) => {
+            state.search.push(c);
+            state.scroll.reset();
+            state
+        }
+        Msg::SearchBackspace => {
+            state.search.pop();
+            state.scroll.reset();
+            state
+        }
+        Msg::EndSearch => {
+            state.searching = false;
+            state
+        }
+        Msg::ClearSearch => {
+            state.searching = false;
+            state.search.clear();
+            state.scroll.reset();
+            state
+        }
         Msg::ScrollUp => {
             state.scroll_up();
             state
@@ -255,101 +561,395 @@ fn update(msg: Msg, mut state: AppState) -> AppState {
             state.scroll_to_bottom();
             state
         }
+        Msg::ToggleWrap => {
+            state.wrap_titles = !state.wrap_titles;
+            state
+        }
+        Msg::ToggleCompact => {
+            state.compact = !state.compact;
+            state
+        }
+        Msg::ToggleFiles => {
+            state.show_files = !state.show_files;
+            state
+        }
+        Msg::PrevMonth => {
+            if let Some(prev) = shift_month(&state.month, -1) {
+                state.month = prev;
+                state.scroll.reset();
+            }
+            state
+        }
+        Msg::NextMonth => {
+            if let Some(next) = shift_month(&state.month, 1)
+                && next <= state.latest_month
+            {
+                state.month = next;
+                state.scroll.reset();
+            }
+            state
+        }
     }
 }
 
-/// Handle keyboard input and convert to messages
-fn handle_input() -> anyhow::Result<Option<Msg>> {
-    use crossterm::event::KeyModifiers;
+/// Move a `YYYY-MM` month by `delta` months, or `None` if `month` does not parse. A day window
+/// steps to the whole neighbouring month.
+fn shift_month(month: &str, delta: i32) -> Option<String> {
+    let (year, month) = month.get(..7)?.split_once('-')?;
+    let index = year.parse::<i32>().ok()? * 12 + month.parse::<i32>().ok()? - 1 + delta;
+    Some(format!(
+        "{:04}-{:02}",
+        index.div_euclid(12),
+        index.rem_euclid(12) + 1
+    ))
+}
 
-    if event::poll(std::time::Duration::from_millis(100))?
-        && let Event::Key(key) = event::read()?
-        && key.kind == KeyEventKind::Press
-    {
-        let msg = match (key.code, key.modifiers) {
-            // Quit
-            (KeyCode::Char('q'), _) | (KeyCode::Esc, _) => Some(Msg::Quit),
+/// Wait briefly for a key press or mouse wheel turn and convert it to a message with
+/// [`key_message`] or [`mouse_message`].
+fn handle_input(state: &AppState) -> anyhow::Result<Option<Msg>> {
+    if !event::poll(std::time::Duration::from_millis(100))? {
+        return Ok(None);
+    }
+    Ok(match event::read()? {
+        Event::Key(key) if key.kind == KeyEventKind::Press => key_message(state, key),
+        Event::Mouse(mouse) => mouse_message(mouse),
+        _ => None,
+    })
+}
 
-            // Views
-            (KeyCode::Char('s'), _) => Some(Msg::ShowSummary),
-            (KeyCode::Char('d'), KeyModifiers::NONE) => Some(Msg::ToggleDetail),
-            (KeyCode::Char('t'), _) => Some(Msg::ShowTail),
+/// The wheel scrolls like the arrow keys; clicks and drags are ignored.
+fn mouse_message(mouse: MouseEvent) -> Option<Msg> {
+    match mouse.kind {
+        MouseEventKind::ScrollUp => Some(Msg::ScrollUp),
+        MouseEventKind::ScrollDown => Some(Msg::ScrollDown),
+        _ => None,
+    }
+}
+
+/// The message a key press maps to. While a search is being typed every key edits it; Esc clears
+/// an active search before it quits.
+fn key_message(state: &AppState, key: crossterm::event::KeyEvent) -> Option<Msg> {
+    use crossterm::event::KeyModifiers;
 
-            // Line by line
-            (KeyCode::Up, _) | (KeyCode::Char('k'), _) => Some(Msg::ScrollUp),
-            (KeyCode::Down, _) | (KeyCode::Char('j'), _) => Some(Msg::ScrollDown),
+    if state.searching {
+        return match key.code {
+            KeyCode::Enter => Some(Msg::EndSearch),
+            KeyCode::Esc => Some(Msg::ClearSearch),
+            KeyCode::Backspace => Some(Msg::SearchBackspace),
+            KeyCode::Char(c) => Some(Msg::SearchInput(c)),
+            _ => None,
+        };
+    }
+    match (key.code, key.modifiers) {
+        // Search
+        (KeyCode::Char('/'), _) => Some(Msg::StartSearch),
+        (KeyCode::Esc, _) if !state.search.is_empty() => Some(Msg::ClearSearch),
+
+        // Quit
+        (KeyCode::Char('q'), _) | (KeyCode::Esc, _) => Some(Msg::Quit),
+
+        // Views
+        (KeyCode::Char('s'), _) => Some(Msg::ShowSummary),
+        (KeyCode::Char('d'), KeyModifiers::NONE) => Some(Msg::ToggleDetail),
+        (KeyCode::Char('t'), _) => Some(Msg::ShowTail),
+        (KeyCode::Char('T'), _) => Some(Msg::ShowTrend),
+        (KeyCode::Char('r'), _) => Some(Msg::ShowReviewers),
+        (KeyCode::Char('w'), _) => Some(Msg::ToggleWrap),
+        (KeyCode::Char('z'), _) => Some(Msg::ToggleCompact),
+        (KeyCode::Char('f'), KeyModifiers::NONE) => Some(Msg::ToggleFiles),
+        (KeyCode::Enter, _) => Some(Msg::OpenSelected),
+
+        // Months
+        (KeyCode::Char('['), _) | (KeyCode::Char('<'), _) => Some(Msg::PrevMonth),
+        (KeyCode::Char(']'), _) | (KeyCode::Char('>'), _) => Some(Msg::NextMonth),
+
+        // Line by line
+        (KeyCode::Up, _) | (KeyCode::Char('k'), _) => Some(Msg::ScrollUp),
+        (KeyCode::Down, _) | (KeyCode::Char('j'), _) => Some(Msg::ScrollDown),
+
+        // Half page navigation (Ctrl-D, Ctrl-U)
+        (KeyCode::Char('d'), KeyModifiers::CONTROL) => Some(Msg::ScrollPageDown),
+        (KeyCode::Char('u'), KeyModifiers::CONTROL) => Some(Msg::ScrollPageUp),
+
+        // Full page navigation (Ctrl-F, Ctrl-B, PageDown, PageUp)
+        (KeyCode::Char('f'), KeyModifiers::CONTROL) | (KeyCode::PageDown, _) => {
+            Some(Msg::ScrollFullPageDown)
+        }
+        (KeyCode::Char('b'), KeyModifiers::CONTROL) | (KeyCode::PageUp, _) => {
+            Some(Msg::ScrollFullPageUp)
+        }
 
-            // Half page navigation (Ctrl-D, Ctrl-U)
-            (KeyCode::Char('d'), KeyModifiers::CONTROL) => Some(Msg::ScrollPageDown),
-            (KeyCode::Char('u'), KeyModifiers::CONTROL) => Some(Msg::ScrollPageUp),
+        // Jump to top/bottom (g, G, Home, End)
+        (KeyCode::Char('g'), _) | (KeyCode::Home, _) => Some(Msg::ScrollToTop),
+        (KeyCode::Char('G'), _) | (KeyCode::End, _) => Some(Msg::ScrollToBottom),
 
-            // Full page navigation (Ctrl-F, Ctrl-B)
-            (KeyCode::Char('f'), KeyModifiers::CONTROL) => Some(Msg::ScrollFullPageDown),
-            (KeyCode::Char('b'), KeyModifiers::CONTROL) => Some(Msg::ScrollFullPageUp),
+        _ => None,
+    }
+}
 
-            // Jump to top/bottom (g, G)
-            (KeyCode::Char('g'), _) => Some(Msg::ScrollToTop),
-            (KeyCode::Char('G'), _) => Some(Msg::ScrollToBottom),
+/// Raw mode, the alternate screen and mouse capture while the TUI runs. Dropping the guard
+/// restores the terminal, so an error returned from the event loop or a panic in it still hands
+/// the shell back a usable terminal.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> Result<Self> {
+        // Leave the TUI before the panic message is printed, or it is lost with the alternate
+        // screen.
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            restore_terminal();
+            default_hook(info);
+        }));
+        enable_raw_mode()?;
+        let guard = TerminalGuard;
+        execute!(stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+        Ok(guard)
+    }
+}
 
-            _ => None,
-        };
-        return Ok(msg);
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore_terminal();
     }
-    Ok(None)
+}
+
+/// Undo [`TerminalGuard::enter`]. Best effort: by now there is nowhere to report a failure.
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(stdout(), DisableMouseCapture, LeaveAlternateScreen);
 }
 
 /// Run the interactive TUI for browsing pull request analytics.
 ///
+/// `compact` starts in the single-line layout (`z` toggles it). `source` drives the data-age note
+/// in the controls bar. `trend` holds the recent months charted by the Trend view (`T`), oldest
+/// first. `load` fetches another month when the
+/// user steps with `[`/`]`; if it fails, the current month stays on screen and the error is shown
+/// in the controls bar.
+///
+/// PR numbers in the Detail, Tail and Reviewers lists are OSC 8 links to the PR unless
+/// `hyperlinks` is off; terminals without OSC 8 support show them as plain text.
+///
 /// # Errors
 /// Returns an error if terminal initialization or rendering fails.
-pub fn run(month_data: MonthData, cfg: Config) -> anyhow::Result<()> {
-    enable_raw_mode()?;
-    execute!(stdout(), EnterAlternateScreen)?;
-
+#[allow(clippy::too_many_arguments)]
+pub fn run<F>(
+    month: &str,
+    mut month_data: MonthData,
+    cfg: Config,
+    mut source: DataSource,
+    compact: bool,
+    hyperlinks: bool,
+    trend: Vec<MonthData>,
+    mut load: F,
+) -> anyhow::Result<()>
+where
+    F: FnMut(&str) -> anyhow::Result<(MonthData, DataSource)>,
+{
+    let _guard = TerminalGuard::enter()?;
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
     let mut state = AppState::new();
+    state.month = month.to_string();
+    state.compact = compact;
+
+    let mut loaded_month = state.month.clone();
+    let theme = cfg.theme.theme();
 
     loop {
-        match state.current_view() {
-            View::Summary => render_summary(&mut terminal, &month_data, state.scroll_mut())?,
-            View::Detail(mode) => {
-                render_detail(&mut terminal, &month_data, state.scroll_mut(), &cfg, mode)?
+        let search = state.search.clone();
+        let expanded_reviewer = state.expanded_reviewer.clone();
+        let opts = RenderOptions {
+            search: &search,
+            expanded_reviewer: expanded_reviewer.as_deref(),
+            theme,
+            hyperlinks,
+            ..state.render_options()
+        };
+        let note = if state.searching || !state.search.is_empty() {
+            search_note(&state.search, state.searching, &theme)
+        } else {
+            controls_note(source, state.status.as_deref(), &theme)
+        };
+        let size = terminal.size()?;
+        if !fits_terminal(size.width, size.height) {
+            // Redrawn every poll tick, so the prompt goes away as soon as the window is large enough.
+            render_too_small(&mut terminal, size.width, size.height, &theme)?;
+        } else {
+            match state.current_view() {
+                View::Summary => render_summary(
+                    &mut terminal,
+                    &month_data,
+                    state.scroll_mut(),
+                    &cfg,
+                    opts,
+                    &note,
+                )?,
+                View::Detail(mode) => render_detail(
+                    &mut terminal,
+                    &month_data,
+                    state.scroll_mut(),
+                    &cfg,
+                    mode,
+                    opts,
+                    &note,
+                )?,
+                View::Tail => render_tail(
+                    &mut terminal,
+                    &month_data,
+                    state.scroll_mut(),
+                    &cfg,
+                    opts,
+                    &note,
+                )?,
+                View::Reviewers => render_reviewers(
+                    &mut terminal,
+                    &month_data,
+                    state.scroll_mut(),
+                    &cfg,
+                    opts,
+                    &note,
+                )?,
+                View::Trend => {
+                    render_trend(&mut terminal, &trend, state.scroll_mut(), opts, &note)?
+                }
             }
-            View::Tail => render_tail(&mut terminal, &month_data, state.scroll_mut(), &cfg)?,
         }
 
-        if let Some(msg) = handle_input()? {
+        if let Some(msg) = handle_input(&state)? {
             if msg == Msg::Quit {
                 break;
             }
+            if msg == Msg::OpenSelected
+                && let Some(url) = state.scroll.selected_row().and_then(SelectableRow::url)
+            {
+                if let Err(err) = open::that_detached(url) {
+                    state.status = Some(format!("Failed to open {}: {}", url, err));
+                }
+                continue;
+            }
             state = update(msg, state);
+
+            if state.month != loaded_month {
+                render_loading(&mut terminal, &state.month, &theme)?;
+                match load(&state.month) {
+                    Ok((data, new_source)) => {
+                        month_data = data;
+                        source = new_source;
+                        loaded_month = state.month.clone();
+                        state.status = None;
+                    }
+                    Err(err) => {
+                        state.status = Some(format!("Failed to load {}: {}", state.month, err));
+                        state.month = loaded_month.clone();
+                    }
+                }
+            }
         }
     }
 
-    disable_raw_mode()?;
-    execute!(stdout(), LeaveAlternateScreen)?;
+    Ok(())
+}
+
+fn render_loading(
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+    month: &str,
+    theme: &Theme,
+) -> Result<()> {
+    terminal.draw(|frame| {
+        let text = Line::from(Span::styled(
+            format!("Loading {}…", month),
+            Style::default().fg(theme.highlight),
+        ));
+        let area = frame.area().inner(Margin {
+            horizontal: HORIZONTAL_MARGIN,
+            vertical: 1,
+        });
+        frame.render_widget(Paragraph::new(text), area);
+    })?;
+
+    Ok(())
+}
+
+fn fits_terminal(width: u16, height: u16) -> bool {
+    width >= MIN_TERMINAL_WIDTH && height >= MIN_TERMINAL_HEIGHT
+}
+
+fn render_too_small(
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+    width: u16,
+    height: u16,
+    theme: &Theme,
+) -> Result<()> {
+    terminal.draw(|frame| {
+        let text = vec![
+            Line::from(Span::styled(
+                "Terminal too small",
+                Style::default().fg(theme.highlight).bold(),
+            )),
+            Line::from(format!(
+                "{}×{}, need at least {}×{}",
+                width, height, MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT
+            )),
+            Line::from("Resize the window, or press q to quit"),
+        ];
+        let widget = Paragraph::new(text).wrap(ratatui::widgets::Wrap { trim: true });
+        frame.render_widget(widget, frame.area());
+    })?;
 
     Ok(())
 }
 
+/// Trailing note for the controls bar: the last load error if there was one, else the data age.
+fn controls_note(source: DataSource, status: Option<&str>, theme: &Theme) -> Span<'static> {
+    match status {
+        Some(status) => Span::styled(format!("  {}", status), Style::default().fg(theme.alert)),
+        None => Span::styled(
+            format!("  {}", source.describe(Utc::now())),
+            Style::default().fg(theme.muted),
+        ),
+    }
+}
+
+/// Controls-bar note showing the search, with a cursor while it is being typed.
+fn search_note(search: &str, editing: bool, theme: &Theme) -> Span<'static> {
+    let cursor = if editing { "▏" } else { "" };
+    Span::styled(
+        format!("  /{}{}", search, cursor),
+        Style::default().fg(theme.highlight),
+    )
+}
+
 fn render_summary(
     terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
     data: &MonthData,
     scroll_state: &mut ScrollState,
+    cfg: &Config,
+    opts: RenderOptions,
+    note: &Span<'static>,
 ) -> Result<()> {
     terminal.draw(|frame| {
-        let [controls_area, summary_area, content_area] = Layout::vertical([
-            Constraint::Length(2),
-            Constraint::Length(3),
-            Constraint::Min(0),
-        ])
-        .areas(frame.area());
-
-        render_controls(frame, controls_area, View::Summary);
-        render_summary_header(frame, summary_area, data);
-
-        let lines = build_summary_content(data, content_area.width as usize);
-        render_scrollable_content(frame, content_area, lines, scroll_state);
+        let [controls_area, summary_area, content_area] = screen_layout(frame.area(), opts.compact);
+
+        render_controls(frame, controls_area, View::Summary, note, opts);
+        render_summary_header(frame, summary_area, data, cfg, opts);
+        if is_empty_month(data) {
+            render_empty_state(
+                frame,
+                content_area,
+                scroll_state,
+                &report_label(data),
+                &opts.theme,
+            );
+            return;
+        }
+
+        let lines = build_summary_content(data, cfg, content_area.width as usize, &opts.theme);
+        let content = ListContent {
+            lines,
+            rows: Vec::new(),
+        };
+        render_scrollable_content(frame, content_area, content, scroll_state, &opts.theme);
     })?;
 
     Ok(())
@@ -361,30 +961,37 @@ fn render_detail(
     scroll_state: &mut ScrollState,
     cfg: &Config,
     mode: DetailMode,
+    opts: RenderOptions,
+    note: &Span<'static>,
 ) -> Result<()> {
-    terminal.draw(|frame| {
-        let [controls_area, summary_area, content_area] = Layout::vertical([
-            Constraint::Length(2),
-            Constraint::Length(3),
-            Constraint::Min(0),
-        ])
-        .areas(frame.area());
-
-        render_controls(frame, controls_area, View::Detail(mode));
-        render_detail_header(frame, summary_area, data, mode);
+    let completed = terminal.draw(|frame| {
+        let [controls_area, summary_area, content_area] = screen_layout(frame.area(), opts.compact);
+
+        render_controls(frame, controls_area, View::Detail(mode), note, opts);
+        render_detail_header(frame, summary_area, data, mode, cfg, opts);
+        if is_empty_month(data) {
+            render_empty_state(
+                frame,
+                content_area,
+                scroll_state,
+                &report_label(data),
+                &opts.theme,
+            );
+            return;
+        }
 
-        let lines = match mode {
+        let content = match mode {
             DetailMode::ByWeek => {
-                build_detail_by_week_content(data, cfg, content_area.width as usize)
+                build_detail_by_week_content(data, cfg, content_area.width as usize, opts)
             }
             DetailMode::ByRepo => {
-                build_detail_by_repo_content(data, cfg, content_area.width as usize)
+                build_detail_by_repo_content(data, cfg, content_area.width as usize, opts)
             }
         };
-        render_scrollable_content(frame, content_area, lines, scroll_state);
+        render_scrollable_content(frame, content_area, content, scroll_state, &opts.theme);
     })?;
-
-    Ok(())
+    let links = pr_link_cells(completed.buffer, scroll_state, opts.hyperlinks);
+    draw_cells(terminal, &links)
 }
 
 fn render_tail(
@@ -392,26 +999,249 @@ fn render_tail(
     data: &MonthData,
     scroll_state: &mut ScrollState,
     cfg: &Config,
+    opts: RenderOptions,
+    note: &Span<'static>,
+) -> Result<()> {
+    let completed = terminal.draw(|frame| {
+        let [controls_area, summary_area, content_area] = screen_layout(frame.area(), opts.compact);
+
+        render_controls(frame, controls_area, View::Tail, note, opts);
+        render_summary_header(frame, summary_area, data, cfg, opts);
+        if is_empty_month(data) {
+            render_empty_state(
+                frame,
+                content_area,
+                scroll_state,
+                &report_label(data),
+                &opts.theme,
+            );
+            return;
+        }
+
+        let content = build_tail_content(data, cfg, content_area.width as usize, opts);
+        render_scrollable_content(frame, content_area, content, scroll_state, &opts.theme);
+    })?;
+    let links = pr_link_cells(completed.buffer, scroll_state, opts.hyperlinks);
+    draw_cells(terminal, &links)
+}
+
+fn render_reviewers(
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+    data: &MonthData,
+    scroll_state: &mut ScrollState,
+    cfg: &Config,
+    opts: RenderOptions,
+    note: &Span<'static>,
+) -> Result<()> {
+    let completed = terminal.draw(|frame| {
+        let [controls_area, summary_area, content_area] = screen_layout(frame.area(), opts.compact);
+
+        render_controls(frame, controls_area, View::Reviewers, note, opts);
+        render_summary_header(frame, summary_area, data, cfg, opts);
+        if is_empty_month(data) {
+            render_empty_state(
+                frame,
+                content_area,
+                scroll_state,
+                &report_label(data),
+                &opts.theme,
+            );
+            return;
+        }
+
+        let content = build_reviewers_content(data, cfg, content_area.width as usize, opts);
+        render_scrollable_content(frame, content_area, content, scroll_state, &opts.theme);
+    })?;
+    let links = pr_link_cells(completed.buffer, scroll_state, opts.hyperlinks);
+    draw_cells(terminal, &links)
+}
+
+/// True when nothing survived the filters, as opposed to a month whose PRs are all ignored.
+fn is_empty_month(data: &MonthData) -> bool {
+    data.total_prs == 0 && data.prs_by_week.iter().all(|week| week.is_empty())
+}
+
+/// Centered "No PRs found" note in place of a list that would otherwise render blank.
+fn render_empty_state(
+    frame: &mut Frame,
+    area: Rect,
+    scroll_state: &mut ScrollState,
+    month: &str,
+    theme: &Theme,
+) {
+    // Nothing to scroll or select; drop the last list's rows so Enter opens nothing.
+    scroll_state.set_rows(Vec::new());
+    scroll_state.set_content_height(0);
+    let text = vec![
+        Line::from(Span::styled(
+            format!("No PRs found for {}", month),
+            Style::default().fg(theme.highlight).bold(),
+        )),
+        Line::from(Span::styled(
+            "[ and ] switch months; filters in your config still apply",
+            Style::default().fg(theme.muted),
+        )),
+    ];
+    let [area] = Layout::vertical([Constraint::Length(text.len() as u16)])
+        .flex(Flex::Center)
+        .areas(area);
+    frame.render_widget(Paragraph::new(text).centered(), area);
+}
+
+/// One month's bars in the Trend view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TrendPoint {
+    label: String,
+    prs: u64,
+    /// Average lead time in whole hours, the bar height.
+    lead_time_hours: u64,
+    /// Average lead time as printed on the bar; `-` for a month without counted PRs.
+    lead_time: String,
+}
+
+fn trend_points(months: &[MonthData]) -> Vec<TrendPoint> {
+    months
+        .iter()
+        .map(|data| TrendPoint {
+            label: report_label(data),
+            prs: data.total_prs as u64,
+            lead_time_hours: data.avg_lead_time.num_hours().max(0) as u64,
+            lead_time: if data.total_prs == 0 {
+                "-".to_string()
+            } else {
+                format_duration(data.avg_lead_time)
+            },
+        })
+        .collect()
+}
+
+fn render_trend(
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+    months: &[MonthData],
+    scroll_state: &mut ScrollState,
+    opts: RenderOptions,
+    note: &Span<'static>,
 ) -> Result<()> {
+    // Nothing to scroll or select here; drop the last list's rows so Enter opens nothing.
+    scroll_state.set_rows(Vec::new());
+    scroll_state.set_content_height(0);
     terminal.draw(|frame| {
-        let [controls_area, summary_area, content_area] = Layout::vertical([
-            Constraint::Length(2),
-            Constraint::Length(3),
-            Constraint::Min(0),
-        ])
-        .areas(frame.area());
-
-        render_controls(frame, controls_area, View::Tail);
-        render_summary_header(frame, summary_area, data);
-
-        let lines = build_tail_content(data, cfg, content_area.width as usize);
-        render_scrollable_content(frame, content_area, lines, scroll_state);
+        let [controls_area, summary_area, content_area] = screen_layout(frame.area(), opts.compact);
+
+        render_controls(frame, controls_area, View::Trend, note, opts);
+
+        let points = trend_points(months);
+        let range = match (points.first(), points.last()) {
+            (Some(first), Some(last)) => format!("{} to {}", first.label, last.label),
+            _ => "no cached months".to_string(),
+        };
+        let title = Line::from(vec![
+            Span::raw("PR trend, "),
+            Span::styled(range, Style::default().bold()),
+            Span::styled(
+                format!(" ({} months)", points.len()),
+                Style::default().fg(opts.theme.muted),
+            ),
+        ]);
+        if opts.compact {
+            frame.render_widget(Paragraph::new(title), summary_area);
+        } else {
+            let header = Paragraph::new(vec![
+                title,
+                Line::from("Total PRs and average lead time per month, from the cache"),
+            ])
+            .block(
+                Block::default()
+                    .borders(Borders::BOTTOM)
+                    .border_style(Style::default().fg(opts.theme.muted)),
+            );
+            frame.render_widget(header, summary_area);
+        }
+
+        let content_area = content_area.inner(Margin {
+            horizontal: HORIZONTAL_MARGIN,
+            vertical: 0,
+        });
+        if points.is_empty() {
+            let hint = Paragraph::new(
+                "No cached months yet; run gh-log prefetch, or view a few months first.",
+            )
+            .style(Style::default().fg(opts.theme.muted));
+            frame.render_widget(hint, content_area);
+            return;
+        }
+
+        let [prs_area, lead_time_area] =
+            Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .areas(content_area);
+        let pr_bars: Vec<Bar> = points
+            .iter()
+            .map(|point| Bar::with_label(point.label.clone(), point.prs))
+            .collect();
+        let lead_time_bars: Vec<Bar> = points
+            .iter()
+            .map(|point| {
+                Bar::with_label(point.label.clone(), point.lead_time_hours)
+                    .text_value(point.lead_time.clone())
+            })
+            .collect();
+        frame.render_widget(
+            trend_chart("Total PRs", pr_bars, opts.theme.accent, &opts.theme),
+            prs_area,
+        );
+        frame.render_widget(
+            trend_chart(
+                "Avg Lead Time",
+                lead_time_bars,
+                opts.theme.highlight,
+                &opts.theme,
+            ),
+            lead_time_area,
+        );
     })?;
 
     Ok(())
 }
 
-fn render_controls(frame: &mut Frame, area: Rect, current_view: View) {
+fn trend_chart<'a>(
+    title: &'a str,
+    bars: Vec<Bar<'a>>,
+    color: Color,
+    theme: &Theme,
+) -> BarChart<'a> {
+    BarChart::default()
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(theme.muted)),
+        )
+        .data(BarGroup::new(bars))
+        .bar_width(TREND_BAR_WIDTH)
+        .bar_gap(2)
+        .bar_style(Style::default().fg(color))
+        .value_style(Style::default().fg(Color::Black).bg(color))
+}
+
+/// Split the screen into controls, header and content areas. Compact mode drops the borders and
+/// folds the header onto one line.
+fn screen_layout(area: Rect, compact: bool) -> [Rect; 3] {
+    let (controls_height, header_height) = if compact { (1, 1) } else { (2, 3) };
+    Layout::vertical([
+        Constraint::Length(controls_height),
+        Constraint::Length(header_height),
+        Constraint::Min(0),
+    ])
+    .areas(area)
+}
+
+fn render_controls(
+    frame: &mut Frame,
+    area: Rect,
+    current_view: View,
+    note: &Span<'static>,
+    opts: RenderOptions,
+) {
     let detail_label = match current_view {
         View::Detail(DetailMode::ByWeek) => "By Repo",
         View::Detail(DetailMode::ByRepo) => "By Week",
@@ -419,86 +1249,195 @@ fn render_controls(frame: &mut Frame, area: Rect, current_view: View) {
     };
 
     let controls = Line::from(vec![
-        Span::styled("s", Style::default().fg(Color::Gray).bold()),
+        Span::styled("s", Style::default().fg(opts.theme.header).bold()),
         Span::raw(":Summary "),
-        Span::styled("d", Style::default().fg(Color::Gray).bold()),
+        Span::styled("d", Style::default().fg(opts.theme.header).bold()),
         Span::raw(format!(":{} ", detail_label)),
-        Span::styled("t", Style::default().fg(Color::Gray).bold()),
+        Span::styled("t", Style::default().fg(opts.theme.header).bold()),
         Span::raw(":Tail "),
-        Span::styled("q", Style::default().fg(Color::Gray).bold()),
+        Span::styled("r", Style::default().fg(opts.theme.header).bold()),
+        Span::raw(":Reviewers "),
+        Span::styled("T", Style::default().fg(opts.theme.header).bold()),
+        Span::raw(":Trend "),
+        Span::styled("w", Style::default().fg(opts.theme.header).bold()),
+        Span::raw(":Wrap "),
+        Span::styled("z", Style::default().fg(opts.theme.header).bold()),
+        Span::raw(":Compact "),
+        Span::styled("f", Style::default().fg(opts.theme.header).bold()),
+        Span::raw(":Files "),
+        Span::styled("[ ]", Style::default().fg(opts.theme.header).bold()),
+        Span::raw(":Month "),
+        Span::styled("enter", Style::default().fg(opts.theme.header).bold()),
+        Span::raw(":Open "),
+        Span::styled("/", Style::default().fg(opts.theme.header).bold()),
+        Span::raw(":Search "),
+        Span::styled("q", Style::default().fg(opts.theme.header).bold()),
         Span::raw(":Quit"),
+        note.clone(),
     ]);
+    if opts.compact {
+        frame.render_widget(Paragraph::new(controls), area);
+        return;
+    }
     let widget = Paragraph::new(controls).block(
         Block::default()
             .borders(Borders::BOTTOM)
-            .border_style(Style::default().fg(Color::DarkGray)),
+            .border_style(Style::default().fg(opts.theme.muted)),
     );
     frame.render_widget(widget, area);
 }
 
-fn render_detail_header(frame: &mut Frame, area: Rect, data: &MonthData, mode: DetailMode) {
-    let month_year = format_month(data.month_start);
+fn render_detail_header(
+    frame: &mut Frame,
+    area: Rect,
+    data: &MonthData,
+    mode: DetailMode,
+    cfg: &Config,
+    opts: RenderOptions,
+) {
+    let month_year = report_label(data);
     let mode_label = match mode {
         DetailMode::ByWeek => "by Week",
         DetailMode::ByRepo => "by Repository",
     };
-    let review_ratio = if data.total_prs > 0 {
-        data.reviewed_count as f64 / data.total_prs as f64
-    } else {
-        0.0
-    };
+    let theme = &opts.theme;
+    if opts.compact {
+        let line = compact_header_line(data, Some(mode_label), cfg, theme);
+        frame.render_widget(Paragraph::new(line), area);
+        return;
+    }
 
     let summary_lines = vec![
         Line::from(vec![
             Span::raw("GitHub PRs for "),
             Span::styled(month_year, Style::default().bold()),
             Span::raw(" — "),
-            Span::styled(mode_label, Style::default().fg(Color::Cyan)),
+            Span::styled(mode_label, Style::default().fg(theme.mode)),
         ]),
         Line::from(vec![
             Span::raw("Total PRs: "),
-            Span::styled(data.total_prs.to_string(), Style::default().fg(Color::Blue)),
+            Span::styled(
+                data.total_prs.to_string(),
+                Style::default().fg(theme.accent),
+            ),
             Span::raw(" │ Avg Lead Time: "),
             Span::styled(
                 format_duration(data.avg_lead_time),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.highlight),
             ),
             Span::raw(" │ Frequency: "),
             Span::styled(
                 format_frequency(data.frequency),
-                Style::default().fg(Color::Green),
+                Style::default().fg(theme.count),
             ),
-        ]),
-        Line::from(vec![
-            Span::raw("Sizes: "),
-            Span::raw(data.format_size_distribution()),
-            Span::raw(" │ Review Balance: "),
+            Span::raw(" │ Add:Del: "),
             Span::styled(
-                format!("{:.1}:1", review_ratio),
-                Style::default().fg(Color::Cyan),
+                format_add_delete_ratio(data.additions, data.deletions),
+                Style::default().fg(theme.ratio),
             ),
             Span::styled(
-                format!(" ({} reviewed)", data.reviewed_count),
-                Style::default().fg(Color::DarkGray),
+                format!(" ({})", format_line_totals(data.additions, data.deletions)),
+                Style::default().fg(theme.muted),
             ),
         ]),
+        Line::from(
+            [
+                Span::raw("Sizes: "),
+                Span::raw(data.format_size_distribution(&cfg.size)),
+            ]
+            .into_iter()
+            .chain(review_balance_spans(data, cfg, theme))
+            .collect::<Vec<_>>(),
+        ),
     ];
 
     let header = Paragraph::new(summary_lines).block(
         Block::default()
             .borders(Borders::BOTTOM)
-            .border_style(Style::default().fg(Color::DarkGray)),
+            .border_style(Style::default().fg(theme.muted)),
     );
     frame.render_widget(header, area);
 }
 
-fn render_summary_header(frame: &mut Frame, area: Rect, data: &MonthData) {
-    let month_year = format_month(data.month_start);
-    let review_ratio = if data.total_prs > 0 {
-        data.reviewed_count as f64 / data.total_prs as f64
-    } else {
-        0.0
+/// Header spans for the review balance; empty when the review query was skipped. The ratio is
+/// green or red against `[review] target_ratio` when one is set.
+fn review_balance_spans(data: &MonthData, cfg: &Config, theme: &Theme) -> Vec<Span<'static>> {
+    let Some(reviewed_count) = data.reviewed_count else {
+        return Vec::new();
     };
+    let balance = data.review_balance(cfg.analytics.review_balance_base);
+    let color = match cfg.review.meets_target(balance) {
+        Some(true) => theme.on_target,
+        Some(false) => theme.alert,
+        None => theme.balance,
+    };
+    vec![
+        Span::raw(" │ Review Balance: "),
+        Span::styled(format!("{:.1}:1", balance), Style::default().fg(color)),
+        Span::styled(
+            format!(" ({} reviewed)", reviewed_count),
+            Style::default().fg(theme.muted),
+        ),
+    ]
+}
+
+/// The whole header on one line: month, totals, sizes and review balance.
+fn compact_header_line(
+    data: &MonthData,
+    mode_label: Option<&str>,
+    cfg: &Config,
+    theme: &Theme,
+) -> Line<'static> {
+    let mut spans = vec![Span::styled(report_label(data), Style::default().bold())];
+    if let Some(label) = mode_label {
+        spans.push(Span::raw(" — "));
+        spans.push(Span::styled(
+            label.to_string(),
+            Style::default().fg(theme.mode),
+        ));
+    }
+    spans.extend([
+        Span::raw(" │ "),
+        Span::styled(
+            data.total_prs.to_string(),
+            Style::default().fg(theme.accent),
+        ),
+        Span::raw(" PRs │ Avg "),
+        Span::styled(
+            format_duration(data.avg_lead_time),
+            Style::default().fg(theme.highlight),
+        ),
+        Span::raw(" │ "),
+        Span::styled(
+            format_frequency(data.frequency),
+            Style::default().fg(theme.count),
+        ),
+        Span::raw(" │ "),
+        Span::styled(
+            format_add_delete_ratio(data.additions, data.deletions),
+            Style::default().fg(theme.ratio),
+        ),
+        Span::raw(" add:del │ "),
+        Span::raw(data.format_size_distribution(&cfg.size)),
+    ]);
+    spans.extend(review_balance_spans(data, cfg, theme));
+    Line::from(spans)
+}
+
+fn render_summary_header(
+    frame: &mut Frame,
+    area: Rect,
+    data: &MonthData,
+    cfg: &Config,
+    opts: RenderOptions,
+) {
+    let theme = &opts.theme;
+    if opts.compact {
+        let line = compact_header_line(data, None, cfg, theme);
+        frame.render_widget(Paragraph::new(line), area);
+        return;
+    }
+    let month_year = report_label(data);
 
     let summary_lines = vec![
         Line::from(vec![
@@ -507,37 +1446,45 @@ fn render_summary_header(frame: &mut Frame, area: Rect, data: &MonthData) {
         ]),
         Line::from(vec![
             Span::raw("Total PRs: "),
-            Span::styled(data.total_prs.to_string(), Style::default().fg(Color::Blue)),
+            Span::styled(
+                data.total_prs.to_string(),
+                Style::default().fg(theme.accent),
+            ),
             Span::raw(" │ Avg Lead Time: "),
             Span::styled(
                 format_duration(data.avg_lead_time),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.highlight),
             ),
             Span::raw(" │ Frequency: "),
             Span::styled(
                 format_frequency(data.frequency),
-                Style::default().fg(Color::Green),
+                Style::default().fg(theme.count),
             ),
-        ]),
-        Line::from(vec![
-            Span::raw("Sizes: "),
-            Span::raw(data.format_size_distribution()),
-            Span::raw(" │ Review Balance: "),
+            Span::raw(" │ Add:Del: "),
             Span::styled(
-                format!("{:.1}:1", review_ratio),
-                Style::default().fg(Color::Cyan),
+                format_add_delete_ratio(data.additions, data.deletions),
+                Style::default().fg(theme.ratio),
             ),
             Span::styled(
-                format!(" ({} reviewed)", data.reviewed_count),
-                Style::default().fg(Color::DarkGray),
+                format!(" ({})", format_line_totals(data.additions, data.deletions)),
+                Style::default().fg(theme.muted),
             ),
         ]),
+        Line::from(
+            [
+                Span::raw("Sizes: "),
+                Span::raw(data.format_size_distribution(&cfg.size)),
+            ]
+            .into_iter()
+            .chain(review_balance_spans(data, cfg, theme))
+            .collect::<Vec<_>>(),
+        ),
     ];
 
     let header = Paragraph::new(summary_lines).block(
         Block::default()
             .borders(Borders::BOTTOM)
-            .border_style(Style::default().fg(Color::DarkGray)),
+            .border_style(Style::default().fg(theme.muted)),
     );
     frame.render_widget(header, area);
 }
@@ -545,9 +1492,13 @@ fn render_summary_header(frame: &mut Frame, area: Rect, data: &MonthData) {
 fn render_scrollable_content(
     frame: &mut Frame,
     area: Rect,
-    lines: Vec<Line>,
+    content: ListContent,
     scroll_state: &mut ScrollState,
+    theme: &Theme,
 ) {
+    let [area, footer_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+    let ListContent { mut lines, rows } = content;
     scroll_state.set_content_height(lines.len());
     scroll_state.set_viewport_height(
         area.inner(Margin {
@@ -556,6 +1507,15 @@ fn render_scrollable_content(
         })
         .height as usize,
     );
+    scroll_state.clamp_position();
+    scroll_state.set_rows(rows);
+    // Toggling wrap or resizing moves rows around, so bring the cursor back into view.
+    scroll_state.reveal_selected();
+    if let Some(row) = scroll_state.selected_row() {
+        for line in lines.iter_mut().skip(row.line).take(row.height) {
+            line.style = line.style.add_modifier(Modifier::REVERSED);
+        }
+    }
 
     let content_area = area.inner(Margin {
         horizontal: HORIZONTAL_MARGIN,
@@ -563,13 +1523,170 @@ fn render_scrollable_content(
     });
     let content = Paragraph::new(lines).scroll((scroll_state.position as u16, 0));
     frame.render_widget(content, content_area);
+    scroll_state.area = content_area;
 
     let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
     let mut scrollbar_state = scroll_state.as_scrollbar_state();
     frame.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
+
+    let footer = Paragraph::new(Line::from(scroll_state.position_label()).right_aligned())
+        .style(Style::default().fg(theme.muted));
+    frame.render_widget(
+        footer,
+        footer_area.inner(Margin {
+            horizontal: HORIZONTAL_MARGIN,
+            vertical: 0,
+        }),
+    );
+}
+
+/// The `#123` reference of every PR row on screen, cell by cell, each cell wrapped in an OSC 8
+/// link to the PR; empty unless `hyperlinks` is set. ratatui would count the escape sequence as
+/// columns and shift the rest of the row, so these go to the backend after the frame (see
+/// [`draw_cells`]) rather than into it.
+fn pr_link_cells(buffer: &Buffer, scroll: &ScrollState, hyperlinks: bool) -> Vec<(u16, u16, Cell)> {
+    let area = scroll.area;
+    let mut cells = Vec::new();
+    if !hyperlinks {
+        return cells;
+    }
+    for row in &scroll.rows {
+        let Some(url) = row.url() else {
+            continue;
+        };
+        let Some(offset) = row
+            .line
+            .checked_sub(scroll.position)
+            .filter(|&offset| offset < area.height as usize)
+        else {
+            continue;
+        };
+        let Some(number) = url.rsplit('/').next().and_then(|n| n.parse::<u64>().ok()) else {
+            continue;
+        };
+        let y = area.y + offset as u16;
+        // Drawn the way pr_row_lines writes it, so the search skips a `#123` in the title.
+        let reference: Vec<char> = format!("#{:4}", number).chars().collect();
+        let width = reference.len() as u16;
+        let Some(start) = (area.left()..area.right().saturating_sub(width - 1)).find(|&x| {
+            reference
+                .iter()
+                .enumerate()
+                .all(|(i, ch)| buffer[(x + i as u16, y)].symbol().chars().eq([*ch]))
+        }) else {
+            continue;
+        };
+        for x in start..start + width {
+            let mut cell = buffer[(x, y)].clone();
+            cell.set_symbol(&osc8_link(url, cell.symbol()));
+            cells.push((x, y, cell));
+        }
+    }
+    cells
+}
+
+/// Write `cells` over the last frame, straight to the terminal.
+fn draw_cells(
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+    cells: &[(u16, u16, Cell)],
+) -> Result<()> {
+    if cells.is_empty() {
+        return Ok(());
+    }
+    let backend = terminal.backend_mut();
+    backend.draw(cells.iter().map(|(x, y, cell)| (*x, *y, cell)))?;
+    Backend::flush(backend)
+}
+
+/// `text` as an OSC 8 hyperlink to `url`; terminals without OSC 8 support show just the text.
+fn osc8_link(url: &str, text: impl std::fmt::Display) -> String {
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+/// Row and PR-count styles for a week: gaps are dimmed with a red count, the busiest week is bold.
+fn week_row_styles(pr_count: usize, busiest: usize, theme: &Theme) -> (Style, Style) {
+    if pr_count == 0 {
+        (
+            Style::default().add_modifier(Modifier::DIM),
+            Style::default().fg(theme.alert),
+        )
+    } else if pr_count == busiest {
+        (
+            Style::default(),
+            Style::default()
+                .fg(theme.count)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        (Style::default(), Style::default().fg(theme.count))
+    }
+}
+
+/// "Fastest │ 12m      │ owner/repo#123 Title" for the summary's outlier callout; `value` is the
+/// lead time, or the date for the first PR.
+fn highlight_line(
+    label: &str,
+    value: String,
+    pr: &data::PrHighlight,
+    usable_width: usize,
+    theme: &Theme,
+) -> Line<'static> {
+    let reference = format!("{}#{}", pr.repo, pr.number);
+    // Label (7) + " │ " + value (8+) + " │ " + reference + " " leaves the rest for the title.
+    let title_width = usable_width
+        .saturating_sub(7 + 3 + value.width().max(8) + 3 + reference.width() + 1)
+        .max(10);
+    Line::from(vec![
+        Span::styled(format!("{:7}", label), Style::default().fg(theme.header)),
+        Span::raw(" │ "),
+        Span::styled(format!("{:8}", value), Style::default().fg(theme.highlight)),
+        Span::raw(" │ "),
+        Span::styled(reference, Style::default().fg(theme.accent)),
+        Span::raw(" "),
+        Span::raw(truncate(&pr.title, title_width)),
+    ])
+}
+
+/// "4h–1d  │ ██████ 3" per bucket, bars scaled so the fullest one fills the space left over.
+fn lead_time_histogram_lines(
+    buckets: &[data::LeadTimeBucket],
+    usable_width: usize,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let label_width = buckets
+        .iter()
+        .map(|bucket| bucket.label.width())
+        .max()
+        .unwrap_or(0);
+    let most = buckets.iter().map(|bucket| bucket.count).max().unwrap_or(0);
+    // Label + " │ " + bar + " " + count (up to 4 digits).
+    let bar_width = usable_width
+        .saturating_sub(label_width + 3 + 1 + 4)
+        .clamp(10, 40);
+    buckets
+        .iter()
+        .map(|bucket| {
+            // Round up so a bucket with any PRs always shows at least one block.
+            let blocks = (bucket.count * bar_width).div_ceil(most.max(1));
+            Line::from(vec![
+                Span::styled(
+                    pad(&bucket.label, label_width),
+                    Style::default().fg(theme.header),
+                ),
+                Span::raw(" │ "),
+                Span::styled("█".repeat(blocks), Style::default().fg(theme.highlight)),
+                Span::raw(format!(" {}", bucket.count)),
+            ])
+        })
+        .collect()
 }
 
-fn build_summary_content(data: &MonthData, width: usize) -> Vec<Line<'static>> {
+fn build_summary_content(
+    data: &MonthData,
+    cfg: &Config,
+    width: usize,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
     let usable_width = width
         .saturating_sub((HORIZONTAL_MARGIN * 2) as usize)
         .saturating_sub(SCROLLBAR_SPACE as usize);
@@ -577,10 +1694,47 @@ fn build_summary_content(data: &MonthData, width: usize) -> Vec<Line<'static>> {
     let week_date_width = usable_width.saturating_sub(53).max(12);
 
     let mut lines = Vec::new();
+    let warnings = data.warnings(&cfg.targets);
+    for warning in &warnings {
+        lines.push(
+            Line::from(format!("⚠ {}", warning)).style(Style::default().fg(theme.highlight).bold()),
+        );
+    }
+    if !warnings.is_empty() {
+        lines.push(Line::from(""));
+    }
+    if let Some(extremes) = &data.extremes {
+        for (label, pr) in [
+            ("Fastest", &extremes.fastest),
+            ("Slowest", &extremes.slowest),
+        ] {
+            let lead = format_duration(pr.lead_time);
+            lines.push(highlight_line(label, lead, pr, usable_width, theme));
+        }
+    }
+    if let Some(first) = &data.first_pr {
+        lines.push(highlight_line(
+            "First",
+            first.describe(),
+            &first.pr,
+            usable_width,
+            theme,
+        ));
+    }
+    if data.extremes.is_some() || data.first_pr.is_some() {
+        lines.push(Line::from(""));
+    }
     lines.push(
-        Line::from(separator_line("Weeks", usable_width)).style(Style::default().fg(Color::Gray)),
+        Line::from(separator_line("Weeks", usable_width)).style(Style::default().fg(theme.header)),
     );
+    let busiest = data
+        .weeks
+        .iter()
+        .map(|week| week.pr_count)
+        .max()
+        .unwrap_or(0);
     for week in &data.weeks {
+        let (row_style, count_style) = week_row_styles(week.pr_count, busiest, theme);
         let mut spans = vec![
             Span::raw(format!("Week {:2}", week.week_num)),
             Span::raw(" │ "),
@@ -590,62 +1744,80 @@ fn build_summary_content(data: &MonthData, width: usize) -> Vec<Line<'static>> {
                 width = week_date_width
             )),
             Span::raw(" │ "),
-            Span::styled(
-                format!("{:2}", week.pr_count),
-                Style::default().fg(Color::Green),
-            ),
+            Span::styled(format!("{:2}", week.pr_count), count_style),
             Span::raw(" PRs │ Avg: "),
             Span::styled(
                 format!("{:8}", format_duration(week.avg_lead_time)),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.highlight),
             ),
             Span::raw(" │ "),
         ];
         spans.extend(size_distribution_colored(
+            theme,
+            &cfg.size,
             week.size_s,
             week.size_m,
             week.size_l,
             week.size_xl,
+            week.size_unknown,
         ));
-        lines.push(Line::from(spans));
+        lines.push(Line::from(spans).style(row_style));
     }
     for _ in 0..SECTION_SPACING {
         lines.push(Line::from(""));
     }
 
+    if data
+        .lead_time_histogram
+        .iter()
+        .any(|bucket| bucket.count > 0)
+    {
+        lines.push(
+            Line::from(separator_line("Lead Time", usable_width))
+                .style(Style::default().fg(theme.header)),
+        );
+        lines.extend(lead_time_histogram_lines(
+            &data.lead_time_histogram,
+            usable_width,
+            theme,
+        ));
+        for _ in 0..SECTION_SPACING {
+            lines.push(Line::from(""));
+        }
+    }
+
     let repo_name_width = usable_width.saturating_sub(43).max(20);
 
     lines.push(
         Line::from(separator_line("Repositories", usable_width))
-            .style(Style::default().fg(Color::Gray)),
+            .style(Style::default().fg(theme.header)),
     );
     for repo in &data.repos {
         let mut spans = vec![
             Span::styled(
-                format!(
-                    "{:width$}",
-                    truncate(&repo.name, repo_name_width),
-                    width = repo_name_width
-                ),
-                Style::default().fg(Color::Blue),
+                truncate(&repo.name, repo_name_width),
+                Style::default().fg(theme.accent),
             ),
             Span::raw(" │ "),
             Span::styled(
                 format!("{:2}", repo.pr_count),
-                Style::default().fg(Color::Green),
+                Style::default().fg(theme.count),
             ),
             Span::raw(" PRs │ Avg: "),
             Span::styled(
                 format!("{:8}", format_duration(repo.avg_lead_time)),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.highlight),
             ),
             Span::raw(" │ "),
         ];
         spans.extend(size_distribution_colored(
+            theme,
+            &cfg.size,
             repo.size_s,
             repo.size_m,
             repo.size_l,
             repo.size_xl,
+            repo.size_unknown,
         ));
         lines.push(Line::from(spans));
     }
@@ -657,19 +1829,15 @@ fn build_summary_content(data: &MonthData, width: usize) -> Vec<Line<'static>> {
 
     lines.push(
         Line::from(separator_line("Top Reviewers", usable_width))
-            .style(Style::default().fg(Color::Gray)),
+            .style(Style::default().fg(theme.header)),
     );
     for reviewer in data.reviewers.iter().take(10) {
         lines.push(Line::from(vec![
-            Span::raw(format!(
-                "{:width$}",
-                truncate(&reviewer.login, reviewer_name_width),
-                width = reviewer_name_width
-            )),
+            Span::raw(truncate(&reviewer.login, reviewer_name_width)),
             Span::raw(" │ "),
             Span::styled(
                 format!("{:2}", reviewer.pr_count),
-                Style::default().fg(Color::Green),
+                Style::default().fg(theme.count),
             ),
             Span::raw(" PRs"),
         ]));
@@ -678,23 +1846,63 @@ fn build_summary_content(data: &MonthData, width: usize) -> Vec<Line<'static>> {
     lines
 }
 
+/// Lines for a PR list view, plus where each PR's row landed so the cursor can find it.
+struct ListContent {
+    lines: Vec<Line<'static>>,
+    rows: Vec<SelectableRow>,
+}
+
+impl ListContent {
+    fn new() -> Self {
+        Self {
+            lines: Vec::new(),
+            rows: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, line: Line<'static>) {
+        self.lines.push(line);
+    }
+
+    /// Append the lines of one PR row and remember it as selectable.
+    fn push_pr(&mut self, pr: &PRDetail, lines: Vec<Line<'static>>) {
+        self.rows.push(SelectableRow {
+            line: self.lines.len(),
+            height: lines.len(),
+            target: RowTarget::Pr(pr.url()),
+        });
+        self.lines.extend(lines);
+    }
+
+    /// Append a Reviewers header that Enter expands or collapses.
+    fn push_reviewer(&mut self, login: &str, line: Line<'static>) {
+        self.rows.push(SelectableRow {
+            line: self.lines.len(),
+            height: 1,
+            target: RowTarget::Reviewer(login.to_string()),
+        });
+        self.lines.push(line);
+    }
+}
+
 fn build_detail_by_week_content(
     data: &MonthData,
     cfg: &Config,
     width: usize,
-) -> Vec<Line<'static>> {
+    opts: RenderOptions,
+) -> ListContent {
     let usable_width = width
         .saturating_sub((HORIZONTAL_MARGIN * 2) as usize)
         .saturating_sub(SCROLLBAR_SPACE as usize);
+    let layout = RowLayout::new(usable_width, opts);
 
-    let fixed_width = 6 + 3 + 3 + 5 + 3 + 3 + 8 + 3 + 2;
-    let remaining = usable_width.saturating_sub(fixed_width).max(30);
-    let repo_width = (remaining / 3).max(10);
-    let title_width = remaining.saturating_sub(repo_width).max(15);
-
-    let mut lines = Vec::new();
+    let mut content = ListContent::new();
 
     for (week, prs) in data.weeks.iter().zip(data.prs_by_week.iter()) {
+        let prs = matching_prs(prs, opts.search);
+        if prs.is_empty() && !opts.search.is_empty() {
+            continue;
+        }
         let week_header = format!(
             "━━━ Week {} ({}) │ {} PRs │ Avg: {}",
             week.week_num,
@@ -702,219 +1910,365 @@ fn build_detail_by_week_content(
             week.pr_count,
             format_duration(week.avg_lead_time)
         );
-        lines.push(
+        content.push(
             Line::from(pad_line(&week_header, usable_width, '━'))
-                .style(Style::default().fg(Color::Gray)),
+                .style(Style::default().fg(opts.theme.header)),
         );
 
         for pr in prs {
-            let pr_size = pr.size(&cfg.size);
-            let size_color = match pr_size {
-                PRSize::S => Color::Green,
-                PRSize::M => Color::Blue,
-                PRSize::L => Color::Yellow,
-                PRSize::XL => Color::Red,
-            };
-
-            lines.push(Line::from(vec![
-                Span::styled(
-                    format_date_short(pr.created_at),
-                    Style::default().fg(Color::DarkGray),
-                ),
-                Span::raw(" │ "),
-                Span::styled(
-                    format!(
-                        "{:repo_w$}",
-                        truncate(&pr.repo, repo_width),
-                        repo_w = repo_width
-                    ),
-                    Style::default().fg(Color::Blue),
-                ),
-                Span::raw(" │ "),
-                Span::styled(
-                    format!("#{:4}", pr.number),
-                    Style::default().fg(Color::DarkGray),
-                ),
-                Span::raw(" "),
-                Span::raw(format!(
-                    "{:title_w$}",
-                    truncate(&pr.title, title_width),
-                    title_w = title_width
-                )),
-                Span::raw(" │ "),
-                Span::styled(
-                    format!("{:8}", format_duration(pr.lead_time)),
-                    Style::default().fg(Color::Yellow),
-                ),
-                Span::raw(" │ "),
-                Span::styled(format!("{}", pr_size), Style::default().fg(size_color)),
-            ]));
+            content.push_pr(pr, detail_rows(data, pr, cfg, layout, &opts.theme));
         }
         for _ in 0..SECTION_SPACING {
-            lines.push(Line::from(""));
+            content.push(Line::from(""));
         }
     }
 
-    lines
+    content
+}
+
+/// `pr_row_lines`, with the month's first PR in bold so the Detail view shows where it started.
+fn detail_rows(
+    data: &MonthData,
+    pr: &PRDetail,
+    cfg: &Config,
+    layout: RowLayout,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let mut rows = pr_row_lines(pr, cfg, layout, theme);
+    let is_first = data
+        .first_pr
+        .as_ref()
+        .is_some_and(|first| first.pr.repo == pr.repo && first.pr.number == pr.number);
+    if is_first && let Some(row) = rows.first_mut() {
+        row.style = row.style.add_modifier(Modifier::BOLD);
+    }
+    rows
 }
 
 fn build_detail_by_repo_content(
     data: &MonthData,
     cfg: &Config,
     width: usize,
-) -> Vec<Line<'static>> {
+    opts: RenderOptions,
+) -> ListContent {
     let usable_width = width
         .saturating_sub((HORIZONTAL_MARGIN * 2) as usize)
         .saturating_sub(SCROLLBAR_SPACE as usize);
+    let layout = RowLayout::new(usable_width, opts);
 
-    let fixed_width = 6 + 3 + 3 + 5 + 3 + 3 + 8 + 3 + 2;
-    let remaining = usable_width.saturating_sub(fixed_width).max(30);
-    let repo_width = (remaining / 3).max(10);
-    let title_width = remaining.saturating_sub(repo_width).max(15);
-
-    let mut lines = Vec::new();
+    let mut content = ListContent::new();
 
     for (repo, prs) in data.repos.iter().zip(data.prs_by_repo.iter()) {
-        let repo_header = format!(
-            "━━━ {} │ {} PRs │ Avg: {} │ [{}]",
+        let prs = matching_prs(prs, opts.search);
+        if prs.is_empty() && !opts.search.is_empty() {
+            continue;
+        }
+        let mut repo_header = format!(
+            "━━━ {} │ {} PRs │ Avg: {} │ p50: {} │ p90: {} │ [{}]",
             repo.name,
             repo.pr_count,
             format_duration(repo.avg_lead_time),
-            repo.format_size_distribution()
+            format_duration(repo.p50_lead_time),
+            format_duration(repo.p90_lead_time),
+            repo.format_size_distribution(&cfg.size)
         );
-        lines.push(
+        if let Some(review) = repo_review_summary(repo) {
+            repo_header.push_str(&format!(" │ {}", review));
+        }
+        content.push(
             Line::from(pad_line(&repo_header, usable_width, '━'))
-                .style(Style::default().fg(Color::Gray)),
+                .style(Style::default().fg(opts.theme.header)),
         );
 
         for pr in prs {
-            let pr_size = pr.size(&cfg.size);
-            let size_color = match pr_size {
-                PRSize::S => Color::Green,
-                PRSize::M => Color::Blue,
-                PRSize::L => Color::Yellow,
-                PRSize::XL => Color::Red,
-            };
-
-            lines.push(Line::from(vec![
-                Span::styled(
-                    format_date_short(pr.created_at),
-                    Style::default().fg(Color::DarkGray),
-                ),
-                Span::raw(" │ "),
-                Span::styled(
-                    format!(
-                        "{:repo_w$}",
-                        truncate(&pr.repo, repo_width),
-                        repo_w = repo_width
-                    ),
-                    Style::default().fg(Color::Blue),
-                ),
-                Span::raw(" │ "),
-                Span::styled(
-                    format!("#{:4}", pr.number),
-                    Style::default().fg(Color::DarkGray),
-                ),
-                Span::raw(" "),
-                Span::raw(format!(
-                    "{:title_w$}",
-                    truncate(&pr.title, title_width),
-                    title_w = title_width
-                )),
-                Span::raw(" │ "),
-                Span::styled(
-                    format!("{:8}", format_duration(pr.lead_time)),
-                    Style::default().fg(Color::Yellow),
-                ),
-                Span::raw(" │ "),
-                Span::styled(format!("{}", pr_size), Style::default().fg(size_color)),
-            ]));
+            content.push_pr(pr, detail_rows(data, pr, cfg, layout, &opts.theme));
         }
         for _ in 0..SECTION_SPACING {
-            lines.push(Line::from(""));
+            content.push(Line::from(""));
         }
     }
 
-    lines
+    content
 }
 
-fn build_tail_content(data: &MonthData, cfg: &Config, width: usize) -> Vec<Line<'static>> {
-    let mut all_prs: Vec<PRDetail> = data.prs_by_week.iter().flatten().cloned().collect();
-    all_prs.sort_by(|a, b| b.lead_time.cmp(&a.lead_time));
+fn build_tail_content(
+    data: &MonthData,
+    cfg: &Config,
+    width: usize,
+    opts: RenderOptions,
+) -> ListContent {
+    let mut all_prs: Vec<&PRDetail> = data
+        .prs_by_week
+        .iter()
+        .flat_map(|prs| matching_prs(prs, opts.search))
+        .collect();
+    all_prs.sort_by_key(|pr| std::cmp::Reverse(pr.lead_time));
 
     let usable_width = width
         .saturating_sub((HORIZONTAL_MARGIN * 2) as usize)
         .saturating_sub(SCROLLBAR_SPACE as usize);
+    let layout = RowLayout::new(usable_width, opts);
 
-    let fixed_width = 6 + 3 + 3 + 5 + 3 + 3 + 8 + 3 + 2;
-    let remaining = usable_width.saturating_sub(fixed_width).max(30);
-    let repo_width = (remaining / 3).max(10);
-    let title_width = remaining.saturating_sub(repo_width).max(15);
-
-    let mut lines = Vec::new();
-    lines.push(
+    let mut content = ListContent::new();
+    content.push(
         Line::from(separator_line(
             "All PRs sorted by Lead Time (longest first)",
             usable_width,
         ))
-        .style(Style::default().fg(Color::Gray)),
+        .style(Style::default().fg(opts.theme.header)),
     );
 
-    for pr in &all_prs {
-        let pr_size = pr.size(&cfg.size);
-        let size_color = match pr_size {
-            PRSize::S => Color::Green,
-            PRSize::M => Color::Blue,
-            PRSize::L => Color::Yellow,
-            PRSize::XL => Color::Red,
-        };
+    for pr in all_prs {
+        content.push_pr(pr, pr_row_lines(pr, cfg, layout, &opts.theme));
+    }
 
-        lines.push(Line::from(vec![
-            Span::styled(
-                format_date_short(pr.created_at),
-                Style::default().fg(Color::DarkGray),
-            ),
-            Span::raw(" │ "),
-            Span::styled(
-                format!(
-                    "{:repo_w$}",
-                    truncate(&pr.repo, repo_width),
-                    repo_w = repo_width
-                ),
-                Style::default().fg(Color::Blue),
-            ),
-            Span::raw(" │ "),
-            Span::styled(
-                format!("#{:4}", pr.number),
-                Style::default().fg(Color::DarkGray),
-            ),
-            Span::raw(" "),
-            Span::raw(format!(
-                "{:title_w$}",
-                truncate(&pr.title, title_width),
-                title_w = title_width
-            )),
-            Span::raw(" │ "),
-            Span::styled(
-                format!("{:8}", format_duration(pr.lead_time)),
-                Style::default().fg(Color::Yellow),
-            ),
-            Span::raw(" │ "),
-            Span::styled(format!("{}", pr_size), Style::default().fg(size_color)),
-        ]));
+    content
+}
+
+/// One section per reviewer, busiest first, listing the PRs they left a review on.
+fn build_reviewers_content(
+    data: &MonthData,
+    cfg: &Config,
+    width: usize,
+    opts: RenderOptions,
+) -> ListContent {
+    let usable_width = width
+        .saturating_sub((HORIZONTAL_MARGIN * 2) as usize)
+        .saturating_sub(SCROLLBAR_SPACE as usize);
+    let layout = RowLayout::new(usable_width, opts);
+    let by_ref: HashMap<(&str, u32), &PRDetail> = data
+        .prs_by_week
+        .iter()
+        .flatten()
+        .map(|pr| ((pr.repo.as_str(), pr.number), pr))
+        .collect();
+
+    let mut content = ListContent::new();
+    if data.reviewers.is_empty() {
+        content.push(
+            Line::from("No reviews on these PRs yet").style(Style::default().fg(opts.theme.muted)),
+        );
+        return content;
+    }
+
+    for reviewer in &data.reviewers {
+        let reviewed: Vec<&PRDetail> = data
+            .reviewer_prs
+            .get(&reviewer.login)
+            .into_iter()
+            .flatten()
+            .filter_map(|(repo, number)| by_ref.get(&(repo.as_str(), *number)).copied())
+            .collect();
+        let prs = matching_prs(reviewed.iter().copied(), opts.search);
+        if prs.is_empty() && !opts.search.is_empty() {
+            continue;
+        }
+        // A search shows every reviewer with a match, so the matches are never hidden.
+        let expanded =
+            !opts.search.is_empty() || opts.expanded_reviewer == Some(reviewer.login.as_str());
+        let header = format!(
+            "━━━ {} {} │ {} reviews on {} PRs",
+            if expanded { '▾' } else { '▸' },
+            reviewer.login,
+            reviewer.pr_count,
+            reviewed.len()
+        );
+        content.push_reviewer(
+            &reviewer.login,
+            Line::from(pad_line(&header, usable_width, '━'))
+                .style(Style::default().fg(opts.theme.header)),
+        );
+        if !expanded {
+            continue;
+        }
+
+        for pr in prs {
+            content.push_pr(pr, pr_row_lines(pr, cfg, layout, &opts.theme));
+        }
+        for _ in 0..SECTION_SPACING {
+            content.push(Line::from(""));
+        }
+    }
+
+    content
+}
+
+/// The PRs whose title or repo contains `search`, ignoring case; all of them when it is empty.
+fn matching_prs<'a>(
+    prs: impl IntoIterator<Item = &'a PRDetail>,
+    search: &str,
+) -> Vec<&'a PRDetail> {
+    let search = search.to_lowercase();
+    prs.into_iter()
+        .filter(|pr| {
+            pr.title.to_lowercase().contains(&search) || pr.repo.to_lowercase().contains(&search)
+        })
+        .collect()
+}
+
+/// Render one PR as a list row. With `wrap` set, a title longer than its column continues on
+/// extra lines indented to the title column; otherwise it is truncated to fit. Ignored PRs are
+/// dimmed and tagged with `[display] ignored_marker`.
+fn pr_row_lines(
+    pr: &PRDetail,
+    cfg: &Config,
+    layout: RowLayout,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let RowLayout {
+        repo_width,
+        title_width,
+        ..
+    } = layout;
+    let size_cfg = &cfg.size;
+    let pr_size = pr.size(size_cfg);
+    let marker = cfg.display.ignored_marker();
+    let title = if pr.ignored && !marker.is_empty() {
+        format!("{} {}", pr.title, marker)
+    } else {
+        pr.title.clone()
+    };
+    // Labels sit at the end of the title column, unless they would crowd out the title.
+    let labels_width: usize = pr.labels.iter().map(|label| label.width() + 1).sum();
+    let (text_width, labels) = if labels_width > 0 && labels_width <= title_width / 2 {
+        (title_width - labels_width, pr.labels.as_slice())
+    } else {
+        (title_width, &[][..])
+    };
+    let title_lines = if layout.wrap {
+        wrap_text(&title, text_width)
+    } else {
+        vec![truncate(&title, text_width)]
+    };
+
+    let mut spans = vec![
+        Span::styled(
+            format_date_short(pr.created_at),
+            Style::default().fg(theme.muted),
+        ),
+        Span::raw(" │ "),
+        Span::styled(
+            truncate(&pr.repo, repo_width),
+            Style::default().fg(theme.accent),
+        ),
+        Span::raw(" │ "),
+        Span::styled(
+            format!("#{:4}", pr.number),
+            Style::default().fg(theme.muted),
+        ),
+        Span::raw(" "),
+        Span::raw(pad(&title_lines[0], text_width)),
+    ];
+    for label in labels {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(label.clone(), label_style(label)));
+    }
+    spans.extend([
+        Span::raw(" │ "),
+        Span::styled(
+            format!("{:8}", format_duration(pr.lead_time)),
+            // An open PR's lead time is still running, so it is set apart from final ones.
+            if pr.open {
+                Style::default().fg(theme.header).italic()
+            } else {
+                Style::default().fg(theme.highlight)
+            },
+        ),
+        Span::raw(" │ "),
+        Span::styled(
+            format!("{:2}", size_label(pr_size, size_cfg)),
+            Style::default().fg(theme.size(pr_size)),
+        ),
+        Span::raw(" │ "),
+    ]);
+    if layout.files {
+        spans.push(Span::styled(
+            format!("{:>w$}", format!("{}f", pr.changed_files), w = FILES_WIDTH),
+            Style::default().fg(theme.muted),
+        ));
+        spans.push(Span::raw(" │ "));
+    }
+    spans.push(Span::styled(pr.churn(), Style::default().fg(theme.muted)));
+    let mut lines = vec![Line::from(spans)];
+
+    // Date (6) + " │ " + repo + " │ " + "#nnnn" + " " puts continuation text under the title.
+    let indent = " ".repeat(6 + 3 + repo_width + 3 + 5 + 1);
+    for continuation in &title_lines[1..] {
+        lines.push(Line::from(format!("{}{}", indent, continuation)));
+    }
+    if pr.ignored {
+        for line in &mut lines {
+            line.style = line.style.add_modifier(Modifier::DIM);
+        }
+    }
+    lines
+}
+
+/// Colors label chips cycle through; a label keeps its color across rows and runs, so these stay
+/// outside [`Theme`].
+const LABEL_COLORS: [Color; 5] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::LightGreen,
+    Color::LightBlue,
+    Color::LightRed,
+];
+
+fn label_style(label: &str) -> Style {
+    let index = label.bytes().map(usize::from).sum::<usize>() % LABEL_COLORS.len();
+    Style::default().fg(LABEL_COLORS[index]).italic()
+}
+
+/// Split `text` into lines of at most `width` terminal columns, breaking on whitespace and
+/// hard-splitting words that are longer than a full line.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let mut word = word;
+        while word.width() > width {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            let mut used = 0;
+            let split = word
+                .char_indices()
+                .find(|&(i, ch)| {
+                    used += ch.width().unwrap_or(0);
+                    // Always take at least one character, even one wider than the line.
+                    i > 0 && used > width
+                })
+                .map_or(word.len(), |(i, _)| i);
+            lines.push(word[..split].to_string());
+            word = &word[split..];
+        }
+
+        let current_len = current.width();
+        if current_len > 0 && current_len + 1 + word.width() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
     }
 
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
     lines
 }
 
 fn separator_line(title: &str, width: usize) -> String {
     let prefix = format!("━━━ {} ", title);
-    let remaining = width.saturating_sub(prefix.chars().count()).max(0);
+    let remaining = width.saturating_sub(prefix.width());
     format!("{}{}", prefix, "━".repeat(remaining))
 }
 
 fn pad_line(text: &str, width: usize, pad_char: char) -> String {
-    let text_len = text.chars().count();
+    let text_len = text.width();
     if text_len >= width {
         text.to_string()
     } else {
@@ -937,6 +2291,19 @@ fn format_month(dt: DateTime<Utc>) -> String {
     format!("{:04}-{:02}", dt.year(), dt.month())
 }
 
+/// What the report covers: `YYYY-MM`, or the dates of a rolling window, marked when `--limit`
+/// cut the PR search short.
+fn report_label(data: &MonthData) -> String {
+    let label = match data.window {
+        Some((start, end)) => format!("{}..{}", format_date(start), format_date(end)),
+        None => format_month(data.month_start),
+    };
+    match data.pr_limit {
+        Some(limit) => format!("{} (showing first {})", label, limit),
+        None => label,
+    }
+}
+
 fn format_frequency(freq: f64) -> String {
     format!("{:.1}/week", freq)
 }
@@ -955,74 +2322,232 @@ fn format_date_short(dt: DateTime<Utc>) -> String {
     dt.format("%b %d").to_string()
 }
 
-fn truncate(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        format!("{:width$}", s, width = max_len)
-    } else {
-        format!("{:width$}", &s[..max_len], width = max_len)
+/// Fit `s` into exactly `max_width` terminal columns: cut at a character boundary by display width
+/// (CJK and most emoji take two columns), then pad with spaces.
+fn truncate(s: &str, max_width: usize) -> String {
+    let mut out = String::new();
+    let mut used = 0;
+    for ch in s.chars() {
+        let ch_width = ch.width().unwrap_or(0);
+        if used + ch_width > max_width {
+            break;
+        }
+        out.push(ch);
+        used += ch_width;
     }
+    pad(&out, max_width)
+}
+
+/// Left-align `s` in `width` terminal columns. Unlike `{:width$}`, which counts chars, this lines
+/// up wide characters.
+fn pad(s: &str, width: usize) -> String {
+    format!("{}{}", s, " ".repeat(width.saturating_sub(s.width())))
 }
 
 fn size_distribution_colored(
+    theme: &Theme,
+    sizes: &SizeConfig,
     size_s: usize,
     size_m: usize,
     size_l: usize,
     size_xl: usize,
+    size_unknown: usize,
 ) -> Vec<Span<'static>> {
-    vec![
-        Span::styled(format!("{:2}S", size_s), Style::default().fg(Color::Green)),
+    let labels = &sizes.labels;
+    let mut spans = vec![
+        Span::styled(
+            format!("{:2}{}", size_s, labels.s),
+            Style::default().fg(theme.size_s),
+        ),
         Span::raw(" "),
-        Span::styled(format!("{:2}M", size_m), Style::default().fg(Color::Blue)),
+        Span::styled(
+            format!("{:2}{}", size_m, labels.m),
+            Style::default().fg(theme.size_m),
+        ),
         Span::raw(" "),
-        Span::styled(format!("{:2}L", size_l), Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!("{:2}{}", size_l, labels.l),
+            Style::default().fg(theme.size_l),
+        ),
         Span::raw(" "),
-        Span::styled(format!("{:2}XL", size_xl), Style::default().fg(Color::Red)),
-    ]
+        Span::styled(
+            format!("{:2}{}", size_xl, labels.xl),
+            Style::default().fg(theme.size_xl),
+        ),
+    ];
+    if size_unknown > 0 {
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            format!("{:2}?", size_unknown),
+            Style::default().fg(theme.size_unknown),
+        ));
+    }
+    spans
+}
+
+/// Print the given PRs one by one with every detail we have, bodies included.
+///
+/// # Examples
+/// ```rust,no_run
+/// # use gh_log::{config::SizeConfig, data::PRDetail};
+/// # fn demo(prs: &[PRDetail]) {
+/// gh_log::view::print_pr_details(prs, &SizeConfig::default());
+/// # }
+/// ```
+pub fn print_pr_details(prs: &[PRDetail], size_cfg: &SizeConfig) {
+    for pr in prs {
+        println!("#{} {}", pr.number, pr.title);
+        println!("  - Repo: {}", pr.repo);
+        println!("  - Created: {}", format_date(pr.created_at));
+        match pr.merged_at {
+            Some(merged_at) => println!("  - Merged: {}", format_date(merged_at)),
+            None => println!("  - Merged: no"),
+        }
+        println!("  - Lead Time: {}", format_duration(pr.lead_time));
+        println!(
+            "  - Size: {} (+{} -{}, {} files)",
+            size_label(pr.size(size_cfg), size_cfg),
+            pr.additions,
+            pr.deletions,
+            pr.changed_files
+        );
+        if let Some(body) = &pr.body
+            && !body.is_empty()
+        {
+            println!();
+            for line in body.lines() {
+                println!("    {}", line);
+            }
+        }
+        println!();
+    }
 }
 
 /// Render the monthly analytics as JSON for downstream tooling or AI prompts.
 ///
 /// # Examples
 /// ```rust,no_run
-/// # use gh_log::{config::SizeConfig, data::MonthData};
-/// # fn run(data: MonthData, sizes: SizeConfig) -> anyhow::Result<()> {
-/// gh_log::view::print_json(&data, &sizes)?;
+/// # use gh_log::{config::Config, data::MonthData};
+/// # fn run(data: MonthData, cfg: Config) -> anyhow::Result<()> {
+/// gh_log::view::print_json(&data, &cfg, &[])?;
 /// # Ok(())
 /// # }
 /// ```
 ///
 /// # Errors
 /// Returns an error if serialization fails or writing to stdout encounters an I/O failure.
-pub fn print_json(data: &data::MonthData, size_cfg: &SizeConfig) -> anyhow::Result<()> {
+///
+/// `warnings` (see [`MonthData::warnings`]) are emitted as a `warnings` array.
+pub fn print_json(data: &data::MonthData, cfg: &Config, warnings: &[String]) -> anyhow::Result<()> {
+    println!("{}", json_string(data, cfg, warnings)?);
+    Ok(())
+}
+
+/// The JSON document [`print_json`] writes, for callers that send it somewhere other than stdout,
+/// such as `print --postprocess`.
+pub fn json_string(
+    data: &data::MonthData,
+    cfg: &Config,
+    warnings: &[String],
+) -> anyhow::Result<String> {
     use serde::Serialize;
 
+    let size_cfg = &cfg.size;
+
     #[derive(Serialize)]
     struct JsonOutput<'a> {
         month_start: String,
         total_prs: usize,
+        /// PRs still open, which are left out of the lead-time figures.
+        open_prs: usize,
         avg_lead_time_hours: f64,
+        /// Creation to first review by someone else, over reviewed PRs; `null` when none were.
+        avg_time_to_first_review_hours: Option<f64>,
         frequency: f64,
+        additions: u64,
+        deletions: u64,
+        /// `null` when nothing was deleted.
+        add_delete_ratio: Option<f64>,
         size_distribution: SizeDistribution,
         reviewers: Vec<JsonReviewer<'a>>,
-        reviewed_count: usize,
+        reviewed_count: Option<usize>,
+        /// Fastest and slowest PRs by lead time; `null` for an empty month.
+        extremes: Option<JsonExtremes<'a>>,
+        /// Earliest counted PR; `null` for an empty month.
+        first_pr: Option<JsonFirstPr<'a>>,
+        /// PR count per lead-time range, shortest range first.
+        lead_time_histogram: JsonHistogram<'a>,
+        /// Creation to your first review on the PRs you reviewed; `null` when unknown.
+        review_turnaround: Option<JsonTurnaround>,
+        /// Per-person totals, only present for multi-author reports.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        authors: Vec<JsonAuthor<'a>>,
+        /// Per-month totals, only present for `--year` reports.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        months: Vec<JsonMonth>,
+        /// Every PR in `--sort` order, only present with `--sort`; the weeks keep date order.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        prs: Option<Vec<JsonPR<'a>>>,
         weeks: Vec<JsonWeek<'a>>,
         repositories: Vec<JsonRepo<'a>>,
+        warnings: &'a [String],
     }
 
     #[derive(Serialize)]
-    struct SizeDistribution {
-        s: usize,
-        m: usize,
-        l: usize,
-        xl: usize,
+    struct JsonTurnaround {
+        median_hours: f64,
+        mean_hours: f64,
+        count: usize,
     }
 
-    #[derive(Serialize)]
+    /// Serialized as a map that keeps the buckets in range order.
+    struct JsonHistogram<'a>(&'a [data::LeadTimeBucket]);
+
+    impl Serialize for JsonHistogram<'_> {
+        fn serialize<S: serde::Serializer>(
+            &self,
+            serializer: S,
+        ) -> std::result::Result<S::Ok, S::Error> {
+            serializer.collect_map(
+                self.0
+                    .iter()
+                    .map(|bucket| (bucket.label.as_str(), bucket.count)),
+            )
+        }
+    }
+
+    #[derive(Serialize)]
+    struct SizeDistribution {
+        s: usize,
+        m: usize,
+        l: usize,
+        xl: usize,
+        unknown: usize,
+    }
+
+    #[derive(Serialize)]
+    struct JsonAuthor<'a> {
+        login: &'a str,
+        total_prs: usize,
+        avg_lead_time_hours: f64,
+        reviewed_count: Option<usize>,
+        review_balance: Option<f64>,
+    }
+
+    #[derive(Serialize)]
     struct JsonReviewer<'a> {
         login: &'a str,
         pr_count: usize,
     }
 
+    #[derive(Serialize)]
+    struct JsonMonth {
+        month: String,
+        pr_count: usize,
+        avg_lead_time_hours: f64,
+        size_distribution: SizeDistribution,
+    }
+
     #[derive(Serialize)]
     struct JsonWeek<'a> {
         week_num: usize,
@@ -1044,7 +2569,54 @@ pub fn print_json(data: &data::MonthData, size_cfg: &SizeConfig) -> anyhow::Resu
         size: String,
         additions: u32,
         deletions: u32,
+        add_delete_ratio: Option<f64>,
         changed_files: u32,
+        linked_issues: Vec<JsonIssue<'a>>,
+        labels: &'a [String],
+        /// "created", "merged" or "created+merged"; only present with `--also-merged`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        matched_by: Option<&'static str>,
+        /// Still open; `lead_time_hours` runs to the time of the report.
+        open: bool,
+    }
+
+    #[derive(Serialize)]
+    struct JsonExtremes<'a> {
+        fastest: JsonHighlight<'a>,
+        slowest: JsonHighlight<'a>,
+    }
+
+    #[derive(Serialize)]
+    struct JsonHighlight<'a> {
+        number: u32,
+        title: &'a str,
+        repo: &'a str,
+        lead_time_hours: f64,
+    }
+
+    #[derive(Serialize)]
+    struct JsonFirstPr<'a> {
+        number: u32,
+        title: &'a str,
+        repo: &'a str,
+        date: String,
+        days_in: i64,
+    }
+
+    fn highlight(pr: &data::PrHighlight) -> JsonHighlight<'_> {
+        JsonHighlight {
+            number: pr.number,
+            title: &pr.title,
+            repo: &pr.repo,
+            lead_time_hours: pr.lead_time.num_seconds() as f64 / 3600.0,
+        }
+    }
+
+    #[derive(Serialize)]
+    struct JsonIssue<'a> {
+        id: &'a str,
+        /// From `[display] issue_url_template`; null when unset.
+        url: Option<String>,
     }
 
     #[derive(Serialize)]
@@ -1052,19 +2624,61 @@ pub fn print_json(data: &data::MonthData, size_cfg: &SizeConfig) -> anyhow::Resu
         name: &'a str,
         pr_count: usize,
         avg_lead_time_hours: f64,
+        p50_lead_time_hours: f64,
+        p90_lead_time_hours: f64,
         size_distribution: SizeDistribution,
+        /// PRs you reviewed in this repo; null without per-repo review data.
+        reviewed_count: Option<usize>,
+        /// `reviewed_count` per authored PR.
+        review_balance: Option<f64>,
+    }
+
+    fn json_pr<'a>(pr: &'a PRDetail, cfg: &Config) -> JsonPR<'a> {
+        let size_cfg = &cfg.size;
+        JsonPR {
+            created_at: format_date(pr.created_at),
+            repo: &pr.repo,
+            number: pr.number,
+            title: &pr.title,
+            body: pr.body.as_deref(),
+            lead_time_hours: pr.lead_time.num_seconds() as f64 / 3600.0,
+            size: size_label(pr.size(size_cfg), size_cfg).to_string(),
+            additions: pr.additions,
+            deletions: pr.deletions,
+            add_delete_ratio: pr.add_delete_ratio(),
+            changed_files: pr.changed_files,
+            linked_issues: pr
+                .linked_issues
+                .iter()
+                .map(|id| JsonIssue {
+                    id,
+                    url: cfg.display.issue_url(id),
+                })
+                .collect(),
+            labels: &pr.labels,
+            matched_by: pr.matched_by.map(data::MatchedBy::label),
+            open: pr.open,
+        }
     }
 
     let output = JsonOutput {
         month_start: format_date(data.month_start),
         total_prs: data.total_prs,
+        open_prs: data.open_prs,
         avg_lead_time_hours: data.avg_lead_time.num_seconds() as f64 / 3600.0,
+        avg_time_to_first_review_hours: data
+            .avg_time_to_first_review
+            .map(|wait| wait.num_seconds() as f64 / 3600.0),
         frequency: data.frequency,
+        additions: data.additions,
+        deletions: data.deletions,
+        add_delete_ratio: data.add_delete_ratio(),
         size_distribution: SizeDistribution {
             s: data.size_s,
             m: data.size_m,
             l: data.size_l,
             xl: data.size_xl,
+            unknown: data.size_unknown,
         },
         reviewers: data
             .reviewers
@@ -1075,6 +2689,59 @@ pub fn print_json(data: &data::MonthData, size_cfg: &SizeConfig) -> anyhow::Resu
             })
             .collect(),
         reviewed_count: data.reviewed_count,
+        extremes: data.extremes.as_ref().map(|extremes| JsonExtremes {
+            fastest: highlight(&extremes.fastest),
+            slowest: highlight(&extremes.slowest),
+        }),
+        first_pr: data.first_pr.as_ref().map(|first| JsonFirstPr {
+            number: first.pr.number,
+            title: &first.pr.title,
+            repo: &first.pr.repo,
+            date: first.at.format("%Y-%m-%d").to_string(),
+            days_in: first.days_in,
+        }),
+        lead_time_histogram: JsonHistogram(&data.lead_time_histogram),
+        review_turnaround: data
+            .review_turnaround
+            .as_ref()
+            .map(|turnaround| JsonTurnaround {
+                median_hours: turnaround.median.num_seconds() as f64 / 3600.0,
+                mean_hours: turnaround.mean.num_seconds() as f64 / 3600.0,
+                count: turnaround.count,
+            }),
+        authors: data
+            .authors
+            .iter()
+            .map(|author| JsonAuthor {
+                login: &author.login,
+                total_prs: author.total_prs,
+                avg_lead_time_hours: author.avg_lead_time.num_seconds() as f64 / 3600.0,
+                reviewed_count: author.reviewed_count,
+                review_balance: author.review_balance,
+            })
+            .collect(),
+        months: data
+            .months
+            .iter()
+            .map(|month| JsonMonth {
+                month: month.week_start.format("%Y-%m").to_string(),
+                pr_count: month.pr_count,
+                avg_lead_time_hours: month.avg_lead_time.num_seconds() as f64 / 3600.0,
+                size_distribution: SizeDistribution {
+                    s: month.size_s,
+                    m: month.size_m,
+                    l: month.size_l,
+                    xl: month.size_xl,
+                    unknown: month.size_unknown,
+                },
+            })
+            .collect(),
+        prs: data.pr_order.map(|_| {
+            data.sorted_prs(size_cfg)
+                .into_iter()
+                .map(|pr| json_pr(pr, cfg))
+                .collect()
+        }),
         weeks: data
             .weeks
             .iter()
@@ -1087,18 +2754,7 @@ pub fn print_json(data: &data::MonthData, size_cfg: &SizeConfig) -> anyhow::Resu
                 avg_lead_time_hours: week.avg_lead_time.num_seconds() as f64 / 3600.0,
                 prs: data.prs_by_week[idx]
                     .iter()
-                    .map(|pr| JsonPR {
-                        created_at: format_date(pr.created_at),
-                        repo: &pr.repo,
-                        number: pr.number,
-                        title: &pr.title,
-                        body: pr.body.as_deref(),
-                        lead_time_hours: pr.lead_time.num_seconds() as f64 / 3600.0,
-                        size: pr.size(size_cfg).to_string(),
-                        additions: pr.additions,
-                        deletions: pr.deletions,
-                        changed_files: pr.changed_files,
-                    })
+                    .map(|pr| json_pr(pr, cfg))
                     .collect(),
             })
             .collect(),
@@ -1109,142 +2765,1071 @@ pub fn print_json(data: &data::MonthData, size_cfg: &SizeConfig) -> anyhow::Resu
                 name: &repo.name,
                 pr_count: repo.pr_count,
                 avg_lead_time_hours: repo.avg_lead_time.num_seconds() as f64 / 3600.0,
+                p50_lead_time_hours: repo.p50_lead_time.num_seconds() as f64 / 3600.0,
+                p90_lead_time_hours: repo.p90_lead_time.num_seconds() as f64 / 3600.0,
                 size_distribution: SizeDistribution {
                     s: repo.size_s,
                     m: repo.size_m,
                     l: repo.size_l,
                     xl: repo.size_xl,
+                    unknown: repo.size_unknown,
                 },
+                reviewed_count: repo.reviewed_count,
+                review_balance: repo.review_balance(),
             })
             .collect(),
+        warnings,
     };
 
-    let json = serde_json::to_string_pretty(&output)?;
-    println!("{}", json);
-    Ok(())
+    Ok(serde_json::to_string_pretty(&output)?)
 }
 
 /// Render the monthly analytics as CSV suitable for spreadsheets or further processing.
 ///
 /// # Examples
 /// ```rust,no_run
-/// # use gh_log::{config::SizeConfig, data::MonthData};
-/// # fn run(data: MonthData, sizes: SizeConfig) -> anyhow::Result<()> {
-/// gh_log::view::print_csv(&data, &sizes)?;
+/// # use gh_log::{config::Config, data::MonthData};
+/// # fn run(data: MonthData, cfg: Config) -> anyhow::Result<()> {
+/// gh_log::view::print_csv(&data, &cfg)?;
 /// # Ok(())
 /// # }
 /// ```
 ///
 /// # Errors
 /// Returns an error if writing to stdout encounters an I/O failure.
-pub fn print_csv(data: &data::MonthData, size_cfg: &SizeConfig) -> anyhow::Result<()> {
-    println!(
-        "created_at,repo,number,title,body,lead_time_hours,size,additions,deletions,changed_files"
-    );
+pub fn print_csv(data: &data::MonthData, cfg: &Config) -> anyhow::Result<()> {
+    write_csv(stdout().lock(), data, cfg)
+}
 
-    for week_prs in &data.prs_by_week {
-        for pr in week_prs {
-            let lead_time_hours = pr.lead_time.num_seconds() as f64 / 3600.0;
-            let body_escaped = pr
-                .body
-                .as_ref()
-                .map(|b| b.replace("\"", "\"\"").replace("\n", " "))
-                .unwrap_or_default();
-            println!(
-                "{},{},{},\"{}\",\"{}\",{:.2},{},{},{},{}",
+/// Write the CSV rows of [`print_csv`] to any writer.
+///
+/// Quoting is left to the `csv` crate, so commas, quotes and line breaks in
+/// titles and bodies survive a round trip through a spreadsheet.
+///
+/// # Errors
+/// Returns an error if a record cannot be written.
+pub fn write_csv<W: std::io::Write>(
+    writer: W,
+    data: &data::MonthData,
+    cfg: &Config,
+) -> anyhow::Result<()> {
+    let size_cfg = &cfg.size;
+    let mut wtr = csv::Writer::from_writer(writer);
+    wtr.write_record([
+        "created_at",
+        "repo",
+        "number",
+        "title",
+        "body",
+        "lead_time_hours",
+        "size",
+        "additions",
+        "deletions",
+        "changed_files",
+        "linked_issues",
+    ])?;
+
+    for pr in data.sorted_prs(size_cfg) {
+        let lead_time_hours = pr.lead_time.num_seconds() as f64 / 3600.0;
+        wtr.write_record([
+            format_date(pr.created_at),
+            pr.repo.clone(),
+            pr.number.to_string(),
+            pr.title.clone(),
+            pr.body.clone().unwrap_or_default(),
+            format!("{lead_time_hours:.2}"),
+            size_label(pr.size(size_cfg), size_cfg).to_string(),
+            pr.additions.to_string(),
+            pr.deletions.to_string(),
+            pr.changed_files.to_string(),
+            // Space-separated, so several issues share one cell.
+            pr.linked_issues.join(" "),
+        ])?;
+    }
+
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Side-by-side table of two reports (`gh-log compare`): each metric in `a` and `b`, then the
+/// change and the change in percent, e.g. "Total PRs  12  15  +3  +25.0%".
+pub fn comparison_string(cmp: &data::MonthComparison, a: &str, b: &str) -> String {
+    let rows: Vec<[String; 5]> = cmp
+        .metrics
+        .iter()
+        .map(|delta| {
+            [
+                delta.metric.to_string(),
+                format_metric(delta.unit, delta.a),
+                format_metric(delta.unit, delta.b),
+                format_metric_change(delta.unit, delta.change()),
+                delta
+                    .percent_change()
+                    .map_or_else(|| "n/a".to_string(), |pct| format!("{:+.1}%", pct)),
+            ]
+        })
+        .collect();
+    let header = [
+        "Metric".to_string(),
+        a.to_string(),
+        b.to_string(),
+        "Change".to_string(),
+        "%".to_string(),
+    ];
+    let mut widths = header.clone().map(|cell| cell.width());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.width());
+        }
+    }
+
+    let mut out = format!("Comparing {} to {}\n", a, b);
+    for row in std::iter::once(&header).chain(&rows) {
+        let mut line = format!("  {}", pad(&row[0], widths[0]));
+        for (cell, width) in row.iter().zip(widths).skip(1) {
+            line.push_str(&format!("  {:>w$}", cell, w = width));
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// JSON form of [`comparison_string`]: the two labels and one entry per metric, with `null`
+/// for a percentage against a zero baseline.
+///
+/// # Errors
+/// Returns an error if serialization fails.
+pub fn comparison_json(cmp: &data::MonthComparison, a: &str, b: &str) -> anyhow::Result<String> {
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct JsonComparison<'a> {
+        a: &'a str,
+        b: &'a str,
+        metrics: Vec<JsonMetric>,
+    }
+
+    #[derive(Serialize)]
+    struct JsonMetric {
+        metric: &'static str,
+        /// `count`, `hours`, or `per_week`.
+        unit: &'static str,
+        a: f64,
+        b: f64,
+        change: f64,
+        percent_change: Option<f64>,
+    }
+
+    let output = JsonComparison {
+        a,
+        b,
+        metrics: cmp
+            .metrics
+            .iter()
+            .map(|delta| JsonMetric {
+                metric: delta.metric,
+                unit: match delta.unit {
+                    data::MetricUnit::Count => "count",
+                    data::MetricUnit::Hours => "hours",
+                    data::MetricUnit::PerWeek => "per_week",
+                },
+                a: delta.a,
+                b: delta.b,
+                change: delta.change(),
+                percent_change: delta.percent_change(),
+            })
+            .collect(),
+    };
+    Ok(serde_json::to_string_pretty(&output)?)
+}
+
+fn format_metric(unit: data::MetricUnit, value: f64) -> String {
+    match unit {
+        data::MetricUnit::Count => format!("{:.0}", value),
+        data::MetricUnit::Hours => format_duration(Duration::seconds((value * 3600.0) as i64)),
+        data::MetricUnit::PerWeek => format!("{:.1}/wk", value),
+    }
+}
+
+fn format_metric_change(unit: data::MetricUnit, change: f64) -> String {
+    let sign = if change < 0.0 { "-" } else { "+" };
+    format!("{}{}", sign, format_metric(unit, change.abs()))
+}
+
+/// A short prose summary of the month for status emails (`gh-log digest`), e.g. "In 2026-01 you
+/// opened 2 PRs across 1 repo, averaging 2h 0m lead time; your biggest was test/repo#2 ...".
+///
+/// The biggest PR is the one with the most lines changed; the review sentence is left out when
+/// the review count was not fetched.
+pub fn digest(data: &data::MonthData, month: &str) -> String {
+    let plural = |n: usize, word: &str| {
+        if n == 1 {
+            format!("1 {}", word)
+        } else {
+            format!("{} {}s", n, word)
+        }
+    };
+
+    let mut text = if data.total_prs == 0 {
+        format!("In {} you opened no PRs.", month)
+    } else {
+        let mut opened = format!(
+            "In {} you opened {} across {}, averaging {} lead time",
+            month,
+            plural(data.total_prs, "PR"),
+            plural(data.repos.len(), "repo"),
+            format_duration(data.avg_lead_time)
+        );
+        // Only merged PRs that count toward the totals above can be the biggest.
+        let biggest = data
+            .prs_by_week
+            .iter()
+            .flatten()
+            .filter(|pr| !pr.ignored && pr.merged_at.is_some())
+            .max_by_key(|pr| pr.additions + pr.deletions);
+        if let Some(pr) = biggest {
+            opened.push_str(&format!(
+                "; your biggest was {}#{} \"{}\" (+{}/-{})",
+                pr.repo, pr.number, pr.title, pr.additions, pr.deletions
+            ));
+        }
+        opened.push('.');
+        opened
+    };
+    if let Some(reviewed) = data.reviewed_count {
+        text.push_str(&format!(" You reviewed {}.", plural(reviewed, "PR")));
+    }
+    text
+}
+
+/// Print the report as GitHub-flavored Markdown (`print --markdown`), ready to paste into a
+/// review doc.
+pub fn print_markdown(data: &data::MonthData, cfg: &Config) {
+    print!("{}", markdown_string(data, cfg));
+}
+
+/// The PR sections markdown and HTML list: one flat list in `--sort` order, otherwise one
+/// section per week (or month, for multi-month ranges).
+fn pr_sections<'a>(
+    data: &'a data::MonthData,
+    size_cfg: &SizeConfig,
+) -> Vec<(String, Vec<&'a PRDetail>)> {
+    if let Some(order) = data.pr_order {
+        return vec![(order.title(), data.sorted_prs(size_cfg))];
+    }
+    data.weeks
+        .iter()
+        .zip(&data.prs_by_week)
+        .map(|(week, prs)| {
+            let title = if data.monthly_buckets {
+                week.week_start.format("%B %Y").to_string()
+            } else {
+                format!(
+                    "Week {} ({} - {})",
+                    week.week_num,
+                    format_date(week.week_start),
+                    format_date(week.week_end)
+                )
+            };
+            (title, prs.iter().collect())
+        })
+        .collect()
+}
+
+/// The Markdown document [`print_markdown`] writes: the headline numbers, a PR table per week
+/// (or per month for `--all-cached`), then repository and reviewer tables.
+pub fn markdown_string(data: &data::MonthData, cfg: &Config) -> String {
+    let size_cfg = &cfg.size;
+    let plain = Palette {
+        enabled: false,
+        hyperlinks: false,
+    };
+    let mut out = format!("# GitHub PRs for {}\n\n", report_label(data));
+    out.push_str(&format!("- **Total PRs:** {}\n", data.total_prs));
+    out.push_str(&format!(
+        "- **Average Lead Time:** {}\n",
+        format_duration(data.avg_lead_time)
+    ));
+    out.push_str(&format!(
+        "- **Frequency:** {:.1} PRs/week\n",
+        data.frequency
+    ));
+    out.push_str(&format!(
+        "- **Sizes:** {}\n",
+        plain.size_counts(
+            size_cfg,
+            data.size_s,
+            data.size_m,
+            data.size_l,
+            data.size_xl,
+            data.size_unknown
+        )
+    ));
+    if let Some(reviewed) = data.reviewed_count {
+        out.push_str(&format!("- **Reviewed:** {}\n", reviewed));
+    }
+
+    for (title, prs) in pr_sections(data, size_cfg) {
+        out.push_str(&format!("\n## {}\n\n", title));
+        if prs.is_empty() {
+            out.push_str("_No PRs._\n");
+            continue;
+        }
+        out.push_str("| Date | Repo | # | Title | Lead Time | Size |\n");
+        out.push_str("|------|------|---|-------|-----------|------|\n");
+        for pr in prs {
+            out.push_str(&format!(
+                "| {} | {} | [#{}]({}) | {} | {} | {} |\n",
                 format_date(pr.created_at),
-                pr.repo,
+                markdown_cell(&pr.repo),
                 pr.number,
-                pr.title.replace("\"", "\"\""), // Escape quotes in CSV
-                body_escaped,
-                lead_time_hours,
-                pr.size(size_cfg),
-                pr.additions,
-                pr.deletions,
-                pr.changed_files
-            );
+                pr.url(),
+                markdown_cell(&pr.title),
+                format_duration(pr.lead_time),
+                size_label(pr.size(size_cfg), size_cfg)
+            ));
+        }
+    }
+
+    out.push_str("\n## Repositories\n\n");
+    out.push_str("| Repo | PRs | Avg Lead Time | Sizes |\n");
+    out.push_str("|------|-----|---------------|-------|\n");
+    for repo in &data.repos {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            markdown_cell(&repo.name),
+            repo.pr_count,
+            format_duration(repo.avg_lead_time),
+            repo.format_size_distribution(size_cfg)
+        ));
+    }
+
+    if !data.reviewers.is_empty() {
+        out.push_str("\n## Top Reviewers\n\n");
+        out.push_str("| Reviewer | PRs |\n");
+        out.push_str("|----------|-----|\n");
+        for reviewer in &data.reviewers {
+            out.push_str(&format!(
+                "| {} | {} |\n",
+                markdown_cell(&reviewer.login),
+                reviewer.pr_count
+            ));
         }
     }
+    out
+}
 
+/// `text` made safe for one Markdown table cell: pipes escaped, line breaks flattened.
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Print the report as a self-contained HTML page (`print --html`) that opens directly in a
+/// browser.
+pub fn print_html(data: &data::MonthData, cfg: &Config) {
+    print!("{}", html_string(data, cfg));
+}
+
+/// Inline stylesheet for [`html_string`]. Size badges use the TUI's S/M/L/XL colors.
+const HTML_STYLE: &str = "\
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; color: #1f2328; max-width: 980px; margin: 2rem auto; padding: 0 1rem; }
+h1 { margin-bottom: 1rem; }
+h2 { margin-top: 2rem; border-bottom: 1px solid #d0d7de; padding-bottom: 0.3rem; }
+.cards { display: flex; flex-wrap: wrap; gap: 0.75rem; }
+.card { border: 1px solid #d0d7de; border-radius: 6px; padding: 0.6rem 1rem; min-width: 8rem; }
+.card .value { font-size: 1.4rem; font-weight: 600; }
+.card .label { color: #59636e; font-size: 0.85rem; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; vertical-align: top; padding: 0.35rem 0.5rem; border-bottom: 1px solid #d0d7de; }
+th { background: #f6f8fa; }
+a { color: #0969da; text-decoration: none; }
+details pre { white-space: pre-wrap; font-size: 0.85rem; color: #59636e; margin: 0.3rem 0; }
+.empty { color: #59636e; font-style: italic; }
+.size { display: inline-block; min-width: 1.4rem; padding: 0 0.4rem; border-radius: 1rem; color: #fff; font-size: 0.8rem; font-weight: 600; text-align: center; }
+.size-s { background: #1a7f37; }
+.size-m { background: #0969da; }
+.size-l { background: #bf8700; }
+.size-xl { background: #cf222e; }
+.size-unknown { background: #8250df; }
+";
+
+/// The page [`print_html`] writes: summary cards, a PR table per week (or per month for
+/// `--all-cached`), then repository and reviewer tables. Titles, bodies and names are escaped.
+pub fn html_string(data: &data::MonthData, cfg: &Config) -> String {
+    let size_cfg = &cfg.size;
+    let badge = |size: PRSize, text: &str| {
+        let class = match size {
+            PRSize::S => "s",
+            PRSize::M => "m",
+            PRSize::L => "l",
+            PRSize::XL => "xl",
+            PRSize::Unknown => "unknown",
+        };
+        format!(
+            "<span class=\"size size-{}\">{}</span>",
+            class,
+            html_escape(text)
+        )
+    };
+    let title = format!("GitHub PRs for {}", report_label(data));
+    let mut out = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+    out.push_str("<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{}</title>\n", html_escape(&title)));
+    out.push_str(&format!("<style>\n{}</style>\n", HTML_STYLE));
+    out.push_str("</head>\n<body>\n");
+    out.push_str(&format!("<h1>{}</h1>\n", html_escape(&title)));
+
+    let mut cards = vec![
+        ("Total PRs", data.total_prs.to_string()),
+        ("Average Lead Time", format_duration(data.avg_lead_time)),
+        ("Frequency", format!("{:.1} PRs/week", data.frequency)),
+    ];
+    if let Some(reviewed) = data.reviewed_count {
+        cards.push(("Reviewed", reviewed.to_string()));
+    }
+    out.push_str("<div class=\"cards\">\n");
+    for (label, value) in cards {
+        out.push_str(&format!(
+            "<div class=\"card\"><div class=\"value\">{}</div><div class=\"label\">{}</div></div>\n",
+            html_escape(&value),
+            label
+        ));
+    }
+    let sizes = [
+        (PRSize::S, data.size_s),
+        (PRSize::M, data.size_m),
+        (PRSize::L, data.size_l),
+        (PRSize::XL, data.size_xl),
+        (PRSize::Unknown, data.size_unknown),
+    ]
+    .into_iter()
+    .filter(|&(size, count)| size != PRSize::Unknown || count > 0)
+    .map(|(size, count)| format!("{} {}", count, badge(size, size_label(size, size_cfg))))
+    .collect::<Vec<_>>()
+    .join(" ");
+    out.push_str(&format!(
+        "<div class=\"card\"><div class=\"value\">{}</div><div class=\"label\">Sizes</div></div>\n",
+        sizes
+    ));
+    out.push_str("</div>\n");
+
+    for (heading, prs) in pr_sections(data, size_cfg) {
+        out.push_str(&format!("<h2>{}</h2>\n", heading));
+        if prs.is_empty() {
+            out.push_str("<p class=\"empty\">No PRs.</p>\n");
+            continue;
+        }
+        out.push_str("<table>\n<tr><th>Date</th><th>Repo</th><th>#</th><th>Title</th><th>Lead Time</th><th>Size</th></tr>\n");
+        for pr in prs {
+            let mut title = html_escape(&pr.title);
+            if let Some(body) = pr.body.as_deref().filter(|body| !body.trim().is_empty()) {
+                title.push_str(&format!(
+                    "<details><summary>Description</summary><pre>{}</pre></details>",
+                    html_escape(body.trim())
+                ));
+            }
+            out.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td><a href=\"{}\">#{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                format_date(pr.created_at),
+                html_escape(&pr.repo),
+                html_escape(&pr.url()),
+                pr.number,
+                title,
+                format_duration(pr.lead_time),
+                badge(pr.size(size_cfg), size_label(pr.size(size_cfg), size_cfg))
+            ));
+        }
+        out.push_str("</table>\n");
+    }
+
+    out.push_str("<h2>Repositories</h2>\n");
+    out.push_str(
+        "<table>\n<tr><th>Repo</th><th>PRs</th><th>Avg Lead Time</th><th>Sizes</th></tr>\n",
+    );
+    for repo in &data.repos {
+        out.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            html_escape(&repo.name),
+            repo.pr_count,
+            format_duration(repo.avg_lead_time),
+            html_escape(&repo.format_size_distribution(size_cfg))
+        ));
+    }
+    out.push_str("</table>\n");
+
+    if !data.reviewers.is_empty() {
+        out.push_str("<h2>Top Reviewers</h2>\n");
+        out.push_str("<table>\n<tr><th>Reviewer</th><th>PRs</th></tr>\n");
+        for reviewer in &data.reviewers {
+            out.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td></tr>\n",
+                html_escape(&reviewer.login),
+                reviewer.pr_count
+            ));
+        }
+        out.push_str("</table>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// `text` with the characters HTML treats as markup replaced by entities.
+fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// `--format table` width when stdout is not a terminal and no `--width` is given.
+const DEFAULT_TABLE_WIDTH: usize = 100;
+/// Narrowest the title column gets before the table simply runs wider than asked.
+const MIN_TABLE_TITLE_WIDTH: usize = 10;
+/// Longest repo name shown in full; longer ones are cut so titles keep some room.
+const MAX_TABLE_REPO_WIDTH: usize = 30;
+
+/// Print the month's PRs as a bordered table (`print --format table`), `width` columns wide or as
+/// wide as the terminal, with colored sizes when `color` is set.
+///
+/// # Errors
+/// Returns an error if writing to stdout encounters an I/O failure.
+pub fn print_table(
+    data: &data::MonthData,
+    cfg: &Config,
+    width: Option<usize>,
+    color: bool,
+) -> anyhow::Result<()> {
+    use std::io::{IsTerminal, Write};
+    let width = width.unwrap_or_else(|| {
+        stdout()
+            .is_terminal()
+            .then(|| crossterm::terminal::size().ok())
+            .flatten()
+            .map_or(DEFAULT_TABLE_WIDTH, |(columns, _)| columns as usize)
+    });
+    write!(stdout(), "{}", table_string(data, cfg, width, color))?;
     Ok(())
 }
 
-/// Render a human-readable summary of the monthly analytics directly to stdout.
-pub fn print_data(data: &data::MonthData, month: &str, size_cfg: &SizeConfig) {
-    println!("GitHub PRs for {}", month);
-    println!("  - Total PRs: {}", data.total_prs);
+/// The table [`print_table`] writes: one row per PR with date, repo, number, title, lead time and
+/// size. The title column takes whatever `width` the other columns leave; sizes are colored when
+/// `color` is set.
+pub fn table_string(data: &data::MonthData, cfg: &Config, width: usize, color: bool) -> String {
+    let palette = Palette {
+        enabled: color,
+        hyperlinks: false,
+    };
+    let prs = data.sorted_prs(&cfg.size);
+    let column_width = |header: &str, values: &mut dyn Iterator<Item = usize>| {
+        values.fold(header.chars().count(), usize::max)
+    };
+    let date_width = 10;
+    let repo_width =
+        column_width("Repo", &mut prs.iter().map(|pr| pr.repo.width())).min(MAX_TABLE_REPO_WIDTH);
+    let number_width = column_width("#", &mut prs.iter().map(|pr| pr.number.to_string().len()));
+    let lead_width = column_width(
+        "Lead",
+        &mut prs
+            .iter()
+            .map(|pr| format_duration(pr.lead_time).chars().count()),
+    );
+    let size_width = column_width(
+        "Size",
+        &mut prs
+            .iter()
+            .map(|pr| size_label(pr.size(&cfg.size), &cfg.size).chars().count()),
+    );
+    // Seven borders plus one space of padding either side of each of the six cells.
+    let fixed = 7 + 2 * 6 + date_width + repo_width + number_width + lead_width + size_width;
+    let title_width = width.saturating_sub(fixed).max(MIN_TABLE_TITLE_WIDTH);
+    let widths = [
+        date_width,
+        repo_width,
+        number_width,
+        title_width,
+        lead_width,
+        size_width,
+    ];
+
+    let rule = |left: &str, middle: &str, right: &str| {
+        let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+        format!("{}{}{}\n", left, segments.join(middle), right)
+    };
+    let row = |cells: [String; 6]| {
+        let cells: Vec<String> = cells
+            .into_iter()
+            .map(|cell| format!(" {} ", cell))
+            .collect();
+        format!("│{}│\n", cells.join("│"))
+    };
+
+    let mut out = rule("┌", "┬", "┐");
+    out.push_str(&row([
+        format!("{:date_width$}", "Date"),
+        format!("{:repo_width$}", "Repo"),
+        format!("{:>number_width$}", "#"),
+        format!("{:title_width$}", "Title"),
+        format!("{:lead_width$}", "Lead"),
+        format!("{:size_width$}", "Size"),
+    ]));
+    out.push_str(&rule("├", "┼", "┤"));
+    for pr in prs {
+        let pr_size = pr.size(&cfg.size);
+        out.push_str(&row([
+            format_date(pr.created_at),
+            truncate(&pr.repo, repo_width),
+            format!("{:>number_width$}", pr.number),
+            truncate(&pr.title, title_width),
+            format!("{:lead_width$}", format_duration(pr.lead_time)),
+            palette.size(
+                pr_size,
+                format!("{:size_width$}", size_label(pr_size, &cfg.size)),
+            ),
+        ]));
+    }
+    out.push_str(&rule("└", "┴", "┘"));
+    out
+}
+
+const ANSI_RED: &str = "31";
+const ANSI_GREEN: &str = "32";
+const ANSI_YELLOW: &str = "33";
+const ANSI_BLUE: &str = "34";
+const ANSI_MAGENTA: &str = "35";
+const ANSI_DARK_GRAY: &str = "90";
+const ANSI_BOLD: &str = "1";
+
+/// Whether `print` should emit ANSI colors: only when stdout is a terminal and `NO_COLOR` is unset
+/// or empty (<https://no-color.org>), so pipes and redirects get plain text.
+pub fn should_colorize() -> bool {
+    use std::io::IsTerminal;
+    std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()) && stdout().is_terminal()
+}
+
+/// `--color` choices for the plain-text and table output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Always emit ANSI colors, e.g. for less -R
+    Always,
+    /// Color when stdout is a terminal and NO_COLOR is unset
+    #[default]
+    Auto,
+    /// Never emit colors
+    Never,
+}
+
+impl ColorChoice {
+    /// Resolve the choice against the environment; `always` and `never` override `NO_COLOR`.
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Auto => should_colorize(),
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+/// ANSI styling for the plain-text report, using the TUI's palette. Disabled, every method returns
+/// the text unchanged; `hyperlinks` only takes effect while enabled.
+#[derive(Debug, Clone, Copy)]
+struct Palette {
+    enabled: bool,
+    hyperlinks: bool,
+}
+
+impl Palette {
+    /// `text` as an OSC 8 hyperlink to `url`; terminals without OSC 8 support show just the text.
+    fn link(self, url: &str, text: impl std::fmt::Display) -> String {
+        if self.enabled && self.hyperlinks {
+            osc8_link(url, text)
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn paint(self, code: &str, text: impl std::fmt::Display) -> String {
+        if self.enabled {
+            format!("\x1b[{}m{}\x1b[0m", code, text)
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn size(self, size: PRSize, text: impl std::fmt::Display) -> String {
+        let code = match size {
+            PRSize::S => ANSI_GREEN,
+            PRSize::M => ANSI_BLUE,
+            PRSize::L => ANSI_YELLOW,
+            PRSize::XL => ANSI_RED,
+            PRSize::Unknown => ANSI_MAGENTA,
+        };
+        self.paint(code, text)
+    }
+
+    /// Size counts as "2S 1M 0L 0XL", each bucket in its size color.
+    fn size_counts(
+        self,
+        sizes: &SizeConfig,
+        s: usize,
+        m: usize,
+        l: usize,
+        xl: usize,
+        unknown: usize,
+    ) -> String {
+        let labels = &sizes.labels;
+        let mut out = [
+            self.size(PRSize::S, format!("{}{}", s, labels.s)),
+            self.size(PRSize::M, format!("{}{}", m, labels.m)),
+            self.size(PRSize::L, format!("{}{}", l, labels.l)),
+            self.size(PRSize::XL, format!("{}{}", xl, labels.xl)),
+        ]
+        .join(" ");
+        if unknown > 0 {
+            out.push(' ');
+            out.push_str(&self.size(PRSize::Unknown, format!("{}?", unknown)));
+        }
+        out
+    }
+}
+
+fn print_pr_line(pr: &PRDetail, cfg: &Config, palette: Palette, max_body_lines: Option<usize>) {
+    let size_cfg = &cfg.size;
+    let pr_size = pr.size(size_cfg);
+    let matched = pr
+        .matched_by
+        .map(|matched| palette.paint(ANSI_DARK_GRAY, format!(" [{}]", matched.label())))
+        .unwrap_or_default();
+    let open = if pr.open {
+        palette.paint(ANSI_DARK_GRAY, " [open]")
+    } else {
+        String::new()
+    };
     println!(
-        "  - Average Lead Time: {}",
-        format_duration(data.avg_lead_time)
+        "    - {} | {} | {} {} | {} | {}{}{}",
+        palette.paint(ANSI_DARK_GRAY, format_date(pr.created_at)),
+        palette.paint(ANSI_BLUE, &pr.repo),
+        palette.link(
+            &pr.url(),
+            palette.paint(ANSI_DARK_GRAY, format!("#{}", pr.number))
+        ),
+        pr.title,
+        palette.paint(ANSI_YELLOW, format_duration(pr.lead_time)),
+        palette.size(pr_size, size_label(pr_size, size_cfg)),
+        matched,
+        open
     );
+    if !pr.linked_issues.is_empty() {
+        let issues: Vec<String> = pr
+            .linked_issues
+            .iter()
+            .map(|id| match cfg.display.issue_url(id) {
+                Some(url) => format!("{} ({})", id, url),
+                None => id.clone(),
+            })
+            .collect();
+        println!("      Issues: {}", issues.join(", "));
+    }
+    if let Some(body) = &pr.body
+        && !body.is_empty()
+    {
+        let (lines, hidden) = clamp_body(body, max_body_lines);
+        for line in lines {
+            println!("      {}", line);
+        }
+        if hidden > 0 {
+            println!(
+                "      {}",
+                palette.paint(ANSI_DARK_GRAY, format!("… (+{} more lines)", hidden))
+            );
+        }
+    }
+}
+
+/// The first `max_lines` lines of `body` (all of them for `None`) and how many were cut.
+fn clamp_body(body: &str, max_lines: Option<usize>) -> (Vec<&str>, usize) {
+    let lines: Vec<&str> = body.lines().collect();
+    let keep = max_lines.unwrap_or(lines.len()).min(lines.len());
+    let hidden = lines.len() - keep;
+    (lines.into_iter().take(keep).collect(), hidden)
+}
+
+/// Render a human-readable summary of the monthly analytics directly to stdout, with ANSI colors
+/// when `color` is set (see [`ColorChoice::enabled`]) and PR numbers as OSC 8 links to GitHub when
+/// `hyperlinks` is set too. PR descriptions are cut to `max_body_lines` lines when set; `0` leaves
+/// them out.
+#[allow(clippy::too_many_arguments)]
+pub fn print_data(
+    data: &data::MonthData,
+    month: &str,
+    cfg: &Config,
+    warnings: &[String],
+    group_by: GroupBy,
+    date_field: DateField,
+    max_body_lines: Option<usize>,
+    color: bool,
+    hyperlinks: bool,
+) {
+    let size_cfg = &cfg.size;
+    let balance_base = cfg.analytics.review_balance_base;
+    let palette = Palette {
+        enabled: color,
+        hyperlinks,
+    };
+    let heading = |text: String| palette.paint(ANSI_BOLD, text);
+    let count = |n: usize| palette.paint(ANSI_GREEN, n);
+    let lead_time = |d: Duration| palette.paint(ANSI_YELLOW, format_duration(d));
+
+    println!("{}", heading(format!("GitHub PRs for {}", month)));
+    for warning in warnings {
+        println!("  {}", palette.paint(ANSI_YELLOW, format!("⚠ {}", warning)));
+    }
+    println!("  - Total PRs: {}", count(data.total_prs));
+    println!("  - Average Lead Time: {}", lead_time(data.avg_lead_time));
+    if let Some(wait) = data.avg_time_to_first_review {
+        println!(
+            "  - Avg Time to First Review: {} (reviewed PRs only)",
+            lead_time(wait)
+        );
+    }
+    if data.open_prs > 0 {
+        println!(
+            "  - Open PRs: {} (still running, left out of lead-time figures)",
+            count(data.open_prs)
+        );
+    }
     println!("  - Frequency: {:.1} PRs/week", data.frequency);
-    println!("  - Sizes: [{}]", data.format_size_distribution());
+    println!(
+        "  - Add:Delete: {} ({})",
+        format_add_delete_ratio(data.additions, data.deletions),
+        format_line_totals(data.additions, data.deletions)
+    );
+    println!(
+        "  - Sizes: [{}]",
+        palette.size_counts(
+            size_cfg,
+            data.size_s,
+            data.size_m,
+            data.size_l,
+            data.size_xl,
+            data.size_unknown
+        )
+    );
+    if let Some(extremes) = &data.extremes {
+        for (label, pr) in [
+            ("Fastest", &extremes.fastest),
+            ("Slowest", &extremes.slowest),
+        ] {
+            println!(
+                "  - {}: {} {} ({})",
+                label,
+                palette.paint(ANSI_BLUE, format!("{}#{}", pr.repo, pr.number)),
+                pr.title,
+                lead_time(pr.lead_time)
+            );
+        }
+    }
+    if let Some(first) = &data.first_pr {
+        println!(
+            "  - First PR: {} {} {}",
+            first.describe(),
+            palette.paint(ANSI_BLUE, format!("{}#{}", first.pr.repo, first.pr.number)),
+            first.pr.title
+        );
+    }
     println!();
 
+    if !data.authors.is_empty() {
+        println!("{}", heading("Authors".to_string()));
+        for line in author_table(&data.authors) {
+            println!("  {}", line);
+        }
+        println!();
+    }
+
     if !data.reviewers.is_empty() {
-        println!("Top Reviewers");
+        println!("{}", heading("Top Reviewers".to_string()));
         for reviewer in data.reviewers.iter().take(10) {
-            println!("  - {}: {} PRs", reviewer.login, reviewer.pr_count);
+            println!(
+                "  - {}: {} PRs",
+                palette.paint(ANSI_BLUE, &reviewer.login),
+                count(reviewer.pr_count)
+            );
         }
         println!();
     }
 
-    println!("My Review Activity");
-    println!("  - PRs Reviewed: {}", data.reviewed_count);
-    if data.total_prs > 0 {
-        let ratio = data.reviewed_count as f64 / data.total_prs as f64;
-        println!(
-            "  - Review Balance: {:.1}:1 ({} reviewed / {} created)",
-            ratio, data.reviewed_count, data.total_prs
-        );
+    if let Some(reviewed_count) = data.reviewed_count {
+        println!("{}", heading("My Review Activity".to_string()));
+        println!("  - PRs Reviewed: {}", count(reviewed_count));
+        let denominator = data.review_balance_denominator(balance_base);
+        if denominator > 0 {
+            let balance = data.review_balance(balance_base);
+            let ratio = format!("{:.1}:1", balance);
+            let ratio = match cfg.review.meets_target(balance) {
+                Some(true) => palette.paint(ANSI_GREEN, ratio),
+                Some(false) => palette.paint(ANSI_RED, ratio),
+                None => ratio,
+            };
+            let target = cfg
+                .review
+                .target_ratio
+                .map(|target| format!(", target {:.1}:1", target))
+                .unwrap_or_default();
+            println!(
+                "  - Review Balance: {} ({} reviewed / {} {}{})",
+                ratio,
+                reviewed_count,
+                denominator,
+                balance_base.label(),
+                target
+            );
+        }
+        if let Some(turnaround) = &data.review_turnaround {
+            println!(
+                "  - Review Turnaround: median {}, mean {} (first review, {} PRs)",
+                lead_time(turnaround.median),
+                lead_time(turnaround.mean),
+                turnaround.count
+            );
+        }
+        println!();
     }
-    println!();
-
-    for (week_idx, week) in data.weeks.iter().enumerate() {
-        println!(
-            "Week {} ({} - {})",
-            week.week_num,
-            format_date(week.week_start),
-            format_date(week.week_end)
-        );
-        println!("  - PRs: {}", week.pr_count);
-        println!("  - Avg Lead Time: {}", format_duration(week.avg_lead_time));
 
-        let prs = &data.prs_by_week[week_idx];
-        for pr in prs {
+    if !data.months.is_empty() {
+        println!("{}", heading("Months".to_string()));
+        for month in &data.months {
             println!(
-                "    - {} | {} | #{} {} | {} | {}",
-                format_date(pr.created_at),
-                pr.repo,
-                pr.number,
-                pr.title,
-                format_duration(pr.lead_time),
-                pr.size(size_cfg)
+                "  - {} - {} PRs (Avg: {}) [{}]",
+                month.week_start.format("%B %Y"),
+                count(month.pr_count),
+                lead_time(month.avg_lead_time),
+                palette.size_counts(
+                    size_cfg,
+                    month.size_s,
+                    month.size_m,
+                    month.size_l,
+                    month.size_xl,
+                    month.size_unknown
+                )
             );
-            if let Some(body) = &pr.body
-                && !body.is_empty()
-            {
-                // Indent and display the full body
-                for line in body.lines() {
-                    println!("      {}", line);
-                }
-            }
         }
         println!();
     }
 
-    println!("Repositories");
+    if let Some(order) = data.pr_order {
+        let prs = data.sorted_prs(size_cfg);
+        println!("{}", heading(order.title()));
+        println!("  - PRs: {}", count(prs.len()));
+        for pr in prs {
+            print_pr_line(pr, cfg, palette, max_body_lines);
+        }
+        println!();
+    } else if group_by == GroupBy::Week {
+        for (week_idx, week) in data.weeks.iter().enumerate() {
+            let title = if data.monthly_buckets {
+                week.week_start.format("%B %Y").to_string()
+            } else {
+                format!(
+                    "Week {} ({} - {})",
+                    week.week_num,
+                    format_date(week.week_start),
+                    format_date(week.week_end)
+                )
+            };
+            println!("{}", heading(title));
+            println!("  - PRs: {}", count(week.pr_count));
+            println!("  - Avg Lead Time: {}", lead_time(week.avg_lead_time));
+            for pr in &data.prs_by_week[week_idx] {
+                print_pr_line(pr, cfg, palette, max_body_lines);
+            }
+            println!();
+        }
+    } else {
+        for group in data.group_prs(group_by, size_cfg, date_field) {
+            println!("{}", heading(group.title.clone()));
+            println!("  - PRs: {}", count(group.prs.len()));
+            println!("  - Avg Lead Time: {}", lead_time(group.avg_lead_time()));
+            for pr in &group.prs {
+                print_pr_line(pr, cfg, palette, max_body_lines);
+            }
+            println!();
+        }
+    }
+
+    println!("{}", heading("Repositories".to_string()));
     for repo in &data.repos {
+        let review = repo_review_summary(repo)
+            .map(|review| format!(" {}", review))
+            .unwrap_or_default();
         println!(
-            "  - {} - {} PRs (Avg: {}) [{}]",
-            repo.name,
-            repo.pr_count,
-            format_duration(repo.avg_lead_time),
-            repo.format_size_distribution()
+            "  - {} - {} PRs (Avg: {}) [{}]{}",
+            palette.paint(ANSI_BLUE, &repo.name),
+            count(repo.pr_count),
+            lead_time(repo.avg_lead_time),
+            palette.size_counts(
+                size_cfg,
+                repo.size_s,
+                repo.size_m,
+                repo.size_l,
+                repo.size_xl,
+                repo.size_unknown
+            ),
+            review
         );
     }
 }
 
+/// Aligned rows for the per-author table of a combined report, header first.
+fn author_table(authors: &[data::AuthorSummary]) -> Vec<String> {
+    let login_width = authors
+        .iter()
+        .map(|author| author.login.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Author".len());
+    let mut lines = vec![format!(
+        "{:<login_width$}  {:>5}  {:<13}  Review Balance",
+        "Author", "PRs", "Avg Lead Time"
+    )];
+    for author in authors {
+        let balance = match (author.reviewed_count, author.review_balance) {
+            (Some(reviewed), Some(balance)) => format!("{:.1}:1 ({} reviewed)", balance, reviewed),
+            (Some(reviewed), None) => format!("- ({} reviewed)", reviewed),
+            (None, _) => "-".to_string(),
+        };
+        lines.push(format!(
+            "{:<login_width$}  {:>5}  {:<13}  {}",
+            author.login,
+            author.total_prs,
+            format_duration(author.avg_lead_time),
+            balance
+        ));
+    }
+    lines
+}
+
+/// "Reviewed: 3 (1.5:1)" for repos with per-repo review counts; the ratio is reviewed per
+/// authored PR.
+fn repo_review_summary(repo: &data::RepoData) -> Option<String> {
+    let reviewed = repo.reviewed_count?;
+    Some(match repo.review_balance() {
+        Some(balance) => format!("Reviewed: {} ({:.1}:1)", reviewed, balance),
+        None => format!("Reviewed: {}", reviewed),
+    })
+}
+
 fn format_date(dt: chrono::DateTime<chrono::Utc>) -> String {
     dt.format("%Y-%m-%d").to_string()
 }
@@ -1252,9 +3837,15 @@ fn format_date(dt: chrono::DateTime<chrono::Utc>) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::SizeConfig;
     use chrono::Utc;
 
+    /// The user's config, read on a UTC clock so dates do not depend on the machine's timezone.
+    fn test_config() -> Config {
+        let mut config = Config::default().unwrap();
+        config.display.timezone = Some(crate::config::Timezone::Utc);
+        config
+    }
+
     fn create_test_month_data() -> data::MonthData {
         use chrono::TimeZone;
 
@@ -1271,6 +3862,7 @@ mod tests {
             size_m: 1,
             size_l: 0,
             size_xl: 0,
+            size_unknown: 0,
             weeks: vec![data::WeekData {
                 week_num: 1,
                 week_start,
@@ -1281,19 +3873,25 @@ mod tests {
                 size_m: 1,
                 size_l: 0,
                 size_xl: 0,
+                size_unknown: 0,
             }],
             repos: vec![data::RepoData {
                 name: "test/repo".to_string(),
                 pr_count: 2,
                 avg_lead_time: chrono::Duration::hours(2),
+                p50_lead_time: chrono::Duration::hours(1),
+                p90_lead_time: chrono::Duration::hours(3),
                 size_s: 1,
                 size_m: 1,
                 size_l: 0,
                 size_xl: 0,
+                size_unknown: 0,
+                reviewed_count: Some(1),
             }],
             prs_by_week: vec![vec![
                 data::PRDetail {
                     created_at: Utc.with_ymd_and_hms(2026, 1, 6, 10, 0, 0).unwrap(),
+                    merged_at: None,
                     repo: "test/repo".to_string(),
                     number: 1,
                     title: "Test PR 1".to_string(),
@@ -1302,44 +3900,174 @@ mod tests {
                     additions: 10,
                     deletions: 5,
                     changed_files: 2,
+                    linked_issues: vec![],
+                    labels: vec![],
+                    matched_by: None,
+                    ignored: false,
+                    open: false,
                 },
                 data::PRDetail {
                     created_at: Utc.with_ymd_and_hms(2026, 1, 7, 14, 0, 0).unwrap(),
+                    merged_at: None,
                     repo: "test/repo".to_string(),
                     number: 2,
                     title: "Test PR 2".to_string(),
                     body: None,
                     lead_time: chrono::Duration::hours(3),
-                    additions: 100,
-                    deletions: 50,
-                    changed_files: 5,
+                    additions: 100,
+                    deletions: 50,
+                    changed_files: 5,
+                    linked_issues: vec![],
+                    labels: vec![],
+                    matched_by: None,
+                    ignored: false,
+                    open: false,
+                },
+            ]],
+            prs_by_repo: vec![],
+            reviewers: vec![data::ReviewerData {
+                login: "alice".to_string(),
+                pr_count: 2,
+            }],
+            reviewer_prs: std::collections::BTreeMap::from([(
+                "alice".to_string(),
+                vec![("test/repo".to_string(), 2)],
+            )]),
+            reviewed_count: Some(5),
+            merged_prs: 2,
+            review_eligible_prs: 1,
+            truncated_reviews: 0,
+            additions: 110,
+            deletions: 55,
+            extremes: Some(data::LeadTimeExtremes {
+                fastest: data::PrHighlight {
+                    number: 1,
+                    title: "Test PR 1".to_string(),
+                    repo: "test/repo".to_string(),
+                    lead_time: chrono::Duration::hours(1),
+                },
+                slowest: data::PrHighlight {
+                    number: 2,
+                    title: "Test PR 2".to_string(),
+                    repo: "test/repo".to_string(),
+                    lead_time: chrono::Duration::hours(3),
                 },
-            ]],
-            prs_by_repo: vec![],
-            reviewers: vec![data::ReviewerData {
-                login: "alice".to_string(),
-                pr_count: 2,
-            }],
-            reviewed_count: 5,
+            }),
+            lead_time_histogram: vec![
+                data::LeadTimeBucket {
+                    label: "<1h".to_string(),
+                    count: 0,
+                },
+                data::LeadTimeBucket {
+                    label: "1h–4h".to_string(),
+                    count: 2,
+                },
+                data::LeadTimeBucket {
+                    label: "4h+".to_string(),
+                    count: 0,
+                },
+            ],
+            first_pr: Some(data::FirstPr {
+                pr: data::PrHighlight {
+                    number: 1,
+                    title: "Test PR 1".to_string(),
+                    repo: "test/repo".to_string(),
+                    lead_time: chrono::Duration::hours(1),
+                },
+                at: Utc.with_ymd_and_hms(2026, 1, 6, 10, 0, 0).unwrap(),
+                days_in: 5,
+            }),
+            open_prs: 0,
+            avg_time_to_first_review: None,
+            authors: Vec::new(),
+            review_turnaround: None,
+            monthly_buckets: false,
+            months: Vec::new(),
+            window: None,
+            pr_order: None,
+            pr_limit: None,
+            skewed_prs: Vec::new(),
         }
     }
 
     #[test]
     fn test_print_json_output() {
         let data = create_test_month_data();
-        let size_config = SizeConfig::default();
-        let result = print_json(&data, &size_config);
+        let cfg = test_config();
+        let result = print_json(&data, &cfg, &[]);
         assert!(result.is_ok(), "JSON output should succeed");
     }
 
+    #[test]
+    fn test_json_lists_pr_labels() {
+        let mut data = create_test_month_data();
+        data.prs_by_week[0][0].labels = vec!["bug".to_string()];
+        let cfg = test_config();
+        let json: serde_json::Value =
+            serde_json::from_str(&json_string(&data, &cfg, &[]).unwrap()).unwrap();
+        let prs = &json["weeks"][0]["prs"];
+        assert_eq!(prs[0]["labels"], serde_json::json!(["bug"]));
+        assert_eq!(prs[1]["labels"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_sort_lists_prs_flat_in_json_and_markdown() {
+        let cfg = test_config();
+        let mut data = create_test_month_data();
+        data.sort_prs(data::PrOrder {
+            key: data::PrSort::Date,
+            descending: true,
+        });
+        let json: serde_json::Value =
+            serde_json::from_str(&json_string(&data, &cfg, &[]).unwrap()).unwrap();
+        let numbers: Vec<_> = json["prs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|pr| pr["number"].as_u64().unwrap())
+            .collect();
+        assert_eq!(numbers, [2, 1]);
+        assert_eq!(json["weeks"][0]["prs"][0]["number"], 1);
+
+        let markdown = markdown_string(&data, &cfg);
+        assert!(markdown.contains("## PRs by date (descending)"));
+        assert!(!markdown.contains("## Week"));
+    }
+
     #[test]
     fn test_print_csv_output() {
         let data = create_test_month_data();
-        let size_config = SizeConfig::default();
-        let result = print_csv(&data, &size_config);
+        let cfg = test_config();
+        let result = print_csv(&data, &cfg);
         assert!(result.is_ok(), "CSV output should succeed");
     }
 
+    #[test]
+    fn test_csv_round_trips_commas_quotes_and_newlines() {
+        let mut data = create_test_month_data();
+        let pr = &mut data.prs_by_week[0][0];
+        pr.title = "fix: parse \"a, b\" pairs".to_string();
+        pr.body = Some("First line\nSecond, with comma\n\n\"Quoted\" line".to_string());
+        let (title, body) = (pr.title.clone(), pr.body.clone().unwrap());
+        let cfg = test_config();
+
+        let mut out = Vec::new();
+        write_csv(&mut out, &data, &cfg).unwrap();
+
+        let mut reader = csv::Reader::from_reader(out.as_slice());
+        let headers = reader.headers().unwrap().clone();
+        assert_eq!(headers.len(), 11);
+        assert_eq!(&headers[3], "title");
+        let record = reader.records().next().unwrap().unwrap();
+        assert_eq!(record.len(), 11);
+        assert_eq!(&record[1], "test/repo");
+        assert_eq!(&record[3], title);
+        assert_eq!(&record[4], body);
+        let rows = reader.records().count() + 1;
+        let total: usize = data.prs_by_week.iter().map(Vec::len).sum();
+        assert_eq!(rows, total, "every PR is one record");
+    }
+
     #[test]
     fn test_format_duration() {
         assert_eq!(format_duration(chrono::Duration::minutes(30)), "30m");
@@ -1415,6 +4143,194 @@ mod tests {
         assert!(matches!(result.current_view(), View::Tail));
     }
 
+    #[test]
+    fn test_update_show_trend_changes_view() {
+        let state = AppState::new();
+
+        let result = update(Msg::ShowTrend, state);
+        assert!(matches!(result.current_view(), View::Trend));
+
+        use crossterm::event::{KeyEvent, KeyModifiers};
+        let shift_t = KeyEvent::new(KeyCode::Char('T'), KeyModifiers::SHIFT);
+        assert_eq!(key_message(&result, shift_t), Some(Msg::ShowTrend));
+    }
+
+    #[test]
+    fn test_trend_points_label_months_and_skip_empty_lead_time() {
+        use chrono::TimeZone;
+
+        let busy = create_test_month_data();
+        let mut quiet = create_test_month_data();
+        quiet.month_start = Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap();
+        quiet.total_prs = 0;
+        quiet.avg_lead_time = Duration::zero();
+
+        let points = trend_points(&[busy, quiet]);
+        assert_eq!(
+            points,
+            [
+                TrendPoint {
+                    label: "2026-01".to_string(),
+                    prs: 2,
+                    lead_time_hours: 2,
+                    lead_time: "2h 0m".to_string(),
+                },
+                TrendPoint {
+                    label: "2026-02".to_string(),
+                    prs: 0,
+                    lead_time_hours: 0,
+                    lead_time: "-".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_update_search_edits_query_and_clears() {
+        let mut state = update(Msg::StartSearch, AppState::new());
+        assert!(matches!(state.current_view(), View::Tail));
+        assert!(state.searching);
+
+        for c in "fixx".chars() {
+            state = update(Msg::SearchInput(c), state);
+        }
+        state = update(Msg::SearchBackspace, state);
+        state = update(Msg::EndSearch, state);
+        assert_eq!(state.search, "fix");
+        assert!(!state.searching);
+
+        state = update(Msg::ClearSearch, state);
+        assert!(state.search.is_empty());
+    }
+
+    #[test]
+    fn test_mouse_wheel_scrolls() {
+        use crossterm::event::{KeyModifiers, MouseButton};
+        let mouse = |kind| MouseEvent {
+            kind,
+            column: 10,
+            row: 5,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        assert_eq!(
+            mouse_message(mouse(MouseEventKind::ScrollUp)),
+            Some(Msg::ScrollUp)
+        );
+        assert_eq!(
+            mouse_message(mouse(MouseEventKind::ScrollDown)),
+            Some(Msg::ScrollDown)
+        );
+        assert_eq!(
+            mouse_message(mouse(MouseEventKind::Down(MouseButton::Left))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_page_and_home_end_keys_scroll() {
+        use crossterm::event::{KeyEvent, KeyModifiers};
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        let mut state = AppState::new();
+
+        let cases = [
+            (key(KeyCode::PageDown), Msg::ScrollFullPageDown),
+            (key(KeyCode::PageUp), Msg::ScrollFullPageUp),
+            (ctrl('d'), Msg::ScrollPageDown),
+            (ctrl('u'), Msg::ScrollPageUp),
+            (key(KeyCode::Home), Msg::ScrollToTop),
+            (key(KeyCode::Char('g')), Msg::ScrollToTop),
+            (key(KeyCode::End), Msg::ScrollToBottom),
+            (key(KeyCode::Char('G')), Msg::ScrollToBottom),
+        ];
+        for (event, msg) in cases {
+            assert_eq!(key_message(&state, event), Some(msg), "{event:?}");
+        }
+
+        // While typing a search, letters are text rather than commands.
+        state.searching = true;
+        assert_eq!(
+            key_message(&state, key(KeyCode::Char('G'))),
+            Some(Msg::SearchInput('G'))
+        );
+        assert_eq!(key_message(&state, key(KeyCode::End)), None);
+    }
+
+    #[test]
+    fn test_search_narrows_lists_and_drops_empty_sections() {
+        let data = create_test_month_data();
+        let cfg = test_config();
+        let opts = RenderOptions {
+            search: "pr 2",
+            ..Default::default()
+        };
+
+        let tail = build_tail_content(&data, &cfg, 80, opts);
+        assert_eq!(tail.rows.len(), 1);
+        assert!(tail.rows[0].url().unwrap().ends_with("/pull/2"));
+
+        let by_week = build_detail_by_week_content(
+            &data,
+            &cfg,
+            80,
+            RenderOptions {
+                search: "TEST/REPO",
+                ..Default::default()
+            },
+        );
+        assert_eq!(by_week.rows.len(), 2);
+
+        let none = build_detail_by_repo_content(
+            &data,
+            &cfg,
+            80,
+            RenderOptions {
+                search: "nothing",
+                ..Default::default()
+            },
+        );
+        assert!(none.lines.is_empty());
+    }
+
+    #[test]
+    fn test_reviewers_view_lists_each_reviewers_prs() {
+        let data = create_test_month_data();
+        let cfg = test_config();
+
+        let state = update(Msg::ShowReviewers, AppState::new());
+        assert!(matches!(state.current_view(), View::Reviewers));
+
+        // Collapsed, each reviewer is a header row that Enter expands.
+        let content = build_reviewers_content(&data, &cfg, 80, RenderOptions::default());
+        let header = content.lines[0].to_string();
+        assert!(
+            header.starts_with("━━━ ▸ alice │ 2 reviews on 1 PRs━"),
+            "{header}"
+        );
+        assert_eq!(content.rows.len(), 1);
+        assert_eq!(content.rows[0].target, RowTarget::Reviewer("alice".into()));
+
+        let mut state = state;
+        state.scroll.set_rows(content.rows);
+        let state = update(Msg::OpenSelected, state);
+        assert_eq!(state.expanded_reviewer.as_deref(), Some("alice"));
+
+        let opts = RenderOptions {
+            expanded_reviewer: state.expanded_reviewer.as_deref(),
+            ..RenderOptions::default()
+        };
+        let content = build_reviewers_content(&data, &cfg, 80, opts);
+        assert!(content.lines[0].to_string().starts_with("━━━ ▾ alice"));
+        assert_eq!(content.rows.len(), 2);
+        assert!(content.rows[1].url().unwrap().ends_with("/pull/2"));
+
+        // Enter on the header again collapses it.
+        let mut state = state;
+        state.scroll.set_rows(content.rows);
+        assert_eq!(update(Msg::OpenSelected, state).expanded_reviewer, None);
+    }
+
     #[test]
     fn test_update_scroll_up_is_idempotent_at_top() {
         let state = AppState::new();
@@ -1532,4 +4448,613 @@ mod tests {
         let result = update(Msg::ScrollFullPageUp, state);
         assert_eq!(result.scroll.position, 30);
     }
+
+    #[test]
+    fn test_update_toggle_wrap_keeps_view() {
+        let mut state = AppState::new();
+        state.set_view(View::Tail);
+        assert!(!state.wrap_titles);
+
+        let state = update(Msg::ToggleWrap, state);
+        assert!(state.wrap_titles);
+        assert!(matches!(state.current_view(), View::Tail));
+
+        let state = update(Msg::ToggleWrap, state);
+        assert!(!state.wrap_titles);
+    }
+
+    #[test]
+    fn test_shift_month_crosses_year_boundaries() {
+        assert_eq!(shift_month("2025-01", -1).as_deref(), Some("2024-12"));
+        assert_eq!(shift_month("2024-12", 1).as_deref(), Some("2025-01"));
+        assert_eq!(shift_month("2025-06", 1).as_deref(), Some("2025-07"));
+        assert_eq!(
+            shift_month("2025-06-15..2025-06-21", -1).as_deref(),
+            Some("2025-05")
+        );
+        assert_eq!(shift_month("not-a-month", 1), None);
+    }
+
+    #[test]
+    fn test_update_month_navigation_clamps_at_latest() {
+        let mut state = AppState::new();
+        state.month = "2025-11".to_string();
+        state.latest_month = "2025-12".to_string();
+        state.scroll.position = 5;
+
+        let state = update(Msg::NextMonth, state);
+        assert_eq!(state.month, "2025-12");
+        assert_eq!(state.scroll.position, 0);
+
+        let state = update(Msg::NextMonth, state);
+        assert_eq!(state.month, "2025-12");
+
+        let state = update(Msg::PrevMonth, state);
+        let state = update(Msg::PrevMonth, state);
+        assert_eq!(state.month, "2025-10");
+    }
+
+    #[test]
+    fn test_wrap_text_breaks_on_words() {
+        assert_eq!(
+            wrap_text("Refactor the cache layer for speed", 12),
+            vec!["Refactor the", "cache layer", "for speed"]
+        );
+        assert_eq!(wrap_text("short", 12), vec!["short"]);
+        assert_eq!(wrap_text("", 12), vec![""]);
+    }
+
+    #[test]
+    fn test_wrap_text_splits_overlong_words() {
+        assert_eq!(
+            wrap_text("abcdefghij xy", 4),
+            vec!["abcd", "efgh", "ij", "xy"]
+        );
+    }
+
+    #[test]
+    fn test_wrapped_tail_emits_continuation_lines() {
+        let mut data = create_test_month_data();
+        data.prs_by_week[0][0].title = "A very long pull request title that will not fit".into();
+        let cfg = test_config();
+
+        let truncated = build_tail_content(&data, &cfg, 80, RenderOptions::default());
+        let wrapped = build_tail_content(
+            &data,
+            &cfg,
+            80,
+            RenderOptions {
+                wrap: true,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(truncated.lines.len(), 3);
+        assert!(wrapped.lines.len() > truncated.lines.len());
+        // The wrapped row is taller, and the rows after it move down to make room.
+        assert_eq!(truncated.rows[0].height, 1);
+        assert!(wrapped.rows.iter().any(|row| row.height > 1));
+        let last = wrapped.rows.last().unwrap();
+        assert_eq!(last.line + last.height, wrapped.lines.len());
+    }
+
+    #[test]
+    fn test_cursor_moves_by_pr_and_stays_on_screen() {
+        let data = create_test_month_data();
+        let cfg = test_config();
+        let content = build_detail_by_week_content(&data, &cfg, 80, RenderOptions::default());
+        let urls: Vec<String> = content
+            .rows
+            .iter()
+            .filter_map(|row| row.url().map(String::from))
+            .collect();
+        assert_eq!(
+            urls,
+            [
+                "https://github.com/test/repo/pull/1",
+                "https://github.com/test/repo/pull/2"
+            ]
+        );
+
+        let mut state = AppState::new();
+        state.set_view(View::Detail(DetailMode::ByWeek));
+        state.scroll.set_content_height(content.lines.len());
+        state.scroll.set_viewport_height(1);
+        state.scroll.set_rows(content.rows);
+        assert_eq!(
+            state.scroll.selected_row().unwrap().url(),
+            Some(urls[0].as_str())
+        );
+
+        let state = update(Msg::ScrollDown, state);
+        let selected = state.scroll.selected_row().unwrap();
+        assert!(selected.url().unwrap().ends_with("/pull/2"));
+        assert_eq!(state.scroll.position, selected.line);
+        // Stops on the last PR instead of scrolling into the blank lines below it.
+        let state = update(Msg::ScrollDown, state);
+        assert!(
+            state
+                .scroll
+                .selected_row()
+                .unwrap()
+                .url()
+                .unwrap()
+                .ends_with("/pull/2")
+        );
+        let state = update(Msg::ScrollToTop, state);
+        assert_eq!(state.scroll.selected, 0);
+        assert_eq!(update(Msg::OpenSelected, state).scroll.selected, 0);
+    }
+
+    #[test]
+    fn test_comparison_table() {
+        let january = create_test_month_data();
+        let mut february = create_test_month_data();
+        february.total_prs = 3;
+        february.avg_lead_time = Duration::minutes(90);
+        february.frequency = 1.5;
+        february.size_m = 2;
+        february.reviewed_count = Some(4);
+        let cmp = data::compare_months(&january, &february);
+
+        insta::assert_snapshot!(comparison_string(&cmp, "2026-01", "2026-02"), @r"
+        Comparing 2026-01 to 2026-02
+          Metric         2026-01  2026-02   Change        %
+          Total PRs            2        3       +1   +50.0%
+          Avg Lead Time    2h 0m   1h 30m     -30m   -25.0%
+          Frequency       2.0/wk   1.5/wk  -0.5/wk   -25.0%
+          Size S               1        1       +0    +0.0%
+          Size M               1        2       +1  +100.0%
+          Size L               0        0       +0      n/a
+          Size XL              0        0       +0      n/a
+          Reviewed             5        4       -1   -20.0%
+        ");
+        let json: serde_json::Value =
+            serde_json::from_str(&comparison_json(&cmp, "2026-01", "2026-02").unwrap()).unwrap();
+        assert_eq!(json["metrics"][0]["change"], 1.0);
+        assert_eq!(
+            json["metrics"][5]["percent_change"],
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn test_compact_layout_reclaims_rows() {
+        let area = Rect::new(0, 0, 80, 24);
+        let [_, _, normal] = screen_layout(area, false);
+        let [controls, header, compact] = screen_layout(area, true);
+        assert_eq!((controls.height, header.height), (1, 1));
+        assert_eq!(compact.height, normal.height + 3);
+
+        let state = update(Msg::ToggleCompact, AppState::new());
+        assert!(state.render_options().compact);
+    }
+
+    #[test]
+    fn test_scroll_position_label() {
+        let mut scroll = ScrollState::new();
+        assert_eq!(scroll.position_label(), "Line 0–0 of 0 (100%)");
+
+        scroll.set_content_height(120);
+        scroll.set_viewport_height(20);
+        assert_eq!(scroll.position_label(), "Line 1–20 of 120 (0%)");
+
+        scroll.position = 20;
+        assert_eq!(scroll.position_label(), "Line 21–40 of 120 (20%)");
+
+        scroll.scroll_to_bottom();
+        assert_eq!(scroll.position_label(), "Line 101–120 of 120 (100%)");
+
+        scroll.set_content_height(8);
+        scroll.clamp_position();
+        assert_eq!(scroll.position_label(), "Line 1–8 of 8 (100%)");
+    }
+
+    #[test]
+    fn test_fits_terminal_requires_both_dimensions() {
+        assert!(fits_terminal(MIN_TERMINAL_WIDTH, MIN_TERMINAL_HEIGHT));
+        assert!(fits_terminal(120, 40));
+        assert!(!fits_terminal(MIN_TERMINAL_WIDTH - 1, 40));
+        assert!(!fits_terminal(120, MIN_TERMINAL_HEIGHT - 1));
+    }
+
+    #[test]
+    fn test_pr_link_cells_wrap_the_pr_reference_on_screen() {
+        let url = "https://github.com/test/repo/pull/7";
+        let area = Rect::new(2, 1, 40, 2);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 44, 4));
+        buffer.set_string(2, 1, "01-06 │ test/repo │ #   7 fix #7", Style::default());
+        let mut scroll = ScrollState::new();
+        scroll.area = area;
+        scroll.rows = vec![
+            SelectableRow {
+                line: 0,
+                height: 1,
+                target: RowTarget::Pr(url.to_string()),
+            },
+            // Scrolled past the bottom of the list.
+            SelectableRow {
+                line: 2,
+                height: 1,
+                target: RowTarget::Pr(url.to_string()),
+            },
+        ];
+
+        let cells = pr_link_cells(&buffer, &scroll, true);
+        let xs: Vec<u16> = cells.iter().map(|(x, _, _)| *x).collect();
+        assert_eq!(xs, (22..27).collect::<Vec<_>>());
+        assert_eq!(cells[0].2.symbol(), osc8_link(url, "#"));
+        assert_eq!(cells[4].2.symbol(), osc8_link(url, "7"));
+        assert!(pr_link_cells(&buffer, &scroll, false).is_empty());
+    }
+
+    #[test]
+    fn test_week_row_styles_flag_gaps_and_busiest_week() {
+        let (gap_row, gap_count) = week_row_styles(0, 5, &Theme::default());
+        assert!(gap_row.add_modifier.contains(Modifier::DIM));
+        assert_eq!(gap_count.fg, Some(Color::Red));
+
+        let (_, busiest) = week_row_styles(5, 5, &Theme::default());
+        assert!(busiest.add_modifier.contains(Modifier::BOLD));
+
+        let (row, count) = week_row_styles(3, 5, &Theme::default());
+        assert_eq!(row, Style::default());
+        assert_eq!(count, Style::default().fg(Color::Green));
+    }
+
+    #[test]
+    fn test_summary_calls_out_fastest_and_slowest_prs() {
+        let cfg = test_config();
+        let mut data = create_test_month_data();
+        let text = |data: &data::MonthData| -> Vec<String> {
+            build_summary_content(data, &cfg, 100, &Theme::default())
+                .iter()
+                .map(|line| line.to_string())
+                .collect()
+        };
+
+        let lines = text(&data);
+        assert!(lines[0].starts_with("Fastest │ 1h 0m    │ test/repo#1 Test PR 1"));
+        assert!(lines[1].starts_with("Slowest │ 3h 0m    │ test/repo#2 Test PR 2"));
+        assert!(lines[2].starts_with("First   │ Jan 6 (5 days in) │ test/repo#1 Test PR 1"));
+
+        data.extremes = None;
+        data.first_pr = None;
+        assert!(!text(&data).iter().any(|line| line.starts_with("Fastest")));
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let cfg = test_config();
+        let mut data = create_test_month_data();
+        data.prs_by_week[0][0].title = "fix: a | b\nc".to_string();
+        insta::assert_snapshot!(markdown_string(&data, &cfg));
+    }
+
+    #[test]
+    fn test_html_report_escapes_text() {
+        let cfg = test_config();
+        let mut data = create_test_month_data();
+        data.prs_by_week[0][0].title = "fix: <script>alert(\"x\")</script> & more".to_string();
+        data.prs_by_week[0][0].body = Some("Uses <b>tags</b>".to_string());
+        let html = html_string(&data, &cfg);
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<b>"));
+        insta::assert_snapshot!(html);
+    }
+
+    #[test]
+    fn test_table_fits_requested_width() {
+        let cfg = test_config();
+        let table = table_string(&create_test_month_data(), &cfg, 70, false);
+        assert!(table.lines().all(|line| line.chars().count() == 70));
+        insta::assert_snapshot!(table);
+    }
+
+    #[test]
+    fn test_palette_is_plain_when_disabled() {
+        let sizes = test_config().size;
+        let plain = Palette {
+            enabled: false,
+            hyperlinks: true,
+        };
+        assert_eq!(plain.size_counts(&sizes, 2, 1, 0, 0, 1), "2S 1M 0L 0XL 1?");
+        assert_eq!(plain.paint(ANSI_BLUE, "owner/repo"), "owner/repo");
+
+        let colored = Palette {
+            enabled: true,
+            hyperlinks: true,
+        };
+        assert_eq!(colored.size(PRSize::XL, "XL"), "\x1b[31mXL\x1b[0m");
+    }
+
+    #[test]
+    fn test_palette_links_only_with_color_and_hyperlinks() {
+        let url = "https://github.com/owner/repo/pull/7";
+        let linked = Palette {
+            enabled: true,
+            hyperlinks: true,
+        };
+        assert_eq!(
+            linked.link(url, "#7"),
+            "\x1b]8;;https://github.com/owner/repo/pull/7\x1b\\#7\x1b]8;;\x1b\\"
+        );
+        for (enabled, hyperlinks) in [(true, false), (false, true)] {
+            assert_eq!(
+                Palette {
+                    enabled,
+                    hyperlinks
+                }
+                .link(url, "#7"),
+                "#7"
+            );
+        }
+    }
+
+    fn row_layout(repo_width: usize, title_width: usize) -> RowLayout {
+        RowLayout {
+            repo_width,
+            title_width,
+            wrap: false,
+            files: false,
+        }
+    }
+
+    #[test]
+    fn test_files_column_shows_count_and_keeps_width() {
+        let data = create_test_month_data();
+        let cfg = test_config();
+        let plain = build_tail_content(&data, &cfg, 100, RenderOptions::default()).lines;
+        let state = update(Msg::ToggleFiles, AppState::new());
+        let with_files = build_tail_content(&data, &cfg, 100, state.render_options()).lines;
+
+        assert!(!plain[1].to_string().contains(" 5f │"), "{}", plain[1]);
+        assert!(
+            with_files[1].to_string().contains(" 5f │"),
+            "{}",
+            with_files[1]
+        );
+        assert_eq!(with_files[1].width(), plain[1].width());
+    }
+
+    #[test]
+    fn test_truncate_cuts_on_character_boundaries() {
+        for title in [
+            "café résumé",
+            "🚀🎉 ship it",
+            "修复登录重定向",
+            "naïve 👍🏽 fix",
+        ] {
+            for width in 0..=14 {
+                let cut = truncate(title, width);
+                assert_eq!(cut.width(), width, "{title:?} at {width}");
+                assert!(title.starts_with(cut.trim_end()), "{title:?} at {width}");
+            }
+        }
+        assert_eq!(truncate("🚀🎉 ship it", 3), "🚀 ");
+        assert_eq!(truncate("café résumé", 4), "café");
+    }
+
+    #[test]
+    fn test_wide_titles_keep_columns_aligned() {
+        let cfg = test_config();
+        let row = |title: &str| {
+            let mut pr = create_test_month_data().prs_by_week[0][0].clone();
+            pr.title = title.to_string();
+            pr_row_lines(&pr, &cfg, row_layout(12, 20), &Theme::default()).remove(0)
+        };
+        let ascii = row("Fix the login redirect loop for good");
+        let cjk = row("修复登录重定向循环问题的补丁");
+        let emoji = row("🚀 Ship the 🎉 release notes");
+        assert_eq!(cjk.width(), ascii.width());
+        assert_eq!(emoji.width(), ascii.width());
+
+        assert_eq!(truncate("修复登录", 5), "修复 ");
+        assert_eq!(wrap_text("修复登录重定向", 6), ["修复登", "录重定", "向"]);
+    }
+
+    #[test]
+    fn test_lead_time_histogram_scales_bars() {
+        let bucket = |label: &str, count| data::LeadTimeBucket {
+            label: label.to_string(),
+            count,
+        };
+        let lines: Vec<String> = lead_time_histogram_lines(
+            &[bucket("<1h", 1), bucket("1h–4h", 4), bucket("4h+", 0)],
+            30,
+            &Theme::default(),
+        )
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+        insta::assert_snapshot!(lines.join("\n"), @r"
+        <1h   │ █████ 1
+        1h–4h │ █████████████████ 4
+        4h+   │  0
+        ");
+    }
+
+    #[test]
+    fn test_labels_follow_title_within_its_column() {
+        let cfg = test_config();
+        let mut pr = create_test_month_data().prs_by_week[0][0].clone();
+        let plain = pr_row_lines(&pr, &cfg, row_layout(12, 30), &Theme::default()).remove(0);
+
+        pr.labels = vec!["bug".to_string(), "ui".to_string()];
+        let labeled = pr_row_lines(&pr, &cfg, row_layout(12, 30), &Theme::default()).remove(0);
+        assert!(labeled.to_string().contains("Test PR 1"), "{labeled}");
+        assert!(labeled.to_string().contains(" bug ui │"), "{labeled}");
+        assert_eq!(labeled.width(), plain.width());
+        let chip = labeled
+            .spans
+            .iter()
+            .find(|span| span.content == "bug")
+            .unwrap();
+        assert_eq!(chip.style, label_style("bug"));
+
+        // Labels that would take over half the title column are left out.
+        pr.labels = vec!["a-very-long-label-name".to_string()];
+        let crowded = pr_row_lines(&pr, &cfg, row_layout(12, 30), &Theme::default()).remove(0);
+        assert!(!crowded.to_string().contains("a-very-long"), "{crowded}");
+    }
+
+    #[test]
+    fn test_theme_colors_pr_rows() {
+        let mut cfg = test_config();
+        cfg.theme.accent = Some("black".to_string());
+        cfg.theme.size_s = Some("#00aa00".to_string());
+        let pr = create_test_month_data().prs_by_week[0][0].clone();
+        assert_eq!(pr.size(&cfg.size), PRSize::S);
+
+        let row = pr_row_lines(&pr, &cfg, row_layout(12, 30), &cfg.theme.theme()).remove(0);
+        let color_of = |text: &str| {
+            row.spans
+                .iter()
+                .find(|span| span.content.trim() == text)
+                .and_then(|span| span.style.fg)
+        };
+        assert_eq!(color_of(&pr.repo), Some(Color::Black));
+        assert_eq!(color_of("S"), Some(Color::Rgb(0, 0xaa, 0)));
+    }
+
+    #[test]
+    fn test_ignored_prs_are_dimmed_and_tagged() {
+        let mut cfg = test_config();
+        let mut pr = create_test_month_data().prs_by_week[0][0].clone();
+        pr.ignored = true;
+
+        let row = pr_row_lines(&pr, &cfg, row_layout(12, 30), &Theme::default()).remove(0);
+        assert!(row.to_string().contains("Test PR 1 (ignored)"), "{row}");
+        assert!(row.style.add_modifier.contains(Modifier::DIM));
+
+        cfg.display.ignored_marker = Some(String::new());
+        let row = pr_row_lines(&pr, &cfg, row_layout(12, 30), &Theme::default()).remove(0);
+        assert!(!row.to_string().contains("ignored"), "{row}");
+        assert!(row.style.add_modifier.contains(Modifier::DIM));
+    }
+
+    #[test]
+    fn test_clamp_body_counts_hidden_lines() {
+        let body = "Summary\n\n- [x] Tests\n- [ ] Docs";
+        assert_eq!(clamp_body(body, None), (body.lines().collect(), 0));
+        assert_eq!(clamp_body(body, Some(2)), (vec!["Summary", ""], 2));
+        assert_eq!(clamp_body(body, Some(0)), (vec![], 4));
+        assert_eq!(clamp_body(body, Some(10)).1, 0);
+    }
+
+    #[test]
+    fn test_digest_reads_as_prose() {
+        let mut data = create_test_month_data();
+        for pr in &mut data.prs_by_week[0] {
+            pr.merged_at = Some(pr.created_at + pr.lead_time);
+        }
+        insta::assert_snapshot!(digest(&data, "2026-01"));
+
+        // An ignored or still-open PR never counts as the biggest.
+        data.prs_by_week[0][1].ignored = true;
+        assert!(digest(&data, "2026-01").contains("test/repo#1"));
+        data.prs_by_week[0][1].ignored = false;
+        data.prs_by_week[0][1].open = true;
+        data.prs_by_week[0][1].merged_at = None;
+        assert!(digest(&data, "2026-01").contains("test/repo#1"));
+
+        let cfg = test_config();
+        let empty = data::build_month_data("2026-02", vec![], Some(1), DateField::Created, &cfg);
+        assert_eq!(
+            digest(&empty, "2026-02"),
+            "In 2026-02 you opened no PRs. You reviewed 1 PR."
+        );
+    }
+
+    #[test]
+    fn test_author_table_aligns_columns() {
+        let authors = vec![
+            data::AuthorSummary {
+                login: "alice".to_string(),
+                total_prs: 12,
+                avg_lead_time: chrono::Duration::hours(5),
+                reviewed_count: Some(18),
+                review_balance: Some(1.5),
+            },
+            data::AuthorSummary {
+                login: "bartholomew".to_string(),
+                total_prs: 0,
+                avg_lead_time: chrono::Duration::zero(),
+                reviewed_count: None,
+                review_balance: None,
+            },
+        ];
+        insta::assert_snapshot!(author_table(&authors).join("\n"));
+    }
+
+    #[test]
+    fn test_compact_header_line_snapshot() {
+        let data = create_test_month_data();
+        let cfg = test_config();
+        let line = compact_header_line(&data, Some("by Week"), &cfg, &Theme::default());
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        insta::assert_snapshot!(text);
+    }
+
+    #[test]
+    fn test_review_balance_color_follows_target() {
+        let mut data = create_test_month_data();
+        data.reviewed_count = Some(1);
+        let mut cfg = test_config();
+        let ratio_color = |cfg: &Config| {
+            review_balance_spans(&data, cfg, &Theme::default())[1]
+                .style
+                .fg
+        };
+
+        assert_eq!(ratio_color(&cfg), Some(Color::Cyan));
+        cfg.review.target_ratio = Some(1.0);
+        assert_eq!(ratio_color(&cfg), Some(Color::Red));
+        cfg.review.target_ratio = Some(0.5);
+        assert_eq!(ratio_color(&cfg), Some(Color::Green));
+    }
+
+    #[test]
+    fn test_empty_month_shows_centered_message() {
+        let mut data = create_test_month_data();
+        assert!(!is_empty_month(&data));
+        data.total_prs = 0;
+        assert!(!is_empty_month(&data), "ignored PRs are still listed");
+        data.prs_by_week.clear();
+        assert!(is_empty_month(&data));
+
+        let mut terminal = Terminal::new(ratatui::backend::TestBackend::new(60, 7)).unwrap();
+        let mut scroll = ScrollState::new();
+        terminal
+            .draw(|frame| {
+                render_empty_state(
+                    frame,
+                    frame.area(),
+                    &mut scroll,
+                    &report_label(&data),
+                    &Theme::default(),
+                )
+            })
+            .unwrap();
+        let rows: Vec<String> = terminal
+            .backend()
+            .buffer()
+            .content()
+            .chunks(60)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+            .collect();
+        insta::assert_snapshot!(rows.join("\n"));
+    }
+
+    #[test]
+    fn test_clamp_position_after_content_shrinks() {
+        let mut scroll = ScrollState::new();
+        scroll.set_content_height(100);
+        scroll.set_viewport_height(20);
+        scroll.position = 70;
+
+        scroll.set_content_height(40);
+        scroll.clamp_position();
+        assert_eq!(scroll.position, 20);
+    }
 }
@@ -140,3 +140,805 @@ fn test_completions_invalid_shell() {
     let stderr = String::from_utf8_lossy(&output.stderr);
     insta::assert_snapshot!(stderr);
 }
+
+#[test]
+fn test_invalid_month_exits_with_usage_code() {
+    let mut cmd = Command::new(cargo::cargo_bin!("gh-log"));
+    let output = cmd
+        .arg("print")
+        .arg("--month")
+        .arg("2025/11")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(64));
+}
+
+#[test]
+fn test_day_range_across_months_is_rejected() {
+    let mut cmd = Command::new(cargo::cargo_bin!("gh-log"));
+    let output = cmd
+        .arg("print")
+        .arg("--month")
+        .arg("2025-01-30..2025-02-02")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(64));
+}
+
+#[test]
+fn test_backwards_range_is_rejected() {
+    let mut cmd = Command::new(cargo::cargo_bin!("gh-log"));
+    let output = cmd
+        .arg("print")
+        .arg("--range")
+        .arg("2025-03-01..2025-01-01")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(64));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("START on or before END"), "{stderr}");
+}
+
+#[test]
+fn test_malformed_quarter_is_rejected() {
+    let output = Command::new(cargo::cargo_bin!("gh-log"))
+        .args(["print", "--quarter", "2025-Q5"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(64));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("YYYY-Qn"), "{stderr}");
+}
+
+// XDG_CACHE_HOME only steers the cache location on Linux.
+#[cfg(target_os = "linux")]
+#[test]
+fn test_year_reads_its_own_cache_entry_with_month_totals() {
+    let home = tempfile::TempDir::new().unwrap();
+    let range = "2025-01-01..2025-12-31";
+    write_snapshot(
+        &cache_dir(home.path()),
+        range,
+        serde_json::json!([pr_node(7, "2025-03-12T10:00:00Z")]),
+        0,
+    );
+
+    let output = Command::new(cargo::cargo_bin!("gh-log"))
+        .env("XDG_CACHE_HOME", home.path().join("cache"))
+        .env("XDG_CONFIG_HOME", home.path().join("config"))
+        .env("GH_LOG_GH_PATH", "/nonexistent/gh")
+        .args(["print", "--offline", "--year", "2025", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let months = report["months"].as_array().unwrap();
+    assert_eq!(months.len(), 12);
+    assert_eq!(months[2]["month"], "2025-03");
+    assert_eq!(months[2]["pr_count"], 1);
+    assert_eq!(report["total_prs"], 1);
+}
+
+// XDG_CACHE_HOME only steers the cache location on Linux.
+#[cfg(target_os = "linux")]
+#[test]
+fn test_range_spans_months_in_weekly_buckets() {
+    let home = tempfile::TempDir::new().unwrap();
+    let range = "2025-01-20..2025-02-09";
+    write_snapshot(
+        &cache_dir(home.path()),
+        range,
+        serde_json::json!([pr_node(7, "2025-02-03T10:00:00Z")]),
+        0,
+    );
+
+    let output = Command::new(cargo::cargo_bin!("gh-log"))
+        .env("XDG_CACHE_HOME", home.path().join("cache"))
+        .env("XDG_CONFIG_HOME", home.path().join("config"))
+        .env("GH_LOG_GH_PATH", "/nonexistent/gh")
+        .args(["print", "--offline", "--range", range, "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let counts: Vec<u64> = report["weeks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|week| week["pr_count"].as_u64().unwrap())
+        .collect();
+    assert_eq!(counts, [0, 0, 1]);
+    assert_eq!(report["avg_lead_time_hours"], 2.0);
+}
+
+// XDG_CONFIG_HOME only steers the config location on Linux.
+#[cfg(target_os = "linux")]
+#[test]
+fn test_bad_config_exits_with_config_code() {
+    let config_home = tempfile::TempDir::new().unwrap();
+    let config_dir = config_home.path().join("gh-log");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    std::fs::write(config_dir.join("config.toml"), "[filter\nexclude_repos = ").unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("gh-log"));
+    let output = cmd
+        .env("XDG_CONFIG_HOME", config_home.path())
+        .arg("print")
+        .arg("--month")
+        .arg("2025-11")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Failed to parse config file"), "{stderr}");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_doctor_reports_invalid_config_without_failing() {
+    let config_home = tempfile::TempDir::new().unwrap();
+    let config_dir = config_home.path().join("gh-log");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    std::fs::write(
+        config_dir.join("config.toml"),
+        "[size]\nsmall = 200\nmedium = 100\nlarge = 500\n",
+    )
+    .unwrap();
+
+    let output = Command::new(cargo::cargo_bin!("gh-log"))
+        .env("XDG_CONFIG_HOME", config_home.path())
+        .env("GH_LOG_GH_PATH", "/nonexistent/gh")
+        .arg("doctor")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains(
+            "✗ Config invalid: Invalid config value for size: thresholds must be ascending"
+        ),
+        "{stdout}"
+    );
+
+    std::fs::write(config_dir.join("config.toml"), "").unwrap();
+    let output = Command::new(cargo::cargo_bin!("gh-log"))
+        .env("XDG_CONFIG_HOME", config_home.path())
+        .env("GH_LOG_GH_PATH", "/nonexistent/gh")
+        .args(["doctor", "--json"])
+        .output()
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["config_exists"], true);
+    assert_eq!(report["config_error"], serde_json::Value::Null);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_doctor_reports_token_scopes_and_rate_limit() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let home = tempfile::TempDir::new().unwrap();
+    let gh = home.path().join("gh");
+    std::fs::write(
+        &gh,
+        r#"#!/bin/sh
+case "$1" in
+  --version) echo "gh version 2.60.0" ;;
+  api) echo '{"resources":{"graphql":{"limit":5000,"used":4950,"remaining":50,"reset":1767225600}}}' ;;
+  auth) echo "  - Token scopes: 'gist', 'read:org'" ;;
+esac
+"#,
+    )
+    .unwrap();
+    std::fs::set_permissions(&gh, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let doctor = |json: bool| {
+        let mut cmd = Command::new(cargo::cargo_bin!("gh-log"));
+        cmd.env("XDG_CONFIG_HOME", home.path())
+            .env("XDG_CACHE_HOME", home.path())
+            .env("GH_LOG_GH_PATH", &gh)
+            .arg("doctor");
+        if json {
+            cmd.arg("--json");
+        }
+        cmd.output().unwrap()
+    };
+
+    let output = doctor(false);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Token scopes: gist, read:org"), "{stdout}");
+    assert!(stdout.contains("⚠ Missing scope repo"), "{stdout}");
+    assert!(
+        stdout.contains("GraphQL rate limit: 50/5000 remaining, resets 2026-01-01 00:00 UTC"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("⚠ Rate limit nearly used up"), "{stdout}");
+
+    let report: serde_json::Value = serde_json::from_slice(&doctor(true).stdout).unwrap();
+    assert_eq!(
+        report["token_scopes"],
+        serde_json::json!(["gist", "read:org"])
+    );
+    assert_eq!(report["rate_limit"]["remaining"], 50);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_print_limit_stops_paging_and_skips_cache() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let home = tempfile::TempDir::new().unwrap();
+    let queries = home.path().join("queries.log");
+    let node = |number: u32| {
+        format!(
+            r#"{{"number":{number},"title":"PR {number}","body":null,"author":{{"__typename":"User","login":"me"}},"repository":{{"nameWithOwner":"me/repo"}},"createdAt":"2025-03-0{number}T10:00:00Z","updatedAt":"2025-03-0{number}T12:00:00Z","mergedAt":"2025-03-0{number}T12:00:00Z","state":"MERGED","additions":5,"deletions":1,"changedFiles":1,"reviews":{{"totalCount":0,"nodes":[]}},"labels":{{"nodes":[]}}}}"#
+        )
+    };
+    let page = format!(
+        r#"{{"data":{{"search":{{"pageInfo":{{"hasNextPage":true,"endCursor":"next"}},"nodes":[{},{}]}}}}}}"#,
+        node(1),
+        node(2)
+    );
+    let gh = home.path().join("gh");
+    std::fs::write(
+        &gh,
+        format!(
+            "#!/bin/sh\ncase \"$1\" in\n  --version) echo \"gh version 2.60.0\" ;;\n  api) echo \"$4\" >> {} ; echo '{}' ;;\nesac\n",
+            queries.display(),
+            page
+        ),
+    )
+    .unwrap();
+    std::fs::set_permissions(&gh, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let output = Command::new(cargo::cargo_bin!("gh-log"))
+        .env("XDG_CONFIG_HOME", home.path())
+        .env("XDG_CACHE_HOME", home.path().join("cache"))
+        .env("GH_LOG_GH_PATH", &gh)
+        .args([
+            "print",
+            "--month",
+            "2025-03",
+            "--limit",
+            "2",
+            "--no-reviewed",
+            "--json",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{:?}", output);
+    let queries = std::fs::read_to_string(&queries).unwrap();
+    assert_eq!(queries.matches("search(").count(), 1, "{queries}");
+    assert!(queries.contains("first: 2"), "{queries}");
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["total_prs"], 2);
+    assert!(
+        report["warnings"][0]
+            .as_str()
+            .unwrap()
+            .starts_with("Showing the first 2 PRs only (--limit)"),
+        "{report}"
+    );
+    let cached = std::fs::read_dir(home.path().join("cache/gh-log"))
+        .map(|entries| entries.count())
+        .unwrap_or(0);
+    assert_eq!(cached, 0);
+}
+
+#[test]
+fn test_completions_install_writes_to_dir() {
+    let dir = tempfile::TempDir::new().unwrap();
+
+    let mut cmd = Command::new(cargo::cargo_bin!("gh-log"));
+    let output = cmd
+        .arg("completions")
+        .arg("zsh")
+        .arg("--install")
+        .arg("--dir")
+        .arg(dir.path())
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let script = std::fs::read_to_string(dir.path().join("_gh-log")).unwrap();
+    assert!(script.starts_with("#compdef gh-log"));
+}
+
+#[test]
+fn test_prefetch_rejects_reversed_range() {
+    let mut cmd = Command::new(cargo::cargo_bin!("gh-log"));
+    let output = cmd
+        .arg("prefetch")
+        .arg("--from")
+        .arg("2025-03")
+        .arg("--to")
+        .arg("2025-01")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(64));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--from 2025-03 is after --to 2025-01"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn test_prefetch_rejects_out_of_range_months() {
+    for months in ["0", "4000000000"] {
+        let mut cmd = Command::new(cargo::cargo_bin!("gh-log"));
+        let output = cmd
+            .args(["prefetch", "--months", months, "--to", "2025-01"])
+            .output()
+            .unwrap();
+
+        assert_eq!(output.status.code(), Some(64), "--months {months}");
+    }
+}
+
+#[test]
+fn test_prefetch_rejects_zero_jobs() {
+    let mut cmd = Command::new(cargo::cargo_bin!("gh-log"));
+    let output = cmd.args(["prefetch", "--jobs", "0"]).output().unwrap();
+
+    assert_eq!(output.status.code(), Some(64));
+}
+
+#[test]
+fn test_gh_path_env_is_used() {
+    let mut cmd = Command::new(cargo::cargo_bin!("gh-log"));
+    let output = cmd
+        .env("GH_LOG_GH_PATH", "/nonexistent/gh")
+        .arg("print")
+        .arg("--month")
+        .arg("2020-01")
+        .arg("--force")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("GH_LOG_GH_PATH"), "{stderr}");
+}
+
+#[test]
+fn test_version_verbose_includes_build_info() {
+    let mut cmd = Command::new(cargo::cargo_bin!("gh-log"));
+    let output = cmd.arg("version").arg("--verbose").output().unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with(&format!("gh-log {}", env!("CARGO_PKG_VERSION"))));
+    for field in ["commit: ", "built: ", "rustc: "] {
+        assert!(stdout.contains(field), "{stdout}");
+    }
+}
+
+/// The `gh-log` cache directory under `home`, created if missing.
+#[cfg(target_os = "linux")]
+fn cache_dir(home: &std::path::Path) -> std::path::PathBuf {
+    let cache_dir = home.join("cache").join("gh-log");
+    std::fs::create_dir_all(&cache_dir).unwrap();
+    cache_dir
+}
+
+/// Write a long-stale snapshot of `prs` with `reviewed` reviewed PRs under the cache key `key`,
+/// e.g. `2025-03`, `2025-01-01..2025-12-31` or `2025-03.author-octocat`.
+#[cfg(target_os = "linux")]
+fn write_snapshot(cache_dir: &std::path::Path, key: &str, prs: serde_json::Value, reviewed: usize) {
+    // The snapshot records the period it covers, without the author/org scope.
+    let month = [".author-", ".org-"]
+        .iter()
+        .filter_map(|scope| key.find(scope))
+        .min()
+        .map_or(key, |end| &key[..end]);
+    let snapshot = serde_json::json!({
+        "version": gh_log::cache::CACHE_VERSION,
+        "month": month,
+        "timestamp": "2020-01-01T00:00:00Z",
+        "prs": prs,
+        "reviewed_count": reviewed,
+    });
+    std::fs::write(
+        cache_dir.join(format!("{}.json", key)),
+        snapshot.to_string(),
+    )
+    .unwrap();
+}
+
+/// A merged PR in `acme/app` as `gh` returns it, created at `created_at` and merged two hours
+/// later.
+#[cfg(target_os = "linux")]
+fn pr_node(number: u32, created_at: &str) -> serde_json::Value {
+    let created = chrono::DateTime::parse_from_rfc3339(created_at).unwrap();
+    let merged = (created + chrono::Duration::hours(2)).to_rfc3339();
+    serde_json::json!({
+        "number": number,
+        "title": "feat: thing",
+        "body": null,
+        "author": null,
+        "repository": { "nameWithOwner": "acme/app" },
+        "createdAt": created_at,
+        "updatedAt": merged,
+        "mergedAt": merged,
+        "state": "MERGED",
+        "additions": 10,
+        "deletions": 5,
+        "changedFiles": 2,
+        "reviews": { "nodes": [], "totalCount": null },
+    })
+}
+
+/// Write an empty, long-stale snapshot for the current month under `home`/cache and return the
+/// month. Pair with `offline_print` so the command never needs `gh`.
+#[cfg(target_os = "linux")]
+fn seed_stale_cache(home: &std::path::Path) -> String {
+    let month = chrono::Utc::now().format("%Y-%m").to_string();
+    write_snapshot(&cache_dir(home), &month, serde_json::json!([]), 0);
+    month
+}
+
+/// `gh-log print --offline --month <month>` with cache and config rooted in `home`.
+#[cfg(target_os = "linux")]
+fn offline_print(home: &std::path::Path, month: &str, extra: &[&str]) -> std::process::Output {
+    Command::new(cargo::cargo_bin!("gh-log"))
+        .env("XDG_CACHE_HOME", home.join("cache"))
+        .env("XDG_CONFIG_HOME", home.join("config"))
+        .env("GH_LOG_GH_PATH", "/nonexistent/gh")
+        .args(["print", "--offline", "--month", month])
+        .args(extra)
+        .output()
+        .unwrap()
+}
+
+// XDG_CACHE_HOME only steers the cache location on Linux.
+#[cfg(target_os = "linux")]
+#[test]
+fn test_offline_serves_stale_cache_without_gh() {
+    let home = tempfile::TempDir::new().unwrap();
+    let month = seed_stale_cache(home.path());
+
+    let output = offline_print(home.path(), &month, &[]);
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains(&format!("GitHub PRs for {}", month)),
+        "{stdout}"
+    );
+
+    let output = offline_print(home.path(), "2020-01", &[]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--offline is set"), "{stderr}");
+}
+
+// An empty month fails CI with its own exit code; a month with PRs passes.
+#[cfg(target_os = "linux")]
+#[test]
+fn test_fail_on_empty_exits_with_empty_code() {
+    let home = tempfile::TempDir::new().unwrap();
+    let cache_dir = cache_dir(home.path());
+    write_snapshot(&cache_dir, "2025-02", serde_json::json!([]), 0);
+    write_snapshot(
+        &cache_dir,
+        "2025-03",
+        serde_json::json!([pr_node(7, "2025-03-10T10:00:00Z")]),
+        0,
+    );
+
+    let output = offline_print(home.path(), "2025-02", &["--fail-on-empty"]);
+    assert_eq!(output.status.code(), Some(5), "{:?}", output);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No PRs found for 2025-02"), "{stderr}");
+
+    let output = offline_print(home.path(), "2025-03", &["--fail-on-empty"]);
+    assert_eq!(output.status.code(), Some(0), "{:?}", output);
+}
+
+#[test]
+fn test_invalid_author_exits_with_usage_code() {
+    let output = Command::new(cargo::cargo_bin!("gh-log"))
+        .args(["print", "--author", "octo cat"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(64));
+}
+
+// Another user's PRs in one org are cached apart from your own month.
+#[cfg(target_os = "linux")]
+#[test]
+fn test_author_and_org_read_their_own_cache_entry() {
+    let home = tempfile::TempDir::new().unwrap();
+    let cache_dir = cache_dir(home.path());
+    write_snapshot(&cache_dir, "2025-03", serde_json::json!([]), 0);
+    write_snapshot(
+        &cache_dir,
+        "2025-03.author-octocat.org-acme",
+        serde_json::json!([pr_node(7, "2025-03-10T10:00:00Z")]),
+        0,
+    );
+
+    let total_prs = |extra: &[&str]| {
+        let mut args = vec!["--json"];
+        args.extend_from_slice(extra);
+        let output = offline_print(home.path(), "2025-03", &args);
+        assert!(output.status.success(), "{:?}", output);
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        json["total_prs"].as_u64().unwrap()
+    };
+    assert_eq!(total_prs(&[]), 0);
+    assert_eq!(total_prs(&["--author", "Octocat", "--org", "ACME"]), 1);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_bad_exclude_pattern_flag_fails_before_fetching() {
+    let home = tempfile::TempDir::new().unwrap();
+    let month = seed_stale_cache(home.path());
+
+    let output = offline_print(home.path(), &month, &["--exclude-pattern", "[wip"]);
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("[wip"), "{stderr}");
+
+    let output = offline_print(
+        home.path(),
+        &month,
+        &["--exclude-pattern", "^wip:", "--ignore-repo", "me/notes"],
+    );
+    assert!(output.status.success(), "{:?}", output);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_color_flag_overrides_tty_detection() {
+    let home = tempfile::TempDir::new().unwrap();
+    let month = seed_stale_cache(home.path());
+
+    let output = offline_print(home.path(), &month, &[]);
+    assert!(!String::from_utf8_lossy(&output.stdout).contains('\x1b'));
+
+    let output = offline_print(home.path(), &month, &["--color", "always"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("\x1b[1mGitHub PRs for"));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_save_config_persists_flags_as_defaults() {
+    let home = tempfile::TempDir::new().unwrap();
+    let month = seed_stale_cache(home.path());
+
+    let output = offline_print(
+        home.path(),
+        &month,
+        &[
+            "--group-by",
+            "repo",
+            "--sort-repos",
+            "lead-time",
+            "--save-config",
+        ],
+    );
+    assert!(output.status.success(), "{:?}", output);
+    let config_path = home
+        .path()
+        .join("config")
+        .join("gh-log")
+        .join("config.toml");
+    let written = std::fs::read_to_string(&config_path).unwrap();
+    assert!(
+        written.contains("[defaults]\ngroup_by = \"repo\"\nsort_repos = \"lead-time\"\n"),
+        "{written}"
+    );
+
+    let output = offline_print(
+        home.path(),
+        &month,
+        &[
+            "--sort-order",
+            "asc",
+            "--state",
+            "merged",
+            "--tz",
+            "+02:00",
+            "--save-config",
+        ],
+    );
+    assert!(output.status.success(), "{:?}", output);
+    let written = std::fs::read_to_string(&config_path).unwrap();
+    assert!(written.contains("group_by = \"repo\""), "{written}");
+    assert!(written.contains("sort_order = \"asc\""), "{written}");
+    let table: toml::Table = toml::from_str(&written).unwrap();
+    assert_eq!(
+        table["filter"]["state"].as_str(),
+        Some("merged"),
+        "{written}"
+    );
+    assert_eq!(
+        table["display"]["timezone"].as_str(),
+        Some("+02:00"),
+        "{written}"
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_text_only_flags_override_default_format() {
+    let home = tempfile::TempDir::new().unwrap();
+    let month = seed_stale_cache(home.path());
+    let config_dir = home.path().join("config").join("gh-log");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    std::fs::write(
+        config_dir.join("config.toml"),
+        "[defaults]\nformat = \"json\"\n",
+    )
+    .unwrap();
+
+    let output = offline_print(home.path(), &month, &[]);
+    assert!(output.status.success(), "{:?}", output);
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with('{'));
+
+    for flag in [
+        &["--group-by", "repo"][..],
+        &["--max-body-lines", "1"],
+        &["--no-hyperlinks"],
+    ] {
+        let output = offline_print(home.path(), &month, flag);
+        assert!(output.status.success(), "{:?}", output);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("GitHub PRs for"), "{flag:?}: {stdout}");
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_postprocess_pipes_json_through_command() {
+    let home = tempfile::TempDir::new().unwrap();
+    let month = seed_stale_cache(home.path());
+
+    let output = offline_print(home.path(), &month, &["--postprocess", "grep -c total_prs"]);
+    assert!(output.status.success(), "{:?}", output);
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "1");
+
+    let output = offline_print(home.path(), &month, &["--postprocess", "exit 3"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--postprocess command exited"), "{stderr}");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_author_file_combines_cached_logins() {
+    let home = tempfile::TempDir::new().unwrap();
+    let cache_dir = cache_dir(home.path());
+    let month = "2025-03";
+    for (login, reviewed) in [("alice", 4), ("bob", 1)] {
+        let key = format!("{}.author-{}", month, login);
+        write_snapshot(&cache_dir, &key, serde_json::json!([]), reviewed);
+    }
+    let team = home.path().join("team.txt");
+    std::fs::write(
+        &team,
+        "# platform team\nalice\n\nBob  # on leave in May\nalice\n",
+    )
+    .unwrap();
+
+    let output = offline_print(
+        home.path(),
+        month,
+        &["--author-file", team.to_str().unwrap(), "--json"],
+    );
+    assert!(output.status.success(), "{:?}", output);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let logins: Vec<&str> = report["authors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|author| author["login"].as_str().unwrap())
+        .collect();
+    assert_eq!(logins, ["alice", "Bob"]);
+    assert_eq!(report["reviewed_count"], 5);
+
+    std::fs::write(&team, "alice\nnot a login\n").unwrap();
+    let output = offline_print(
+        home.path(),
+        month,
+        &["--author-file", team.to_str().unwrap()],
+    );
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("line 2"), "{stderr}");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_all_cached_rolls_up_every_cached_month() {
+    let home = tempfile::TempDir::new().unwrap();
+    let cache_dir = cache_dir(home.path());
+    for (month, number, reviewed) in [("2025-01", 1, 2), ("2025-03", 2, 3)] {
+        let pr = pr_node(number, &format!("{}-10T10:00:00Z", month));
+        write_snapshot(&cache_dir, month, serde_json::json!([pr]), reviewed);
+    }
+
+    let output = Command::new(cargo::cargo_bin!("gh-log"))
+        .env("XDG_CACHE_HOME", home.path().join("cache"))
+        .env("XDG_CONFIG_HOME", home.path().join("config"))
+        .env("GH_LOG_GH_PATH", "/nonexistent/gh")
+        .args(["print", "--all-cached", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["total_prs"], 2);
+    assert_eq!(report["reviewed_count"], 5);
+    let counts: Vec<u64> = report["weeks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|bucket| bucket["pr_count"].as_u64().unwrap())
+        .collect();
+    assert_eq!(counts, [1, 0, 1]);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_compare_reports_change_between_cached_months() {
+    let home = tempfile::TempDir::new().unwrap();
+    let cache_dir = cache_dir(home.path());
+    for (month, prs, reviewed) in [("2025-01", 2, 4), ("2025-02", 3, 2)] {
+        let prs: Vec<serde_json::Value> = (1..=prs)
+            .map(|number| pr_node(number, &format!("{}-1{}T10:00:00Z", month, number)))
+            .collect();
+        write_snapshot(&cache_dir, month, serde_json::json!(prs), reviewed);
+    }
+
+    let output = Command::new(cargo::cargo_bin!("gh-log"))
+        .env("XDG_CACHE_HOME", home.path().join("cache"))
+        .env("XDG_CONFIG_HOME", home.path().join("config"))
+        .env("GH_LOG_GH_PATH", "/nonexistent/gh")
+        .args(["compare", "2025-01", "2025-02", "--offline", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        (report["a"].as_str(), report["b"].as_str()),
+        (Some("2025-01"), Some("2025-02"))
+    );
+    let metric = |name: &str| {
+        report["metrics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|metric| metric["metric"] == name)
+            .unwrap()
+            .clone()
+    };
+    assert_eq!(metric("Total PRs")["change"], 1.0);
+    assert_eq!(metric("Total PRs")["percent_change"], 50.0);
+    assert_eq!(metric("Reviewed")["percent_change"], -50.0);
+}
+
+#[test]
+fn test_compare_rejects_non_calendar_month() {
+    let home = tempfile::TempDir::new().unwrap();
+    let output = Command::new(cargo::cargo_bin!("gh-log"))
+        .env("XDG_CACHE_HOME", home.path().join("cache"))
+        .env("XDG_CONFIG_HOME", home.path().join("config"))
+        .env("GH_LOG_GH_PATH", "/nonexistent/gh")
+        .args(["compare", "2025-01-03", "2025-02", "--offline"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(64), "{:?}", output);
+}
@@ -0,0 +1,118 @@
+use chrono::{TimeZone, Utc};
+use gh_log::cache::{CACHE_VERSION, Cache, CachedData};
+use gh_log::config::Config;
+use gh_log::data::build_month_data;
+use gh_log::github::{DateField, PullRequest, Repository, Reviews};
+
+fn pr(number: u32, title: &str, repo: &str, day: u32, additions: u32) -> PullRequest {
+    let created_at = Utc.with_ymd_and_hms(2024, 1, day, 9, 0, 0).unwrap();
+    PullRequest {
+        number,
+        title: title.to_string(),
+        body: None,
+        author: None,
+        repository: Repository {
+            name_with_owner: repo.to_string(),
+        },
+        created_at,
+        updated_at: created_at + chrono::Duration::hours(2),
+        merged_at: Some(created_at + chrono::Duration::hours(2)),
+        state: None,
+        additions,
+        deletions: 0,
+        changed_files: 1,
+        reviews: Reviews {
+            nodes: vec![],
+            total_count: None,
+        },
+        labels: vec![],
+    }
+}
+
+fn config_with(dir: &std::path::Path, toml: &str) -> Config {
+    std::fs::write(dir.join("config.toml"), toml).unwrap();
+    Config::new(dir.to_path_buf()).unwrap()
+}
+
+#[test]
+fn test_cached_month_rebuilds_under_a_new_config() {
+    let cache_dir = tempfile::TempDir::new().unwrap();
+    let cache = Cache::new(cache_dir.path().to_path_buf(), 100).unwrap();
+    let snapshot = CachedData {
+        version: CACHE_VERSION,
+        month: "2024-01".to_string(),
+        timestamp: Utc::now(),
+        prs: vec![
+            pr(1, "feat: search", "acme/app", 8, 40),
+            pr(2, "docs: search guide", "acme/app", 9, 10),
+            pr(3, "try things", "acme/scratch", 10, 5),
+        ],
+        reviewed_count: Some(2),
+        reviewed_by_repo: None,
+        review_turnaround_secs: None,
+    };
+    cache.save("2024-01", &snapshot).unwrap();
+    let cached = cache
+        .load("2024-01")
+        .unwrap()
+        .expect("old months stay cached");
+
+    let config_a = tempfile::TempDir::new().unwrap();
+    let config_a = config_with(config_a.path(), "");
+    let config_b = tempfile::TempDir::new().unwrap();
+    let config_b = config_with(
+        config_b.path(),
+        r#"
+[filter]
+exclude_repos = ["acme/scratch"]
+ignore_patterns = ["^docs:"]
+
+[size]
+small = 10
+medium = 20
+large = 30
+"#,
+    );
+
+    let a = build_month_data(
+        "2024-01",
+        cached.prs.clone(),
+        cached.reviewed_count,
+        DateField::Created,
+        &config_a,
+    );
+    let b = build_month_data(
+        "2024-01",
+        cached.prs.clone(),
+        cached.reviewed_count,
+        DateField::Created,
+        &config_b,
+    );
+
+    assert_eq!(a.total_prs, 3);
+    assert_eq!(
+        a.repos.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(),
+        ["acme/app", "acme/scratch"]
+    );
+    assert_eq!((a.size_s, a.size_xl), (3, 0));
+
+    // Excluded repo gone, ignored docs PR listed but not counted, the feature PR now XL.
+    assert_eq!(b.total_prs, 1);
+    assert_eq!(
+        b.repos.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(),
+        ["acme/app"]
+    );
+    assert_eq!(b.prs_by_week.iter().flatten().count(), 2);
+    assert_eq!((b.size_s, b.size_xl), (0, 1));
+
+    // Building again from the same snapshot gives the same report.
+    let again = build_month_data(
+        "2024-01",
+        cached.prs,
+        cached.reviewed_count,
+        DateField::Created,
+        &config_a,
+    );
+    assert_eq!(again.total_prs, a.total_prs);
+    assert_eq!(again.avg_lead_time, a.avg_lead_time);
+}